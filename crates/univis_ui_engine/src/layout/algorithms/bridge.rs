@@ -10,6 +10,9 @@ use crate::internal_prelude::*;
 /// Implementations receive a list of items and context, and must:
 /// 1. Set the position (`result.pos`) for each item.
 /// 2. Return the total used size (`Vec2`) of the container.
+///
+/// Custom placers are registered with `register_layout_placer` and selected
+/// with `UDisplay::Custom(id)`; only registered placers need `Send + Sync`.
 pub trait LayoutPlacer {
     fn place(&self, items: &mut [SolverItem], axis: &AxisHelper, ctx: &PlacementContext) -> Vec2;
 }
@@ -21,9 +24,10 @@ pub trait LayoutPlacer {
 /// The "Bridge" connecting the generic Solver to specific Layout Placers.
 ///
 /// This function acts as a factory/dispatcher:
-/// 1. It selects the correct `LayoutPlacer` based on `layout.display` (Flex, Grid, Stack...).
-/// 2. It filters/prepares the data.
-/// 3. It executes the placement logic and returns the final container size.
+/// 1. It filters/prepares the data.
+/// 2. It selects the correct `LayoutPlacer` based on `layout.display` (Flex, Grid, Stack...).
+/// 3. It executes the placement logic and returns the final container size,
+///    with the grid tracks when the grid placer ran.
 pub fn final_size_with_indices(
     layout: ULayout,
     items: &mut [SolverItem],
@@ -32,13 +36,13 @@ pub fn final_size_with_indices(
     placement_ctx: &PlacementContext,
     _container_main: f32, 
     _final_cross: f32,    
-) -> Vec2 { 
+) -> (Vec2, Option<ComputedGridTracks>) { 
     
     // 1. Prepare temporary data slice for the placer
     let temp_data: Vec<_> = normal_indices.iter()
         .map(|&i| {
             let item = &items[i];
-            (i, item.spec, *item.result, item.margin) 
+            (i, item.spec.clone(), *item.result, item.margin) 
         })
         .collect();
 
@@ -49,32 +53,41 @@ pub fn final_size_with_indices(
     let mut temp_items: Vec<SolverItem> = temp_data.iter()
         .zip(temp_results.iter_mut())
         .map(|((_, spec, _, margin), result)| SolverItem { 
-            spec: *spec, 
+            spec: spec.clone(), 
             result,
             margin: *margin 
         })
         .collect();
 
-    // 2. Select the Placer Strategy
-    let placer: Box<dyn LayoutPlacer> = match layout.display {
-        UDisplay::Flex => Box::new(FlexPlacer),
-        UDisplay::Stack => Box::new(StackPlacer),
-        UDisplay::Grid => Box::new(GridPlacer { columns: layout.grid_columns as usize }),
-        UDisplay::Masonry => Box::new(MasonryPlacer { columns: layout.grid_columns as usize }),
-        UDisplay::Radial => Box::new(RadialPlacer),
-        _ => Box::new(FlexPlacer),
+    // 2. Select the Placer Strategy and execute placement (Grid also reports its tracks)
+    let mut grid_tracks = None;
+    let used_size = match layout.display {
+        UDisplay::Flex => FlexPlacer.place(&mut temp_items, axis, placement_ctx),
+        UDisplay::Stack => StackPlacer.place(&mut temp_items, axis, placement_ctx),
+        UDisplay::Grid => {
+            let (size, tracks) = GridPlacer { columns: layout.grid_columns as usize }
+                .place_with_tracks(&mut temp_items, axis, placement_ctx);
+            grid_tracks = Some(tracks);
+            size
+        }
+        UDisplay::Masonry => MasonryPlacer { columns: layout.grid_columns as usize }
+            .place(&mut temp_items, axis, placement_ctx),
+        UDisplay::Radial => RadialPlacer.place(&mut temp_items, axis, placement_ctx),
+        // غير مسجل: نعود إلى Flex.
+        UDisplay::Custom(_) => match &placement_ctx.custom_placer {
+            Some(placer) => placer.place(&mut temp_items, axis, placement_ctx),
+            None => FlexPlacer.place(&mut temp_items, axis, placement_ctx),
+        },
+        _ => FlexPlacer.place(&mut temp_items, axis, placement_ctx),
     };
     
-    // 3. Execute Placement
-    let used_size = placer.place(&mut temp_items, axis, placement_ctx);
-    
     // 4. Apply results back to original items
     for (temp_idx, &original_idx) in normal_indices.iter().enumerate() {
         *items[original_idx].result = temp_results[temp_idx];
     }
 
     // Return final utilized size
-    used_size
+    (used_size, grid_tracks)
 }
 
 // =========================================================
@@ -1,8 +1,10 @@
 pub mod bridge;
 pub mod places;
+pub mod registry;
 pub mod prelude {
     pub use crate::layout::algorithms::{
         places::prelude::*,
         bridge::*,
+        registry::*,
     };
 }
\ No newline at end of file
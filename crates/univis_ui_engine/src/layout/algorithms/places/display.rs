@@ -64,19 +64,93 @@ fn alignment_offset(align: UAlignSelfExt, free_space_raw: f32, overflow: UOverfl
 }
 
 fn resolve_cross_align(spec: &SolverSpec, container_align: UAlignItems) -> UAlignSelfExt {
+    canonical_align_self(resolve_cross_align_raw(spec, container_align))
+}
+
+/// Like `resolve_cross_align`, but keeps the baseline values.
+fn resolve_cross_align_raw(spec: &SolverSpec, container_align: UAlignItems) -> UAlignSelfExt {
     if let Some(ext) = spec.align_self_ext {
         if !matches!(ext, UAlignSelfExt::Auto | UAlignSelfExt::Normal) {
-            return canonical_align_self(ext);
+            return ext;
         }
     }
 
     if let Some(legacy) = spec.align_self {
         if legacy != UAlignSelf::Auto {
-            return canonical_align_self(map_legacy_align_self(legacy));
+            return map_legacy_align_self(legacy);
+        }
+    }
+
+    map_items_ext_to_self_ext(map_legacy_align_items(container_align))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BaselineGroup {
+    First,
+    Last,
+}
+
+/// Baseline group of an item. Baselines are horizontal, so in a column the
+/// item falls back to start alignment.
+fn resolve_baseline_group(spec: &SolverSpec, container_align: UAlignItems, axis: &AxisHelper) -> Option<BaselineGroup> {
+    if !axis.is_row() {
+        return None;
+    }
+    match resolve_cross_align_raw(spec, container_align) {
+        UAlignSelfExt::Baseline | UAlignSelfExt::FirstBaseline => Some(BaselineGroup::First),
+        UAlignSelfExt::LastBaseline => Some(BaselineGroup::Last),
+        _ => None,
+    }
+}
+
+/// Distance from the top of the item's margin box to its baseline.
+/// Items without a baseline use their bottom edge.
+fn item_baseline(spec: &SolverSpec, group: BaselineGroup, child_cross: f32, m_cross_start: f32) -> f32 {
+    let own = match group {
+        BaselineGroup::First => spec.first_baseline,
+        BaselineGroup::Last => spec.last_baseline,
+    };
+    m_cross_start + own.unwrap_or(child_cross)
+}
+
+/// Shared baselines of a flex line or grid row: the largest extent above and
+/// below each baseline.
+#[derive(Debug, Default, Clone, Copy)]
+struct BaselineShim {
+    first_ascent: f32,
+    first_descent: f32,
+    last_ascent: f32,
+    last_descent: f32,
+}
+
+impl BaselineShim {
+    fn add(&mut self, group: BaselineGroup, ascent: f32, span: f32) {
+        let descent = span - ascent;
+        match group {
+            BaselineGroup::First => {
+                self.first_ascent = self.first_ascent.max(ascent);
+                self.first_descent = self.first_descent.max(descent);
+            }
+            BaselineGroup::Last => {
+                self.last_ascent = self.last_ascent.max(ascent);
+                self.last_descent = self.last_descent.max(descent);
+            }
         }
     }
 
-    canonical_align_self(map_items_ext_to_self_ext(map_legacy_align_items(container_align)))
+    /// Cross size needed to fit both groups.
+    fn extent(&self) -> f32 {
+        (self.first_ascent + self.first_descent).max(self.last_ascent + self.last_descent)
+    }
+
+    /// Offset of the item's margin box inside a line of `line_size`.
+    /// The first group sits at the start of the line, the last group at its end.
+    fn offset(&self, group: BaselineGroup, ascent: f32, span: f32, line_size: f32) -> f32 {
+        match group {
+            BaselineGroup::First => self.first_ascent - ascent,
+            BaselineGroup::Last => line_size - span - (self.last_descent - (span - ascent)),
+        }
+    }
 }
 
 fn resolve_justify_self(spec: &SolverSpec, ctx: &PlacementContext) -> UAlignSelfExt {
@@ -132,62 +206,314 @@ fn has_explicit_justify_self(spec: &SolverSpec) -> bool {
     )
 }
 
-fn resolve_track_sizes(
+/// A grid track normalized to `minmax(min, max)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GridTrack {
+    min: UTrackBreadth,
+    max: UTrackBreadth,
+    /// `fit-content()` limit in pixels.
+    fit_limit: Option<f32>,
+    /// Created by `repeat(auto-fit, ..)`: collapses when no item uses it.
+    auto_fit: bool,
+}
+
+impl From<UTrackSize> for GridTrack {
+    fn from(track: UTrackSize) -> Self {
+        let (min, max) = track.min_max();
+        let fit_limit = match track {
+            UTrackSize::FitContent(limit) => Some(limit.max(0.0)),
+            _ => None,
+        };
+        GridTrack { min, max, fit_limit, auto_fit: false }
+    }
+}
+
+/// An item's size request on the tracks `start..start + span`.
+#[derive(Debug, Clone, Copy)]
+struct TrackItem {
+    start: usize,
+    span: usize,
+    /// Min-content contribution.
+    min_size: f32,
+    /// Max-content contribution.
+    size: f32,
+}
+
+fn definite_breadth(breadth: UTrackBreadth) -> Option<f32> {
+    match breadth {
+        UTrackBreadth::Px(v) => Some(v.max(0.0)),
+        _ => None,
+    }
+}
+
+/// Number of tracks `repeat(auto-fill | auto-fit, ..)` creates: as many as fit
+/// next to the other tracks, and at least one.
+fn auto_repeat_count(
+    template: &[UTrackSize],
+    min: UTrackBreadth,
+    max: UTrackBreadth,
+    available_space: f32,
+    gap: f32,
+) -> usize {
+    let Some(track_size) = definite_breadth(max).or(definite_breadth(min)) else {
+        return 1;
+    };
+    if !available_space.is_finite() {
+        return 1;
+    }
+
+    // Every other track, `repeat(n, ..)` counting `n` times, adds its size and one gap.
+    let (others_size, others_count) = template
+        .iter()
+        .filter(|t| !matches!(t, UTrackSize::Repeat(UGridRepeat::AutoFill | UGridRepeat::AutoFit, _, _)))
+        .fold((0.0, 0.0), |(size, count), t| {
+            let (min, max) = t.min_max();
+            let repeats = match t {
+                UTrackSize::Repeat(UGridRepeat::Count(n), _, _) => (*n).max(1) as f32,
+                _ => 1.0,
+            };
+            (size + definite_breadth(max).or(definite_breadth(min)).unwrap_or(0.0) * repeats, count + repeats)
+        });
+    let others_gaps = others_count * gap;
+
+    let fits = (available_space - others_size - others_gaps + gap) / (track_size + gap).max(0.001);
+    (fits.floor().max(1.0)) as usize
+}
+
+/// Expands `repeat()` entries of a track template. An empty template yields
+/// `fallback_count` copies of `auto_track`.
+fn expand_track_template(
     template: &[UTrackSize],
     fallback_count: usize,
     auto_track: UTrackSize,
     available_space: f32,
     gap: f32,
-    required_min: usize,
-) -> Vec<f32> {
-    let base_count = if template.is_empty() { fallback_count.max(1) } else { template.len() };
-    let mut track_defs = if template.is_empty() {
-        vec![auto_track; base_count]
-    } else {
-        template.to_vec()
-    };
+) -> Vec<GridTrack> {
+    if template.is_empty() {
+        return vec![GridTrack::from(auto_track); fallback_count];
+    }
 
-    while track_defs.len() < required_min.max(1) {
-        track_defs.push(auto_track);
+    let mut tracks = Vec::with_capacity(template.len());
+    for track in template {
+        match *track {
+            UTrackSize::Repeat(repeat, min, max) => {
+                let count = match repeat {
+                    UGridRepeat::Count(n) => n.max(1) as usize,
+                    UGridRepeat::AutoFill | UGridRepeat::AutoFit => {
+                        auto_repeat_count(template, min, max, available_space, gap)
+                    }
+                };
+                let auto_fit = repeat == UGridRepeat::AutoFit;
+                tracks.extend(std::iter::repeat_n(GridTrack { min, max, fit_limit: None, auto_fit }, count));
+            }
+            other => tracks.push(GridTrack::from(other)),
+        }
     }
+    tracks
+}
 
-    let count = track_defs.len();
-    let total_gap = if count > 1 { (count as f32 - 1.0) * gap } else { 0.0 };
-    let distributable = (available_space - total_gap).max(0.0);
+/// `auto-fit` tracks that no item spans.
+fn collapsed_tracks(tracks: &[GridTrack], items: &[TrackItem]) -> Vec<bool> {
+    let mut used = vec![false; tracks.len()];
+    for item in items {
+        for slot in used.iter_mut().skip(item.start).take(item.span) {
+            *slot = true;
+        }
+    }
+    tracks.iter().zip(used).map(|(t, used)| t.auto_fit && !used).collect()
+}
 
-    let mut fixed_sum = 0.0;
-    let mut fr_sum = 0.0;
-    let mut auto_count = 0usize;
+/// Simplified CSS grid track sizing: content-based base sizes, spanning items,
+/// growth up to `max`, `fr` distribution and stretching of `auto` tracks.
+///
+/// `min-content` and `auto` minimums use the items' min-content contributions,
+/// `max-content` and `auto` maximums their max-content contributions.
+fn resolve_track_sizes(
+    tracks: &[GridTrack],
+    items: &[TrackItem],
+    available_space: f32,
+    gap: f32,
+) -> Vec<f32> {
+    let count = tracks.len();
+    let collapsed = collapsed_tracks(tracks, items);
+    let visible = collapsed.iter().filter(|c| !**c).count();
+    let total_gap = if visible > 1 { (visible as f32 - 1.0) * gap } else { 0.0 };
+    let space = (available_space - total_gap).max(0.0);
+    let definite = space.is_finite();
+
+    let mut min_content = vec![0.0f32; count];
+    let mut max_content = vec![0.0f32; count];
+    for item in items.iter().filter(|i| i.span == 1 && i.start < count) {
+        min_content[item.start] = min_content[item.start].max(item.min_size);
+        max_content[item.start] = max_content[item.start].max(item.size);
+    }
 
-    for track in &track_defs {
-        match *track {
-            UTrackSize::Px(v) => fixed_sum += v.max(0.0),
-            UTrackSize::Fr(v) => fr_sum += v.max(0.0),
-            UTrackSize::Auto => auto_count += 1,
+    let is_flex = |t: &GridTrack| matches!(t.max, UTrackBreadth::Fr(_));
+    let mut base = vec![0.0f32; count];
+    let mut limit = vec![0.0f32; count];
+    for (i, track) in tracks.iter().enumerate() {
+        if collapsed[i] {
+            continue;
         }
+        base[i] = match track.min {
+            UTrackBreadth::Px(v) => v.max(0.0),
+            UTrackBreadth::MaxContent => max_content[i],
+            _ => min_content[i],
+        };
+        let mut max = match track.max {
+            UTrackBreadth::Px(v) => v.max(0.0),
+            UTrackBreadth::Fr(_) => base[i],
+            UTrackBreadth::MinContent => min_content[i],
+            _ => max_content[i],
+        };
+        if let Some(fit) = track.fit_limit {
+            max = max_content[i].min(fit);
+        }
+        limit[i] = max.max(base[i]);
     }
 
-    let remaining = (distributable - fixed_sum).max(0.0);
-    let auto_size = if fr_sum <= 0.0 && auto_count > 0 {
-        remaining / auto_count as f32
+    // Items spanning several tracks grow the intrinsic tracks they cover: the
+    // base sizes by their min-content contribution, the limits by their max-content one.
+    for item in items.iter().filter(|i| i.span > 1) {
+        let end = (item.start + item.span).min(count);
+        let covered: Vec<usize> = (item.start..end).filter(|&i| !collapsed[i]).collect();
+        let spanned_gaps = (covered.len().saturating_sub(1)) as f32 * gap;
+        let targets: Vec<usize> = covered
+            .iter()
+            .copied()
+            .filter(|&i| !matches!(tracks[i].min, UTrackBreadth::Px(_)))
+            .collect();
+        if targets.is_empty() {
+            continue;
+        }
+        let extra = item.min_size - covered.iter().map(|&i| base[i]).sum::<f32>() - spanned_gaps;
+        if extra > 0.0 {
+            let share = extra / targets.len() as f32;
+            for &i in &targets {
+                base[i] += share;
+                limit[i] = limit[i].max(base[i]);
+            }
+        }
+        let extra = item.size - covered.iter().map(|&i| limit[i]).sum::<f32>() - spanned_gaps;
+        let growable: Vec<usize> = targets.iter().copied().filter(|&i| !is_flex(&tracks[i])).collect();
+        if extra > 0.0 && !growable.is_empty() {
+            let share = extra / growable.len() as f32;
+            for i in growable {
+                limit[i] += share;
+            }
+        }
+    }
+
+    // Grow non-flexible tracks towards their limits; without a definite size
+    // they take their limits outright.
+    if !definite {
+        for i in (0..count).filter(|&i| !collapsed[i] && !is_flex(&tracks[i])) {
+            base[i] = limit[i];
+        }
     } else {
-        0.0
-    };
+        let mut free = space - base.iter().sum::<f32>();
+        loop {
+            let growable: Vec<usize> = (0..count)
+                .filter(|&i| !collapsed[i] && !is_flex(&tracks[i]) && limit[i] > base[i] + 0.001)
+                .collect();
+            if free <= 0.001 || growable.is_empty() {
+                break;
+            }
+            let share = free / growable.len() as f32;
+            for i in growable {
+                let grow = share.min(limit[i] - base[i]);
+                base[i] += grow;
+                free -= grow;
+            }
+        }
+    }
 
-    track_defs
-        .iter()
-        .map(|track| match *track {
-            UTrackSize::Px(v) => v.max(0.0),
-            UTrackSize::Fr(v) => {
-                if fr_sum > 0.0 {
-                    (remaining * (v.max(0.0) / fr_sum)).max(0.0)
-                } else {
-                    0.0
+    let flex_tracks: Vec<usize> = (0..count).filter(|&i| !collapsed[i] && is_flex(&tracks[i])).collect();
+    let fr = |i: usize| match tracks[i].max {
+        UTrackBreadth::Fr(f) => f.max(0.0),
+        _ => 0.0,
+    };
+    if !flex_tracks.is_empty() {
+        // Find the size of one `fr`, treating tracks whose base exceeds their share as fixed.
+        let unit = if definite {
+            let mut flexible = flex_tracks.clone();
+            loop {
+                let fixed: f32 = (0..count).filter(|i| !flexible.contains(i)).map(|i| base[i]).sum();
+                let fr_sum: f32 = flexible.iter().map(|&i| fr(i)).sum::<f32>().max(1.0);
+                let unit = (space - fixed).max(0.0) / fr_sum;
+                let before = flexible.len();
+                flexible.retain(|&i| base[i] <= unit * fr(i));
+                if flexible.len() == before {
+                    break unit;
                 }
             }
-            UTrackSize::Auto => auto_size.max(0.0),
-        })
-        .collect()
+        } else {
+            flex_tracks
+                .iter()
+                .filter(|&&i| fr(i) > 0.0)
+                .map(|&i| base[i].max(max_content[i]) / fr(i).max(1.0))
+                .fold(0.0, f32::max)
+        };
+        for &i in &flex_tracks {
+            base[i] = base[i].max(unit * fr(i));
+        }
+    } else if definite {
+        // Stretch `auto` tracks into the remaining space.
+        let auto_tracks: Vec<usize> = (0..count)
+            .filter(|&i| !collapsed[i] && tracks[i].max == UTrackBreadth::Auto && tracks[i].fit_limit.is_none())
+            .collect();
+        let free = space - base.iter().sum::<f32>();
+        if free > 0.0 && !auto_tracks.is_empty() {
+            let share = free / auto_tracks.len() as f32;
+            for i in auto_tracks {
+                base[i] += share;
+            }
+        }
+    }
+
+    base
+}
+
+/// Track start offsets (collapsed tracks add no gap) and the total track span.
+fn track_starts(sizes: &[f32], collapsed: &[bool], gap: f32) -> (Vec<f32>, f32) {
+    let mut starts = Vec::with_capacity(sizes.len());
+    let mut cursor = 0.0;
+    let mut any = false;
+    for (size, collapsed) in sizes.iter().zip(collapsed) {
+        starts.push(cursor);
+        if !collapsed {
+            cursor += size + gap;
+            any = true;
+        }
+    }
+    let total = if any { cursor - gap } else { 0.0 };
+    (starts, total)
+}
+
+/// Min- and max-content sizes an item requests from the tracks it spans on
+/// one axis, margins included.
+fn grid_item_contribution(item: &SolverItem, axis: &AxisHelper, main: bool, available: f32) -> (f32, f32) {
+    let (mode, val, _) = if main { axis.get_main_spec(&item.spec) } else { axis.get_cross_spec(&item.spec) };
+    let (m_main_start, m_main_end, m_cross_start, m_cross_end) = axis.extract_margin_sides(item.margin);
+    let (min_size, size) = match mode {
+        SolverSizeMode::Fixed => (val, val),
+        SolverSizeMode::Percent if available.is_finite() => (val * available, val * available),
+        _ if item.spec.aspect_ratio.is_some() => {
+            let (result_main, result_cross) = axis.from_world(item.result.size);
+            let size = if main { result_main } else { result_cross };
+            (size, size)
+        }
+        _ if main => (axis.get_main_min_content(&item.spec), axis.get_main_content(&item.spec)),
+        _ => (axis.get_cross_min_content(&item.spec), axis.get_cross_content(&item.spec)),
+    };
+    let outer = |size: f32| {
+        if main {
+            axis.clamp_main(&item.spec, size, available) + m_main_start + m_main_end
+        } else {
+            axis.clamp_cross(&item.spec, size, available) + m_cross_start + m_cross_end
+        }
+    };
+    (outer(min_size.min(size)), outer(size))
 }
 
 fn ensure_grid_rows(occupancy: &mut Vec<Vec<bool>>, rows: usize, cols: usize) {
@@ -242,6 +568,7 @@ struct FlexLine {
     end: usize,
     main_span: f32,
     cross_size: f32,
+    baseline: BaselineShim,
 }
 
 pub struct FlexPlacer;
@@ -259,6 +586,7 @@ impl LayoutPlacer for FlexPlacer {
         let mut line_start = 0usize;
         let mut line_main = 0.0;
         let mut line_cross = 0.0;
+        let mut line_baseline = BaselineShim::default();
 
         for i in 0..items.len() {
             let item = &items[i];
@@ -278,14 +606,22 @@ impl LayoutPlacer for FlexPlacer {
                     end: i,
                     main_span: line_main,
                     cross_size: line_cross,
+                    baseline: line_baseline,
                 });
                 line_start = i;
                 line_main = item_main_span;
                 line_cross = item_cross_span;
+                line_baseline = BaselineShim::default();
             } else {
                 line_main = tentative;
                 line_cross = line_cross.max(item_cross_span);
             }
+
+            // عناصر الـ baseline قد تحتاج ارتفاعاً أكبر من أطولها.
+            if let Some(group) = resolve_baseline_group(&item.spec, ctx.align_items, axis) {
+                line_baseline.add(group, item_baseline(&item.spec, group, child_cross, m_cross_start), item_cross_span);
+                line_cross = line_cross.max(line_baseline.extent());
+            }
         }
 
         lines.push(FlexLine {
@@ -293,6 +629,7 @@ impl LayoutPlacer for FlexPlacer {
             end: items.len(),
             main_span: line_main,
             cross_size: line_cross,
+            baseline: line_baseline,
         });
 
         let line_count = lines.len();
@@ -404,14 +741,26 @@ impl LayoutPlacer for FlexPlacer {
                     (main_mode, cross_mode, ())
                 };
 
-                if canonical_align_self(cross_align) == UAlignSelfExt::Stretch && cross_mode != SolverSizeMode::Fixed {
+                if canonical_align_self(cross_align) == UAlignSelfExt::Stretch
+                    && cross_mode != SolverSizeMode::Fixed
+                    && item.spec.aspect_ratio.is_none()
+                {
                     child_cross = (line_sizes[line_idx] - m_cross_start - m_cross_end).max(0.0);
+                    child_cross = axis.clamp_cross(&item.spec, child_cross, content_cross);
                     item.result.size = axis.to_world(child_main, child_cross);
                 }
 
                 let occupied_cross = child_cross + m_cross_start + m_cross_end;
                 let free_cross_in_line = line_sizes[line_idx] - occupied_cross;
-                let cross_offset = alignment_offset(cross_align, free_cross_in_line, item.spec.align_overflow);
+                let cross_offset = match resolve_baseline_group(&item.spec, ctx.align_items, axis) {
+                    Some(group) => line.baseline.offset(
+                        group,
+                        item_baseline(&item.spec, group, child_cross, m_cross_start),
+                        occupied_cross,
+                        line_sizes[line_idx],
+                    ),
+                    None => alignment_offset(cross_align, free_cross_in_line, item.spec.align_overflow),
+                };
                 let pos_cross = line_starts[line_idx] + m_cross_start + cross_offset;
 
                 let mut pos_main = main_cursor + m_main_start;
@@ -446,41 +795,99 @@ pub struct GridPlacer {
 
 impl LayoutPlacer for GridPlacer {
     fn place(&self, items: &mut [SolverItem], axis: &AxisHelper, ctx: &PlacementContext) -> Vec2 {
+        self.place_with_tracks(items, axis, ctx).0
+    }
+}
+
+impl GridPlacer {
+    /// Places the items like [`LayoutPlacer::place`] and also returns the
+    /// tracks it sized, with the container padding included in their starts.
+    pub fn place_with_tracks(
+        &self,
+        items: &mut [SolverItem],
+        axis: &AxisHelper,
+        ctx: &PlacementContext,
+    ) -> (Vec2, ComputedGridTracks) {
         if items.is_empty() {
-            return Vec2::ZERO;
+            return (Vec2::ZERO, ComputedGridTracks::default());
         }
 
         let available_main = (ctx.container_main_size - ctx.padding_main_start - ctx.padding_main_end).max(0.0);
         let available_cross = (ctx.container_cross_size - ctx.padding_cross_start * 2.0).max(0.0);
 
-        let fallback_cols = self.columns.max(ctx.grid_columns as usize).max(1);
-        let mut required_cols = fallback_cols;
-        for item in items.iter() {
-            let col_start = item.spec.grid_column_start.unwrap_or(1) as usize;
-            let col_span = item.spec.grid_column_span.max(1) as usize;
-            required_cols = required_cols.max(col_start.saturating_sub(1) + col_span);
-        }
-
-        let col_sizes = resolve_track_sizes(
+        let names = GridNames::new(
+            &ctx.grid_template_areas,
+            &ctx.grid_column_line_names,
+            &ctx.grid_row_line_names,
+        );
+        let fallback_cols = self.columns.max(ctx.grid_columns as usize).max(names.area_columns).max(1);
+        let mut col_tracks = expand_track_template(
             &ctx.grid_template_columns,
             fallback_cols,
             ctx.grid_auto_columns,
             available_main,
             ctx.main_gap,
-            required_cols,
         );
-        let cols = col_sizes.len().max(1);
+        let mut row_tracks = expand_track_template(
+            &ctx.grid_template_rows,
+            0,
+            ctx.grid_auto_rows,
+            available_cross,
+            ctx.cross_gap,
+        );
+        let explicit_cols = col_tracks.len().max(names.area_columns);
+        let explicit_rows = row_tracks.len().max(names.area_rows);
+
+        // (column start, column span, row start, row span) before auto-placement.
+        let resolved: Vec<(Option<usize>, usize, Option<usize>, usize)> = items
+            .iter()
+            .map(|item| {
+                let area = item.spec.grid_area.as_deref().and_then(|name| names.area(name));
+                if let Some(rect) = area {
+                    return (
+                        Some(rect.column_start),
+                        rect.column_end - rect.column_start,
+                        Some(rect.row_start),
+                        rect.row_end - rect.row_start,
+                    );
+                }
+                let (col, col_span) = resolve_grid_axis(
+                    &item.spec.grid_column_lines,
+                    item.spec.grid_column_start,
+                    item.spec.grid_column_span,
+                    &names,
+                    true,
+                    explicit_cols,
+                );
+                let (row, row_span) = resolve_grid_axis(
+                    &item.spec.grid_row_lines,
+                    item.spec.grid_row_start,
+                    item.spec.grid_row_span,
+                    &names,
+                    false,
+                    explicit_rows,
+                );
+                (col, col_span, row, row_span)
+            })
+            .collect();
+
+        let mut required_cols = fallback_cols;
+        for &(col_start, col_span, _, _) in &resolved {
+            required_cols = required_cols.max(col_start.unwrap_or(0) + col_span);
+        }
+
+        while col_tracks.len() < required_cols.max(1) {
+            col_tracks.push(GridTrack::from(ctx.grid_auto_columns));
+        }
+        let cols = col_tracks.len();
 
         let mut occupancy: Vec<Vec<bool>> = vec![vec![false; cols]];
         let mut placements: Vec<(usize, usize, usize, usize)> = Vec::with_capacity(items.len());
         let mut auto_cursor_row = 0usize;
         let mut auto_cursor_col = 0usize;
 
-        for item in items.iter() {
-            let col_span = item.spec.grid_column_span.max(1) as usize;
-            let row_span = item.spec.grid_row_span.max(1) as usize;
-            let fixed_col = item.spec.grid_column_start.map(|v| v.saturating_sub(1) as usize).map(|v| v.min(cols.saturating_sub(1)));
-            let fixed_row = item.spec.grid_row_start.map(|v| v.saturating_sub(1) as usize);
+        for &(fixed_col, col_span, fixed_row, row_span) in &resolved {
+            let fixed_col = fixed_col.map(|v| v.min(cols.saturating_sub(1)));
             let is_fully_auto = fixed_col.is_none() && fixed_row.is_none();
 
             let mut found = None;
@@ -494,13 +901,15 @@ impl LayoutPlacer for GridPlacer {
 
             if found.is_none() {
                 match ctx.grid_auto_flow {
-                    UGridAutoFlow::Row => {
-                        let mut row = if fixed_row.is_none() && fixed_col.is_none() {
+                    UGridAutoFlow::Row | UGridAutoFlow::RowDense => {
+                        // dense: نبدأ البحث من أول خلية لملء الفراغات السابقة.
+                        let from_cursor = is_fully_auto && ctx.grid_auto_flow == UGridAutoFlow::Row;
+                        let mut row = if from_cursor {
                             auto_cursor_row
                         } else {
                             fixed_row.unwrap_or(0)
                         };
-                        let mut first_col = if fixed_row.is_none() && fixed_col.is_none() {
+                        let mut first_col = if from_cursor {
                             auto_cursor_col.min(cols.saturating_sub(1))
                         } else {
                             0
@@ -527,28 +936,39 @@ impl LayoutPlacer for GridPlacer {
                             row += 1;
                         }
                     }
-                    UGridAutoFlow::Column => {
-                        let mut search_rows = occupancy.len().max(1);
+                    UGridAutoFlow::Column | UGridAutoFlow::ColumnDense => {
+                        // الأعمدة تمتلئ حتى عدد الصفوف الصريحة، ثم تزيد الصفوف عند الحاجة.
+                        // sparse: يبدأ من المؤشر (عمود، صف) ولا يعود للفراغات السابقة.
+                        let from_cursor = is_fully_auto
+                            && ctx.grid_auto_flow == UGridAutoFlow::Column
+                            && auto_cursor_col + col_span <= cols;
+                        let first_col = if from_cursor { auto_cursor_col } else { 0 };
+                        let mut search_rows = explicit_rows.max(row_span).max(1);
                         loop {
-                            ensure_grid_rows(&mut occupancy, search_rows + row_span, cols);
+                            ensure_grid_rows(&mut occupancy, search_rows, cols);
+                            let last_row = search_rows - row_span;
+                            let first_row = |col: usize| {
+                                if from_cursor && col == first_col {
+                                    auto_cursor_row
+                                } else {
+                                    fixed_row.unwrap_or(0)
+                                }
+                            };
                             if let Some(fc) = fixed_col {
-                                for row in fixed_row.unwrap_or(0)..(search_rows + row_span) {
+                                for row in fixed_row.unwrap_or(0)..=last_row {
                                     if can_place_span(&occupancy, row, fc, row_span, col_span, cols) {
                                         found = Some((row, fc));
                                         break;
                                     }
                                 }
                             } else {
-                                for col in 0..cols {
-                                    for row in fixed_row.unwrap_or(0)..(search_rows + row_span) {
+                                'cols: for col in first_col..cols {
+                                    for row in first_row(col)..=last_row {
                                         if can_place_span(&occupancy, row, col, row_span, col_span, cols) {
                                             found = Some((row, col));
-                                            break;
+                                            break 'cols;
                                         }
                                     }
-                                    if found.is_some() {
-                                        break;
-                                    }
                                 }
                             }
                             if found.is_some() {
@@ -566,11 +986,16 @@ impl LayoutPlacer for GridPlacer {
             placements.push((row, col, row_span, col_span));
 
             if is_fully_auto {
-                auto_cursor_row = row;
-                auto_cursor_col = col + col_span;
-                if auto_cursor_col >= cols {
-                    auto_cursor_row += auto_cursor_col / cols;
-                    auto_cursor_col %= cols;
+                if matches!(ctx.grid_auto_flow, UGridAutoFlow::Column | UGridAutoFlow::ColumnDense) {
+                    auto_cursor_col = col;
+                    auto_cursor_row = row + row_span;
+                } else {
+                    auto_cursor_row = row;
+                    auto_cursor_col = col + col_span;
+                    if auto_cursor_col >= cols {
+                        auto_cursor_row += auto_cursor_col / cols;
+                        auto_cursor_col %= cols;
+                    }
                 }
             }
         }
@@ -579,28 +1004,54 @@ impl LayoutPlacer for GridPlacer {
             .iter()
             .map(|(row, _, row_span, _)| row + row_span)
             .max()
-            .unwrap_or(1);
+            .unwrap_or(1)
+            .max(names.area_rows);
 
-        let row_sizes = resolve_track_sizes(
-            &ctx.grid_template_rows,
-            required_rows,
-            ctx.grid_auto_rows,
-            available_cross,
-            ctx.cross_gap,
-            required_rows,
-        );
-
-        let mut col_starts = vec![0.0; cols];
-        for i in 1..cols {
-            col_starts[i] = col_starts[i - 1] + col_sizes[i - 1] + ctx.main_gap;
+        while row_tracks.len() < required_rows.max(1) {
+            row_tracks.push(GridTrack::from(ctx.grid_auto_rows));
+        }
+        let rows = row_tracks.len();
+
+        let mut col_items = Vec::with_capacity(items.len());
+        let mut row_items = Vec::with_capacity(items.len());
+        for (item, &(row, col, row_span, col_span)) in items.iter().zip(&placements) {
+            let (min_size, size) = grid_item_contribution(item, axis, true, available_main);
+            col_items.push(TrackItem { start: col, span: col_span, min_size, size });
+            let (min_size, size) = grid_item_contribution(item, axis, false, available_cross);
+            row_items.push(TrackItem { start: row, span: row_span, min_size, size });
         }
 
-        let rows = row_sizes.len();
-        let mut row_starts = vec![0.0; rows];
-        for i in 1..rows {
-            row_starts[i] = row_starts[i - 1] + row_sizes[i - 1] + ctx.cross_gap;
+        // Items spanning a single row share that row's baselines; spanning
+        // items fall back to start alignment.
+        let baseline_groups: Vec<Option<BaselineGroup>> = items
+            .iter()
+            .zip(&placements)
+            .map(|(item, &(_, _, row_span, _))| {
+                resolve_baseline_group(&item.spec, ctx.align_items, axis).filter(|_| row_span == 1)
+            })
+            .collect();
+        let mut row_baselines = vec![BaselineShim::default(); rows];
+        for ((item, &(row, _, _, _)), group) in items.iter().zip(&placements).zip(&baseline_groups) {
+            if let Some(group) = *group {
+                let (_, child_cross) = axis.from_world(item.result.size);
+                let (_, _, m_cross_start, m_cross_end) = axis.extract_margin_sides(item.margin);
+                let ascent = item_baseline(&item.spec, group, child_cross, m_cross_start);
+                row_baselines[row].add(group, ascent, child_cross + m_cross_start + m_cross_end);
+            }
+        }
+        for (row, shim) in row_baselines.iter().enumerate() {
+            if shim.extent() > 0.0 {
+                row_items.push(TrackItem { start: row, span: 1, min_size: shim.extent(), size: shim.extent() });
+            }
         }
 
+        let col_sizes = resolve_track_sizes(&col_tracks, &col_items, available_main, ctx.main_gap);
+        let row_sizes = resolve_track_sizes(&row_tracks, &row_items, available_cross, ctx.cross_gap);
+        let (col_starts, total_cols_size) =
+            track_starts(&col_sizes, &collapsed_tracks(&col_tracks, &col_items), ctx.main_gap);
+        let (row_starts, total_rows_size) =
+            track_starts(&row_sizes, &collapsed_tracks(&row_tracks, &row_items), ctx.cross_gap);
+
         for (idx, item) in items.iter_mut().enumerate() {
             let (row, col, row_span, col_span) = placements[idx];
             let (m_main_start, m_main_end, m_cross_start, m_cross_end) = axis.extract_margin_sides(item.margin);
@@ -629,6 +1080,11 @@ impl LayoutPlacer for GridPlacer {
 
             let (mut child_main, mut child_cross) = axis.from_world(item.result.size);
 
+            // With an aspect ratio, only the axis without a definite size follows the other one.
+            let is_definite = |mode: SolverSizeMode| matches!(mode, SolverSizeMode::Fixed | SolverSizeMode::Percent);
+            let ratio_main = item.spec.aspect_ratio.filter(|_| is_definite(cross_mode) && !is_definite(main_mode));
+            let ratio_cross = item.spec.aspect_ratio.filter(|_| !is_definite(cross_mode));
+
             let mut justify_self = resolve_justify_self(&item.spec, ctx);
             if !has_explicit_justify_self(&item.spec)
                 && ctx.justify_items.is_none()
@@ -636,12 +1092,17 @@ impl LayoutPlacer for GridPlacer {
             {
                 justify_self = UAlignSelfExt::Stretch;
             }
-            if canonical_align_self(justify_self) == UAlignSelfExt::Stretch && main_mode != SolverSizeMode::Fixed {
+            if ratio_main.is_none()
+                && canonical_align_self(justify_self) == UAlignSelfExt::Stretch
+                && main_mode != SolverSizeMode::Fixed {
                 child_main = (cell_main_size - m_main_start - m_main_end).max(0.0);
+                child_main = axis.clamp_main(&item.spec, child_main, cell_main_size);
             }
 
+            let baseline_group = baseline_groups[idx];
             let mut align_self = resolve_cross_align(&item.spec, ctx.align_items);
             if !has_explicit_align_self(&item.spec)
+                && baseline_group.is_none()
                 && matches!(
                     ctx.align_items,
                     UAlignItems::Auto
@@ -653,8 +1114,12 @@ impl LayoutPlacer for GridPlacer {
             {
                 align_self = UAlignSelfExt::Stretch;
             }
-            if canonical_align_self(align_self) == UAlignSelfExt::Stretch && cross_mode != SolverSizeMode::Fixed {
+            if let Some(ratio) = ratio_cross {
+                child_cross = axis.cross_from_ratio(ratio, child_main);
+                child_cross = axis.clamp_cross(&item.spec, child_cross, cell_cross_size);
+            } else if canonical_align_self(align_self) == UAlignSelfExt::Stretch && cross_mode != SolverSizeMode::Fixed {
                 child_cross = (cell_cross_size - m_cross_start - m_cross_end).max(0.0);
+                child_cross = axis.clamp_cross(&item.spec, child_cross, cell_cross_size);
             }
 
             item.result.size = axis.to_world(child_main, child_cross);
@@ -663,7 +1128,15 @@ impl LayoutPlacer for GridPlacer {
             let free_cross = cell_cross_size - (child_cross + m_cross_start + m_cross_end);
 
             let main_offset = alignment_offset(justify_self, free_main, item.spec.justify_overflow);
-            let cross_offset = alignment_offset(align_self, free_cross, item.spec.align_overflow);
+            let cross_offset = match baseline_group {
+                Some(group) => row_baselines[row].offset(
+                    group,
+                    item_baseline(&item.spec, group, child_cross, m_cross_start),
+                    child_cross + m_cross_start + m_cross_end,
+                    cell_cross_size,
+                ),
+                None => alignment_offset(align_self, free_cross, item.spec.align_overflow),
+            };
 
             let pos_main = cell_main_start + m_main_start + main_offset;
             let pos_cross = cell_cross_start + m_cross_start + cross_offset;
@@ -671,10 +1144,25 @@ impl LayoutPlacer for GridPlacer {
             item.result.pos = axis.to_world(pos_main, pos_cross);
         }
 
-        let total_main = col_sizes.iter().sum::<f32>() + if cols > 1 { (cols as f32 - 1.0) * ctx.main_gap } else { 0.0 } + ctx.padding_main_start + ctx.padding_main_end;
-        let total_cross = row_sizes.iter().sum::<f32>() + if rows > 1 { (rows as f32 - 1.0) * ctx.cross_gap } else { 0.0 } + (ctx.padding_cross_start * 2.0);
+        let total_main = total_cols_size + ctx.padding_main_start + ctx.padding_main_end;
+        let total_cross = total_rows_size + (ctx.padding_cross_start * 2.0);
 
-        Vec2::new(total_main, total_cross)
+        let tracks = |starts: &[f32], sizes: &[f32], padding: f32| -> Vec<ComputedTrack> {
+            starts
+                .iter()
+                .zip(sizes)
+                .map(|(&start, &size)| ComputedTrack { start: padding + start, size })
+                .collect()
+        };
+        let main_tracks = tracks(&col_starts, &col_sizes, ctx.padding_main_start);
+        let cross_tracks = tracks(&row_starts, &row_sizes, ctx.padding_cross_start);
+        let (columns, rows) = if axis.is_row() {
+            (main_tracks, cross_tracks)
+        } else {
+            (cross_tracks, main_tracks)
+        };
+
+        (Vec2::new(total_main, total_cross), ComputedGridTracks { columns, rows })
     }
 }
 
@@ -714,33 +1202,52 @@ pub struct MasonryPlacer {
     pub columns: usize,
 }
 
+impl MasonryPlacer {
+    /// Lane count: derived from `min_column_width` when set, else `columns`.
+    fn lane_count(&self, available: f32, ctx: &PlacementContext) -> usize {
+        match ctx.masonry.min_column_width.filter(|w| *w > 0.0) {
+            Some(min) if available.is_finite() => ((available + ctx.main_gap) / (min + ctx.main_gap)).floor().max(1.0) as usize,
+            _ => self.columns,
+        }
+    }
+}
+
 impl LayoutPlacer for MasonryPlacer {
     fn place(&self, items: &mut [SolverItem], axis: &AxisHelper, ctx: &PlacementContext) -> Vec2 {
-        if self.columns == 0 || items.is_empty() {
+        // The main axis runs across the lanes (see `UMasonryFlow::direction`).
+        let available_width = (ctx.container_main_size - ctx.padding_main_start - ctx.padding_main_end).max(0.0);
+        let columns = self.lane_count(available_width, ctx);
+        if columns == 0 || items.is_empty() {
             return Vec2::ZERO;
         }
 
-        let total_gaps = (self.columns as f32 - 1.0) * ctx.main_gap;
-        let available_width = (ctx.container_main_size - ctx.padding_main_start - ctx.padding_main_end).max(0.0);
-        let col_width = (available_width - total_gaps).max(0.0) / self.columns as f32;
+        let total_gaps = (columns as f32 - 1.0) * ctx.main_gap;
+        let col_width = (available_width - total_gaps).max(0.0) / columns as f32;
 
-        let mut col_heights = vec![ctx.padding_cross_start; self.columns];
+        let mut col_heights = vec![ctx.padding_cross_start; columns];
 
-        for item in items.iter_mut() {
+        for (i, item) in items.iter_mut().enumerate() {
             let (m_main_start, m_main_end, m_cross_start, m_cross_end) = axis.extract_margin_sides(item.margin);
 
-            let (shortest_col_idx, &current_y) = col_heights
-                .iter()
-                .enumerate()
-                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
-                .unwrap();
+            let (shortest_col_idx, current_y) = match ctx.masonry.order {
+                UMasonryOrder::ShortestFirst => col_heights
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+                    .unwrap(),
+                UMasonryOrder::SourceOrder => (i % columns, col_heights[i % columns]),
+            };
 
             let pos_main = ctx.padding_main_start + (shortest_col_idx as f32 * (col_width + ctx.main_gap)) + m_main_start;
             let pos_cross = current_y + m_cross_start;
             item.result.pos = axis.to_world(pos_main, pos_cross);
 
-            let (_, child_cross) = axis.from_world(item.result.size);
+            let (_, mut child_cross) = axis.from_world(item.result.size);
             let new_main = (col_width - m_main_start - m_main_end).max(0.0);
+            if let Some(ratio) = item.spec.aspect_ratio {
+                child_cross = axis.clamp_cross(&item.spec, axis.cross_from_ratio(ratio, new_main), f32::INFINITY);
+            }
             item.result.size = axis.to_world(new_main, child_cross);
 
             col_heights[shortest_col_idx] += m_cross_start + child_cross + m_cross_end + ctx.cross_gap;
@@ -759,27 +1266,47 @@ impl LayoutPlacer for RadialPlacer {
         if count == 0 {
             return Vec2::ZERO;
         }
+        let radial = &ctx.radial;
 
         let world_size = axis.to_world(ctx.container_main_size, ctx.container_cross_size);
         let w = world_size.x;
         let h = world_size.y;
 
         let min_dim = w.min(h);
-        let radius = if min_dim < 50.0 {
-            let total_item_width: f32 = items.iter().map(|i| axis.from_world(i.result.size).0).sum();
-            (total_item_width * 1.5 / std::f32::consts::TAU).max(100.0)
-        } else {
-            (min_dim * 0.5) - 20.0
+        let content = axis.to_world(
+            ctx.container_main_size - ctx.padding_main_start - ctx.padding_main_end,
+            ctx.container_cross_size - ctx.padding_cross_start * 2.0,
+        );
+        let radius = match radial.radius.resolve(content.min_element().max(0.0) * 0.5) {
+            Some(r) => r.max(0.0),
+            None if min_dim < 50.0 => {
+                let total_item_width: f32 = items.iter().map(|i| axis.from_world(i.result.size).0).sum();
+                (total_item_width * 1.5 / std::f32::consts::TAU).max(100.0)
+            }
+            None => (min_dim * 0.5) - 20.0,
         };
 
-        let angle_step = std::f32::consts::TAU / count as f32;
+        // Items with a fixed angle keep it; the others share the arc.
+        let auto_count = items.iter().filter(|i| i.spec.radial_angle.is_none()).count();
+        let angle_step = if radial.sweep_angle.abs() >= 360.0 {
+            radial.sweep_angle / auto_count.max(1) as f32
+        } else if auto_count > 1 {
+            radial.sweep_angle / (auto_count - 1) as f32
+        } else {
+            0.0
+        };
+        let direction = match radial.direction {
+            URadialDirection::Clockwise => 1.0,
+            URadialDirection::CounterClockwise => -1.0,
+        };
 
         let mut min_x = f32::INFINITY;
         let mut max_x = f32::NEG_INFINITY;
         let mut min_y = f32::INFINITY;
         let mut max_y = f32::NEG_INFINITY;
+        let mut auto_index = 0;
 
-        for (i, item) in items.iter_mut().enumerate() {
+        for item in items.iter_mut() {
             if item.result.size.x == 0.0 {
                 item.result.size.x = 50.0;
             }
@@ -787,7 +1314,13 @@ impl LayoutPlacer for RadialPlacer {
                 item.result.size.y = 50.0;
             }
 
-            let angle = (i as f32 * angle_step) - std::f32::consts::FRAC_PI_2;
+            let degrees = item.spec.radial_angle.unwrap_or_else(|| {
+                let degrees = radial.start_angle + direction * angle_step * auto_index as f32;
+                auto_index += 1;
+                degrees
+            });
+            // 0° is the top; y grows downwards, so increasing angles turn clockwise.
+            let angle = degrees.to_radians() - std::f32::consts::FRAC_PI_2;
             let cx = radius * angle.cos();
             let cy = radius * angle.sin();
 
@@ -800,6 +1333,8 @@ impl LayoutPlacer for RadialPlacer {
             max_y = max_y.max(pos_y + item.result.size.y);
 
             item.result.pos = Vec2::new(pos_x, pos_y);
+            // Bevy rotates counter-clockwise, screen angles run clockwise.
+            item.result.rotation = radial.rotate_children.then(|| -degrees.to_radians());
         }
 
         let content_width = max_x - min_x;
@@ -832,6 +1367,17 @@ mod tests {
             height_mode: SolverSizeMode::Fixed,
             height_val: 0.0,
             height_flex: 0.0,
+            min_width: UVal::Auto,
+            max_width: UVal::Auto,
+            min_height: UVal::Auto,
+            max_height: UVal::Auto,
+            content_width: 0.0,
+            content_height: 0.0,
+            min_content_width: 0.0,
+            min_content_height: 0.0,
+            first_baseline: None,
+            last_baseline: None,
+            aspect_ratio: None,
             position_type: UPositionType::Relative,
             left: UVal::Auto,
             right: UVal::Auto,
@@ -849,6 +1395,11 @@ mod tests {
             grid_column_span: 1,
             grid_row_start: None,
             grid_row_span: 1,
+            grid_area: None,
+            grid_column_lines: UGridLines::default(),
+            grid_row_lines: UGridLines::default(),
+            radial_angle: None,
+            custom: ULayoutCustomItem::default(),
             order: 0,
         }
     }
@@ -874,25 +1425,138 @@ mod tests {
             grid_auto_flow: UGridAutoFlow::Row,
             grid_auto_rows: UTrackSize::Auto,
             grid_auto_columns: UTrackSize::Auto,
+            grid_template_areas: Vec::new(),
+            grid_column_line_names: Vec::new(),
+            grid_row_line_names: Vec::new(),
+            masonry: ULayoutMasonryContainer::default(),
+            radial: URadialLayout::default(),
+            custom_placer: None,
         }
     }
 
     #[test]
     fn track_resolution_uses_fr_distribution() {
-        let tracks = resolve_track_sizes(
+        let defs = expand_track_template(
             &[UTrackSize::Px(100.0), UTrackSize::Fr(1.0), UTrackSize::Fr(2.0)],
             3,
             UTrackSize::Auto,
             400.0,
             10.0,
-            3,
         );
+        let tracks = resolve_track_sizes(&defs, &[], 400.0, 10.0);
 
         assert_eq!(tracks.len(), 3);
         assert!((tracks[0] - 100.0).abs() < 0.001);
         assert!(tracks[2] > tracks[1]);
     }
 
+    #[test]
+    fn minmax_track_grows_to_max_before_fr_distribution() {
+        let defs = expand_track_template(
+            &[UTrackSize::MinMax(UTrackBreadth::Px(50.0), UTrackBreadth::Px(100.0)), UTrackSize::Fr(1.0)],
+            1,
+            UTrackSize::Auto,
+            300.0,
+            0.0,
+        );
+        let tracks = resolve_track_sizes(&defs, &[], 300.0, 0.0);
+
+        assert_eq!(tracks, vec![100.0, 200.0]);
+    }
+
+    #[test]
+    fn content_tracks_follow_item_contributions() {
+        let defs = expand_track_template(
+            &[UTrackSize::FitContent(80.0), UTrackSize::FitContent(80.0), UTrackSize::MaxContent],
+            1,
+            UTrackSize::Auto,
+            1000.0,
+            0.0,
+        );
+        let items = [
+            TrackItem { start: 0, span: 1, min_size: 30.0, size: 120.0 },
+            TrackItem { start: 1, span: 1, min_size: 30.0, size: 40.0 },
+            TrackItem { start: 2, span: 1, min_size: 30.0, size: 150.0 },
+        ];
+        let tracks = resolve_track_sizes(&defs, &items, 1000.0, 0.0);
+
+        assert_eq!(tracks, vec![80.0, 40.0, 150.0]);
+    }
+
+    #[test]
+    fn min_content_tracks_and_auto_minimums_use_min_content_contributions() {
+        let items = [
+            TrackItem { start: 0, span: 1, min_size: 40.0, size: 120.0 },
+            TrackItem { start: 1, span: 1, min_size: 40.0, size: 120.0 },
+        ];
+        let content = [GridTrack::from(UTrackSize::MinContent), GridTrack::from(UTrackSize::MaxContent)];
+        assert_eq!(resolve_track_sizes(&content, &items, 1000.0, 0.0), vec![40.0, 120.0]);
+
+        // An `auto` track may shrink to its min-content size when space runs out.
+        let auto = [GridTrack::from(UTrackSize::Auto), GridTrack::from(UTrackSize::Px(100.0))];
+        assert_eq!(resolve_track_sizes(&auto, &items[..1], 150.0, 0.0), vec![50.0, 100.0]);
+    }
+
+    #[test]
+    fn auto_fill_counts_a_gap_per_repeated_track() {
+        let defs = expand_track_template(
+            &[
+                UTrackSize::repeat(UGridRepeat::Count(3), UTrackSize::Px(50.0)),
+                UTrackSize::repeat(UGridRepeat::AutoFill, UTrackSize::Px(100.0)),
+            ],
+            1,
+            UTrackSize::Auto,
+            380.0,
+            10.0,
+        );
+
+        // 3 * 50 + 100 + 3 gaps = 280; a second auto-fill track would need 390.
+        assert_eq!(defs.len(), 4);
+    }
+
+    #[test]
+    fn auto_fill_repeats_as_many_tracks_as_fit() {
+        let defs = expand_track_template(
+            &[UTrackSize::repeat(UGridRepeat::AutoFill, UTrackSize::Px(100.0))],
+            1,
+            UTrackSize::Auto,
+            350.0,
+            10.0,
+        );
+
+        assert_eq!(defs.len(), 3);
+    }
+
+    #[test]
+    fn grid_auto_fit_collapses_empty_tracks() {
+        let mut r1 = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r2 = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut s1 = default_spec();
+        s1.width_mode = SolverSizeMode::Content;
+        let mut s2 = default_spec();
+        s2.width_mode = SolverSizeMode::Content;
+        let mut items = vec![
+            SolverItem { spec: s1, result: &mut r1, margin: USides::default() },
+            SolverItem { spec: s2, result: &mut r2, margin: USides::default() },
+        ];
+
+        let mut ctx = base_ctx();
+        ctx.container_main_size = 500.0;
+        ctx.grid_template_columns = vec![UTrackSize::repeat(
+            UGridRepeat::AutoFit,
+            UTrackSize::MinMax(UTrackBreadth::Px(100.0), UTrackBreadth::Fr(1.0)),
+        )];
+
+        let placer = GridPlacer { columns: 1 };
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        let used = placer.place(&mut items, &axis, &ctx);
+
+        assert_eq!(r1.size.x, 250.0);
+        assert_eq!(r2.pos.x, 250.0);
+        assert_eq!(r2.size.x, 250.0);
+        assert_eq!(used.x, 500.0);
+    }
+
     #[test]
     fn safe_overflow_keeps_non_negative_offset() {
         let offset = alignment_offset(UAlignSelfExt::Center, -20.0, UOverflowPosition::Safe);
@@ -919,6 +1583,7 @@ mod tests {
         let mut result = SolverResult {
             size: Vec2::new(50.0, 30.0),
             pos: Vec2::ZERO,
+            ..Default::default()
         };
         let mut spec = default_spec();
         spec.justify_self_ext = Some(UAlignSelfExt::Center);
@@ -943,14 +1608,14 @@ mod tests {
 
     #[test]
     fn wrap_and_align_content_center_pushes_lines_down() {
-        let mut r1 = SolverResult { size: Vec2::new(70.0, 20.0), pos: Vec2::ZERO };
-        let mut r2 = SolverResult { size: Vec2::new(70.0, 20.0), pos: Vec2::ZERO };
-        let mut r3 = SolverResult { size: Vec2::new(70.0, 20.0), pos: Vec2::ZERO };
+        let mut r1 = SolverResult { size: Vec2::new(70.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r2 = SolverResult { size: Vec2::new(70.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r3 = SolverResult { size: Vec2::new(70.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
         let spec = default_spec();
         let mut items = vec![
-            SolverItem { spec, result: &mut r1, margin: USides::default() },
-            SolverItem { spec, result: &mut r2, margin: USides::default() },
-            SolverItem { spec, result: &mut r3, margin: USides::default() },
+            SolverItem { spec: spec.clone(), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec.clone(), result: &mut r2, margin: USides::default() },
+            SolverItem { spec: spec.clone(), result: &mut r3, margin: USides::default() },
         ];
 
         let mut ctx = base_ctx();
@@ -972,12 +1637,12 @@ mod tests {
 
     #[test]
     fn row_gap_is_applied_between_wrapped_lines() {
-        let mut r1 = SolverResult { size: Vec2::new(70.0, 10.0), pos: Vec2::ZERO };
-        let mut r2 = SolverResult { size: Vec2::new(70.0, 10.0), pos: Vec2::ZERO };
+        let mut r1 = SolverResult { size: Vec2::new(70.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r2 = SolverResult { size: Vec2::new(70.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
         let spec = default_spec();
         let mut items = vec![
-            SolverItem { spec, result: &mut r1, margin: USides::default() },
-            SolverItem { spec, result: &mut r2, margin: USides::default() },
+            SolverItem { spec: spec.clone(), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec.clone(), result: &mut r2, margin: USides::default() },
         ];
 
         let mut ctx = base_ctx();
@@ -999,6 +1664,7 @@ mod tests {
         let mut result = SolverResult {
             size: Vec2::new(40.0, 20.0),
             pos: Vec2::ZERO,
+            ..Default::default()
         };
         let spec = default_spec();
         let mut items = vec![SolverItem {
@@ -1022,11 +1688,178 @@ mod tests {
         assert!((result.pos.y - 40.0).abs() < 0.1);
     }
 
+    #[test]
+    fn flex_row_aligns_items_on_first_baseline() {
+        let mut small = SolverResult { size: Vec2::new(30.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut large = SolverResult { size: Vec2::new(30.0, 40.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut icon = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+
+        let mut small_spec = default_spec();
+        small_spec.first_baseline = Some(16.0);
+        let mut large_spec = default_spec();
+        large_spec.first_baseline = Some(30.0);
+
+        let mut items = vec![
+            SolverItem { spec: small_spec, result: &mut small, margin: USides::default() },
+            SolverItem { spec: large_spec, result: &mut large, margin: USides::default() },
+            SolverItem { spec: default_spec(), result: &mut icon, margin: USides::default() },
+        ];
+
+        let mut ctx = base_ctx();
+        ctx.align_items = UAlignItems::Baseline;
+
+        let placer = FlexPlacer;
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        placer.place(&mut items, &axis, &ctx);
+
+        // All baselines meet at y = 30; the icon has none and uses its bottom edge.
+        assert!((small.pos.y - 14.0).abs() < 0.1);
+        assert!((large.pos.y - 0.0).abs() < 0.1);
+        assert!((icon.pos.y - 20.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn flex_row_last_baseline_items_sit_at_line_end() {
+        let mut small = SolverResult { size: Vec2::new(30.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut large = SolverResult { size: Vec2::new(30.0, 40.0), pos: Vec2::ZERO, ..Default::default() };
+
+        let mut small_spec = default_spec();
+        small_spec.align_self_ext = Some(UAlignSelfExt::LastBaseline);
+        small_spec.last_baseline = Some(16.0);
+        let mut large_spec = default_spec();
+        large_spec.align_self_ext = Some(UAlignSelfExt::LastBaseline);
+        large_spec.last_baseline = Some(30.0);
+
+        let mut items = vec![
+            SolverItem { spec: small_spec, result: &mut small, margin: USides::default() },
+            SolverItem { spec: large_spec, result: &mut large, margin: USides::default() },
+        ];
+
+        let ctx = base_ctx();
+        let placer = FlexPlacer;
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        placer.place(&mut items, &axis, &ctx);
+
+        assert!((small.pos.y + 16.0 - 90.0).abs() < 0.1);
+        assert!((large.pos.y + 30.0 - 90.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn grid_row_aligns_items_on_first_baseline() {
+        let mut small = SolverResult { size: Vec2::new(50.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut large = SolverResult { size: Vec2::new(50.0, 40.0), pos: Vec2::ZERO, ..Default::default() };
+
+        let mut small_spec = default_spec();
+        small_spec.height_mode = SolverSizeMode::Content;
+        small_spec.align_self_ext = Some(UAlignSelfExt::FirstBaseline);
+        small_spec.first_baseline = Some(16.0);
+        let mut large_spec = default_spec();
+        large_spec.height_mode = SolverSizeMode::Content;
+        large_spec.align_self_ext = Some(UAlignSelfExt::FirstBaseline);
+        large_spec.first_baseline = Some(30.0);
+
+        let mut items = vec![
+            SolverItem { spec: small_spec, result: &mut small, margin: USides::default() },
+            SolverItem { spec: large_spec, result: &mut large, margin: USides::default() },
+        ];
+
+        let mut ctx = base_ctx();
+        ctx.grid_template_columns = vec![UTrackSize::Px(50.0), UTrackSize::Px(50.0)];
+
+        let placer = GridPlacer { columns: 2 };
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        placer.place(&mut items, &axis, &ctx);
+
+        // Baseline items are not stretched to the row.
+        assert!((small.size.y - 20.0).abs() < 0.1);
+        assert!((small.pos.y - 14.0).abs() < 0.1);
+        assert!((large.pos.y - 0.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn grid_places_items_by_template_area() {
+        let mut header = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut sidebar = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut main = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+
+        let mut s_header = default_spec();
+        s_header.grid_area = Some("header".into());
+        s_header.width_mode = SolverSizeMode::Content;
+        let mut s_sidebar = default_spec();
+        s_sidebar.grid_area = Some("sidebar".into());
+        let mut s_main = default_spec();
+        s_main.grid_area = Some("main".into());
+        s_main.width_mode = SolverSizeMode::Content;
+        s_main.height_mode = SolverSizeMode::Content;
+
+        // Source order differs from the visual order on purpose.
+        let mut items = vec![
+            SolverItem { spec: s_main, result: &mut main, margin: USides::default() },
+            SolverItem { spec: s_sidebar, result: &mut sidebar, margin: USides::default() },
+            SolverItem { spec: s_header, result: &mut header, margin: USides::default() },
+        ];
+
+        let mut ctx = base_ctx();
+        ctx.container_main_size = 100.0;
+        ctx.container_cross_size = 60.0;
+        ctx.grid_template_columns = vec![UTrackSize::Px(30.0), UTrackSize::Px(70.0)];
+        ctx.grid_template_rows = vec![UTrackSize::Px(20.0), UTrackSize::Px(40.0)];
+        ctx.grid_template_areas = vec!["header header".into(), "sidebar main".into()];
+
+        let placer = GridPlacer { columns: 1 };
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        placer.place(&mut items, &axis, &ctx);
+
+        assert_eq!(header.pos, Vec2::new(0.0, 0.0));
+        assert_eq!(header.size.x, 100.0);
+        assert_eq!(sidebar.pos, Vec2::new(0.0, 20.0));
+        assert_eq!(main.pos, Vec2::new(30.0, 20.0));
+        assert_eq!(main.size, Vec2::new(70.0, 40.0));
+    }
+
+    #[test]
+    fn grid_line_placement_supports_names_negative_indices_and_span_to_end() {
+        let mut named = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut last = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut to_end = SolverResult { size: Vec2::new(10.0, 10.0), pos: Vec2::ZERO, ..Default::default() };
+
+        let mut s_named = default_spec();
+        s_named.grid_column_lines = UGridLines::new(UGridLine::named("content"), UGridLine::Auto);
+        let mut s_last = default_spec();
+        s_last.grid_column_lines = UGridLines::new(UGridLine::Index(-2), UGridLine::Index(-1));
+        s_last.width_mode = SolverSizeMode::Content;
+        let mut s_to_end = default_spec();
+        s_to_end.grid_column_lines = UGridLines::new(UGridLine::Index(2), UGridLine::SpanToEnd);
+        s_to_end.width_mode = SolverSizeMode::Content;
+
+        let mut items = vec![
+            SolverItem { spec: s_named, result: &mut named, margin: USides::default() },
+            SolverItem { spec: s_last, result: &mut last, margin: USides::default() },
+            SolverItem { spec: s_to_end, result: &mut to_end, margin: USides::default() },
+        ];
+
+        let mut ctx = base_ctx();
+        ctx.container_main_size = 100.0;
+        ctx.grid_template_columns = vec![UTrackSize::Px(25.0); 4];
+        ctx.grid_template_rows = vec![UTrackSize::Px(10.0); 3];
+        ctx.grid_column_line_names = vec![UGridLineName::new("content", 2)];
+
+        let placer = GridPlacer { columns: 1 };
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        placer.place(&mut items, &axis, &ctx);
+
+        assert_eq!(named.pos, Vec2::new(25.0, 0.0));
+        assert_eq!(last.pos.x, 75.0);
+        assert_eq!(last.size.x, 25.0);
+        assert_eq!(to_end.pos.x, 25.0);
+        assert_eq!(to_end.size.x, 75.0);
+    }
+
     #[test]
     fn grid_span_affects_auto_placement() {
-        let mut r1 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
-        let mut r2 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
-        let mut r3 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
+        let mut r1 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r2 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r3 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
 
         let mut s1 = default_spec();
         s1.grid_column_span = 2;
@@ -1054,13 +1887,42 @@ mod tests {
         assert!(r3.pos.y >= 30.0 - 0.1);
     }
 
+    #[test]
+    fn grid_reports_its_tracks() {
+        let mut r1 = SolverResult { size: Vec2::new(20.0, 20.0), ..Default::default() };
+        let mut r2 = SolverResult { size: Vec2::new(20.0, 20.0), ..Default::default() };
+        let mut s1 = default_spec();
+        s1.grid_column_span = 2;
+
+        let mut items = vec![
+            SolverItem { spec: s1, result: &mut r1, margin: USides::default() },
+            SolverItem { spec: default_spec(), result: &mut r2, margin: USides::default() },
+        ];
+
+        let mut ctx = base_ctx();
+        ctx.container_main_size = 100.0;
+        ctx.container_cross_size = 100.0;
+        ctx.main_gap = 10.0;
+        ctx.grid_template_columns = vec![UTrackSize::Px(40.0), UTrackSize::Px(50.0)];
+        ctx.grid_template_rows = vec![UTrackSize::Px(30.0), UTrackSize::Px(30.0)];
+
+        ctx.padding_main_start = 4.0;
+        ctx.padding_cross_start = 2.0;
+
+        let (_, tracks) = GridPlacer { columns: 2 }.place_with_tracks(&mut items, &AxisHelper::new(UFlexDirection::Row), &ctx);
+
+        let track = |start, size| ComputedTrack { start, size };
+        assert_eq!(tracks.columns, vec![track(4.0, 40.0), track(54.0, 50.0)]);
+        assert_eq!(tracks.rows, vec![track(2.0, 30.0), track(32.0, 30.0)]);
+    }
+
     #[test]
     fn grid_auto_flow_row_is_sparse_no_backfill() {
-        let mut r1 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
-        let mut r2 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
-        let mut r3 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
-        let mut r4 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
-        let mut r5 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO };
+        let mut r1 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r2 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r3 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r4 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r5 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
 
         let mut s1 = default_spec();
         s1.grid_column_span = 2;
@@ -1106,11 +1968,94 @@ mod tests {
         assert!(r5.pos.y >= 20.0 - 0.1);
     }
 
+    #[test]
+    fn grid_auto_flow_row_dense_backfills_holes() {
+        let mut r1 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r2 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r3 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r4 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+        let mut r5 = SolverResult { size: Vec2::new(20.0, 20.0), pos: Vec2::ZERO, ..Default::default() };
+
+        let mut s1 = default_spec();
+        s1.grid_column_span = 2;
+        s1.grid_row_span = 2;
+        let s2 = default_spec();
+        let mut s3 = default_spec();
+        s3.grid_row_span = 2;
+        let mut s4 = default_spec();
+        s4.grid_column_span = 2;
+        let s5 = default_spec();
+
+        let mut items = vec![
+            SolverItem { spec: s1, result: &mut r1, margin: USides::default() },
+            SolverItem { spec: s2, result: &mut r2, margin: USides::default() },
+            SolverItem { spec: s3, result: &mut r3, margin: USides::default() },
+            SolverItem { spec: s4, result: &mut r4, margin: USides::default() },
+            SolverItem { spec: s5, result: &mut r5, margin: USides::default() },
+        ];
+
+        let mut ctx = base_ctx();
+        ctx.container_main_size = 100.0;
+        ctx.container_cross_size = 100.0;
+        ctx.grid_columns = 4;
+        ctx.grid_template_columns = vec![UTrackSize::Px(25.0); 4];
+        ctx.grid_template_rows = vec![UTrackSize::Px(10.0); 4];
+        ctx.grid_auto_flow = UGridAutoFlow::RowDense;
+
+        let placer = GridPlacer { columns: 4 };
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        placer.place(&mut items, &axis, &ctx);
+
+        // The 1x1 item fills the row-2 hole left beside the 2-column item.
+        assert!((r4.pos.y - 20.0).abs() < 0.1);
+        assert!((r5.pos.x - 50.0).abs() < 0.1);
+        assert!((r5.pos.y - 10.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn grid_auto_flow_column_sparse_keeps_cursor_and_dense_backfills() {
+        // Fixed item at row 2 / column 1, then a 2-row item and a 1x1 item.
+        let place = |flow: UGridAutoFlow| {
+            let mut results = [SolverResult { size: Vec2::new(10.0, 10.0), ..Default::default() }; 3];
+            let mut fixed = default_spec();
+            fixed.grid_row_start = Some(2);
+            fixed.grid_column_start = Some(1);
+            let mut tall = default_spec();
+            tall.grid_row_span = 2;
+            let specs = [fixed, tall, default_spec()];
+
+            let mut items: Vec<SolverItem> = specs
+                .into_iter()
+                .zip(results.iter_mut())
+                .map(|(spec, result)| SolverItem { spec, result, margin: USides::default() })
+                .collect();
+            let mut ctx = base_ctx();
+            ctx.grid_columns = 2;
+            ctx.grid_template_columns = vec![UTrackSize::Px(25.0); 2];
+            ctx.grid_template_rows = vec![UTrackSize::Px(10.0); 3];
+            ctx.grid_auto_flow = flow;
+            GridPlacer { columns: 2 }.place(&mut items, &AxisHelper::new(UFlexDirection::Row), &ctx);
+            drop(items);
+            results
+        };
+
+        // The tall item cannot fit under the fixed one, so it starts column 2.
+        let sparse = place(UGridAutoFlow::Column);
+        assert_eq!(sparse[1].pos, Vec2::new(25.0, 0.0));
+        // Sparse continues below the tall item and leaves the row-1 hole.
+        assert_eq!(sparse[2].pos, Vec2::new(25.0, 20.0));
+
+        let dense = place(UGridAutoFlow::ColumnDense);
+        assert_eq!(dense[1].pos, Vec2::new(25.0, 0.0));
+        assert_eq!(dense[2].pos, Vec2::ZERO);
+    }
+
     #[test]
     fn grid_auto_item_stretches_to_cell_by_default() {
         let mut result = SolverResult {
             size: Vec2::ZERO,
             pos: Vec2::ZERO,
+            ..Default::default()
         };
 
         let mut spec = default_spec();
@@ -1143,4 +2088,136 @@ mod tests {
         assert!(result.pos.x.abs() < 0.1);
         assert!(result.pos.y.abs() < 0.1);
     }
+
+    #[test]
+    fn grid_stretch_is_clamped_to_item_max_size() {
+        let mut result = SolverResult {
+            size: Vec2::ZERO,
+            pos: Vec2::ZERO,
+            ..Default::default()
+        };
+
+        let mut spec = default_spec();
+        spec.width_mode = SolverSizeMode::Content;
+        spec.height_mode = SolverSizeMode::Content;
+        spec.max_width = UVal::Px(40.0);
+        spec.max_height = UVal::Percent(0.5);
+
+        let mut items = vec![SolverItem {
+            spec,
+            result: &mut result,
+            margin: USides::default(),
+        }];
+
+        let mut ctx = base_ctx();
+        ctx.grid_template_columns = vec![UTrackSize::Px(100.0)];
+        ctx.grid_template_rows = vec![UTrackSize::Px(60.0)];
+
+        let placer = GridPlacer { columns: 1 };
+        let axis = AxisHelper::new(UFlexDirection::Row);
+        placer.place(&mut items, &axis, &ctx);
+
+        assert!((result.size.x - 40.0).abs() < 0.1);
+        assert!((result.size.y - 30.0).abs() < 0.1);
+    }
+
+    fn radial_centers(radial: URadialLayout, angles: &[Option<f32>]) -> Vec<(Vec2, Option<f32>)> {
+        let mut results = vec![SolverResult { size: Vec2::splat(10.0), ..Default::default() }; angles.len()];
+        let mut items: Vec<SolverItem> = results
+            .iter_mut()
+            .zip(angles)
+            .map(|(result, &angle)| SolverItem {
+                spec: SolverSpec { radial_angle: angle, ..default_spec() },
+                result,
+                margin: USides::default(),
+            })
+            .collect();
+        let mut ctx = base_ctx();
+        ctx.radial = radial;
+
+        RadialPlacer.place(&mut items, &AxisHelper::new(UFlexDirection::Row), &ctx);
+
+        results.iter().map(|r| (r.pos + r.size * 0.5, r.rotation)).collect()
+    }
+
+    #[test]
+    fn radial_half_arc_follows_start_sweep_and_direction() {
+        let arc = URadialLayout {
+            start_angle: 0.0,
+            sweep_angle: 180.0,
+            radius: UVal::Px(100.0),
+            ..Default::default()
+        };
+
+        let cw = radial_centers(arc, &[None, None, None]);
+        assert!((cw[1].0 - cw[0].0 - Vec2::new(100.0, 100.0)).length() < 0.01);
+        assert!((cw[2].0 - cw[0].0 - Vec2::new(0.0, 200.0)).length() < 0.01);
+        assert_eq!(cw[0].1, None);
+
+        let ccw = radial_centers(URadialLayout { direction: URadialDirection::CounterClockwise, ..arc }, &[None, None, None]);
+        assert!((ccw[1].0 - ccw[0].0 - Vec2::new(-100.0, 100.0)).length() < 0.01);
+    }
+
+    #[test]
+    fn radial_item_angle_override_and_outward_rotation() {
+        let radial = URadialLayout {
+            radius: UVal::Percent(1.0),
+            rotate_children: true,
+            ..Default::default()
+        };
+
+        // Percent radius: half the 100x100 content box.
+        let placed = radial_centers(radial, &[None, Some(90.0)]);
+        assert!((placed[1].0 - placed[0].0 - Vec2::new(50.0, 50.0)).length() < 0.01);
+        assert_eq!(placed[0].1, Some(0.0));
+        assert!((placed[1].1.unwrap() + std::f32::consts::FRAC_PI_2).abs() < 0.001);
+    }
+
+    fn masonry_positions(masonry: ULayoutMasonryContainer, direction: UFlexDirection, sizes: &[Vec2]) -> Vec<Vec2> {
+        let mut results: Vec<SolverResult> = sizes.iter().map(|&size| SolverResult { size, ..Default::default() }).collect();
+        let mut items: Vec<SolverItem> = results
+            .iter_mut()
+            .map(|result| SolverItem { spec: default_spec(), result, margin: USides::default() })
+            .collect();
+        let mut ctx = base_ctx();
+        ctx.main_gap = 5.0;
+        ctx.masonry = masonry;
+
+        MasonryPlacer { columns: 2 }.place(&mut items, &AxisHelper::new(direction), &ctx);
+
+        results.iter().map(|r| r.pos).collect()
+    }
+
+    #[test]
+    fn masonry_min_column_width_derives_lane_count() {
+        let masonry = ULayoutMasonryContainer { min_column_width: Some(30.0), ..Default::default() };
+        let sizes = [Vec2::new(0.0, 10.0); 3];
+
+        let positions = masonry_positions(masonry, UFlexDirection::Row, &sizes);
+
+        // (100 + 5) / (30 + 5) = 3 lanes of 30px.
+        assert_eq!(positions, vec![Vec2::new(0.0, 0.0), Vec2::new(35.0, 0.0), Vec2::new(70.0, 0.0)]);
+    }
+
+    #[test]
+    fn masonry_source_order_fills_lanes_round_robin() {
+        let sizes = [Vec2::new(0.0, 50.0), Vec2::new(0.0, 10.0), Vec2::new(0.0, 10.0)];
+
+        let shortest = masonry_positions(ULayoutMasonryContainer::default(), UFlexDirection::Row, &sizes);
+        assert_eq!(shortest[2], Vec2::new(52.5, 10.0));
+
+        let source = ULayoutMasonryContainer { order: UMasonryOrder::SourceOrder, ..Default::default() };
+        let ordered = masonry_positions(source, UFlexDirection::Row, &sizes);
+        assert_eq!(ordered[2], Vec2::new(0.0, 50.0));
+    }
+
+    #[test]
+    fn masonry_horizontal_flow_stacks_items_along_rows() {
+        let horizontal = ULayoutMasonryContainer { flow: UMasonryFlow::Horizontal, ..Default::default() };
+        let sizes = [Vec2::new(40.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(10.0, 0.0)];
+
+        let positions = masonry_positions(horizontal, horizontal.flow.direction(), &sizes);
+
+        assert_eq!(positions, vec![Vec2::new(0.0, 0.0), Vec2::new(0.0, 52.5), Vec2::new(10.0, 52.5)]);
+    }
 }
@@ -0,0 +1,168 @@
+use crate::internal_prelude::*;
+
+/// Cells covered by a named area, as 0-based line positions (end exclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridAreaRect {
+    pub column_start: usize,
+    pub column_end: usize,
+    pub row_start: usize,
+    pub row_end: usize,
+}
+
+/// Named areas and lines of a grid container, parsed once per placement.
+#[derive(Debug, Default)]
+pub struct GridNames {
+    areas: Vec<(String, GridAreaRect)>,
+    column_lines: Vec<(String, usize)>,
+    row_lines: Vec<(String, usize)>,
+    /// Columns implied by `template_areas`.
+    pub area_columns: usize,
+    /// Rows implied by `template_areas`.
+    pub area_rows: usize,
+}
+
+impl GridNames {
+    /// Parses `template_areas`. A non-rectangular area uses its bounding box.
+    pub fn new(
+        template_areas: &[String],
+        column_line_names: &[UGridLineName],
+        row_line_names: &[UGridLineName],
+    ) -> Self {
+        let mut names = GridNames {
+            column_lines: column_line_names
+                .iter()
+                .filter(|l| l.line > 0)
+                .map(|l| (l.name.clone(), l.line as usize - 1))
+                .collect(),
+            row_lines: row_line_names
+                .iter()
+                .filter(|l| l.line > 0)
+                .map(|l| (l.name.clone(), l.line as usize - 1))
+                .collect(),
+            ..Default::default()
+        };
+
+        for (row, row_str) in template_areas.iter().enumerate() {
+            let mut cols_in_row = 0;
+            for (col, cell) in row_str.split_whitespace().enumerate() {
+                cols_in_row = col + 1;
+                if cell.chars().all(|c| c == '.') {
+                    continue;
+                }
+                match names.areas.iter_mut().find(|(name, _)| name == cell) {
+                    Some((_, rect)) => {
+                        rect.column_start = rect.column_start.min(col);
+                        rect.column_end = rect.column_end.max(col + 1);
+                        rect.row_start = rect.row_start.min(row);
+                        rect.row_end = rect.row_end.max(row + 1);
+                    }
+                    None => names.areas.push((
+                        cell.to_string(),
+                        GridAreaRect { column_start: col, column_end: col + 1, row_start: row, row_end: row + 1 },
+                    )),
+                }
+            }
+            if cols_in_row > 0 {
+                names.area_rows = row + 1;
+            }
+            names.area_columns = names.area_columns.max(cols_in_row);
+        }
+
+        names
+    }
+
+    pub fn area(&self, name: &str) -> Option<GridAreaRect> {
+        self.areas.iter().find(|(n, _)| n == name).map(|(_, rect)| *rect)
+    }
+
+    /// Finds a named line: explicit names first, then `<area>-start`/`<area>-end`,
+    /// then a bare area name on the requested edge.
+    fn named_line(&self, name: &str, is_column: bool, is_start: bool) -> Option<usize> {
+        let explicit = if is_column { &self.column_lines } else { &self.row_lines };
+        if let Some((_, line)) = explicit.iter().find(|(n, _)| n == name) {
+            return Some(*line);
+        }
+
+        let edge = |rect: GridAreaRect, start: bool| match (is_column, start) {
+            (true, true) => rect.column_start,
+            (true, false) => rect.column_end,
+            (false, true) => rect.row_start,
+            (false, false) => rect.row_end,
+        };
+        let suffixed = |suffix: &str, start: bool| {
+            name.strip_suffix(suffix)
+                .and_then(|area| self.area(area))
+                .map(|rect| edge(rect, start))
+        };
+        suffixed("-start", true)
+            .or_else(|| suffixed("-end", false))
+            .or_else(|| self.area(name).map(|rect| edge(rect, is_start)))
+    }
+}
+
+/// Resolves an item's placement on one axis to a 0-based start line (or `None`
+/// for auto-placement) and a span.
+///
+/// `explicit` is the number of explicit tracks; negative indices and
+/// `SpanToEnd` count from its last line. Without line placement the legacy
+/// `start`/`span` fields are used.
+pub fn resolve_grid_axis(
+    lines: &UGridLines,
+    legacy_start: Option<u32>,
+    legacy_span: u32,
+    names: &GridNames,
+    is_column: bool,
+    explicit: usize,
+) -> (Option<usize>, usize) {
+    let default_span = legacy_span.max(1) as usize;
+    if lines.is_auto() {
+        return (legacy_start.map(|v| v.saturating_sub(1) as usize), default_span);
+    }
+
+    let line_pos = |line: &UGridLine, is_start: bool| -> Option<usize> {
+        match line {
+            UGridLine::Index(n) if *n > 0 => Some(*n as usize - 1),
+            UGridLine::Index(n) if *n < 0 => Some((explicit as i32 + 1 + n).max(0) as usize),
+            UGridLine::Named(name) => names.named_line(name, is_column, is_start),
+            _ => None,
+        }
+    };
+
+    // `area-name / auto` ends on the same area.
+    let end = match (&lines.start, &lines.end) {
+        (UGridLine::Named(name), UGridLine::Auto) if names.area(name).is_some() => UGridLine::Named(name.clone()),
+        (_, end) => end.clone(),
+    };
+
+    match line_pos(&lines.start, true) {
+        Some(start) => {
+            let span = match &end {
+                UGridLine::SpanToEnd => explicit.saturating_sub(start).max(1),
+                UGridLine::Span(n) => (*n).max(1) as usize,
+                _ => match line_pos(&end, false) {
+                    Some(end) if end > start => end - start,
+                    // Like CSS, reversed lines are swapped.
+                    Some(end) if end < start => return (Some(end), start - end),
+                    Some(_) => 1,
+                    None => default_span,
+                },
+            };
+            (Some(start), span)
+        }
+        None => {
+            let span = match (&lines.start, &end) {
+                (UGridLine::Span(n), _) | (_, UGridLine::Span(n)) => (*n).max(1) as usize,
+                _ => default_span,
+            };
+            match line_pos(&end, false) {
+                Some(0) => (Some(0), span),
+                Some(end) => {
+                    let start = end.saturating_sub(span);
+                    (Some(start), end - start)
+                }
+                None if end == UGridLine::SpanToEnd => (Some(0), explicit.max(1)),
+                None => (None, span),
+            }
+        }
+    }
+}
@@ -1,9 +1,11 @@
 mod display;
+mod grid_areas;
 // mod justify_content;
 
 pub mod prelude {
     pub use crate::layout::algorithms::places::{
         display::*,
+        grid_areas::*,
         // justify_content::*,
     };
 }
\ No newline at end of file
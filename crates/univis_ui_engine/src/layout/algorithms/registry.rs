@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use crate::internal_prelude::*;
+
+/// A registered placer, shared by every container that uses its id.
+///
+/// Registered placers must be `Send + Sync`: the registry is a resource and
+/// containers may be solved in parallel.
+#[derive(Clone)]
+pub struct SharedPlacer(pub Arc<dyn LayoutPlacer + Send + Sync>);
+
+impl LayoutPlacer for SharedPlacer {
+    fn place(&self, items: &mut [SolverItem], axis: &AxisHelper, ctx: &PlacementContext) -> Vec2 {
+        self.0.place(items, axis, ctx)
+    }
+}
+
+impl std::fmt::Debug for SharedPlacer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedPlacer").finish_non_exhaustive()
+    }
+}
+
+/// Placers for `UDisplay::Custom(id)`, keyed by id.
+#[derive(Resource, Default, Debug)]
+pub struct LayoutPlacerRegistry {
+    placers: HashMap<&'static str, SharedPlacer>,
+}
+
+impl LayoutPlacerRegistry {
+    /// Registers `placer` under `id`, replacing any previous one.
+    pub fn insert(&mut self, id: &'static str, placer: impl LayoutPlacer + Send + Sync + 'static) {
+        self.placers.insert(id, SharedPlacer(Arc::new(placer)));
+    }
+
+    pub fn get(&self, id: &str) -> Option<SharedPlacer> {
+        self.placers.get(id).cloned()
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.placers.contains_key(id)
+    }
+}
+
+/// Registers custom layout placers on the app.
+pub trait LayoutPlacerAppExt {
+    /// Makes `P` the placer of containers with `UDisplay::Custom(id)`.
+    fn register_layout_placer<P: LayoutPlacer + Send + Sync + Default + 'static>(&mut self, id: &'static str) -> &mut Self;
+}
+
+impl LayoutPlacerAppExt for App {
+    fn register_layout_placer<P: LayoutPlacer + Send + Sync + Default + 'static>(&mut self, id: &'static str) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(LayoutPlacerRegistry::default)
+            .insert(id, P::default());
+        self
+    }
+}
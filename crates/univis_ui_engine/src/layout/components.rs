@@ -16,10 +16,27 @@ pub struct LayoutDepth(pub usize);
 /// This value is calculated during the **Upward Pass** (`pass_up`).
 /// It represents how much space the element *wants* based on its content and children,
 /// before any external constraints are applied.
-#[derive(Component, Default, Debug, Clone, Copy, Reflect)]
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Reflect)]
 pub struct IntrinsicSize {
     pub width: f32,
     pub height: f32,
+    /// Min-content size: the smallest box the content fits without
+    /// overflowing, e.g. the widest word of a text or the widest item of a
+    /// wrapping row. Zero on the axes of a scroll container.
+    pub min_width: f32,
+    pub min_height: f32,
+}
+
+/// Baseline offsets of a node, measured from the top edge of its box.
+///
+/// Text widgets publish it from their font metrics, and the upward pass derives
+/// it for containers from their first/last child. `None` means the node has no
+/// baseline of its own; alignment then uses its bottom edge.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UBaseline {
+    pub first: Option<f32>,
+    pub last: Option<f32>,
 }
 
 /// A global resource that tracks the maximum depth of the UI tree.
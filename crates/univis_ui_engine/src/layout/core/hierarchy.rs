@@ -45,4 +45,83 @@ fn traverse_and_mark(
         }
     }
     current_max
-}
\ No newline at end of file
+}
+
+/// Nodes whose inherited direction may have changed.
+type DirectionChangedFilter = Or<(Changed<UDirection>, Changed<ChildOf>, Added<UNode>)>;
+
+/// Resolves `ComputedDirection` for every node: its own `UDirection`, or its parent's.
+///
+/// Only subtrees whose direction can have changed are walked: nodes whose
+/// `UDirection` was set or removed, new nodes, and reparented nodes.
+pub fn propagate_direction(
+    changed_query: Query<Entity, DirectionChangedFilter>,
+    mut removed_directions: RemovedComponents<UDirection>,
+    parents_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    direction_query: Query<&UDirection>,
+    mut computed_query: Query<&mut ComputedDirection>,
+) {
+    let dirty: Vec<Entity> = changed_query.iter().chain(removed_directions.read()).collect();
+    for entity in dirty {
+        // الاتجاه الموروث من أقرب سلف يحدده
+        let inherited = parents_query
+            .iter_ancestors(entity)
+            .find_map(|ancestor| direction_query.get(ancestor).ok().copied())
+            .unwrap_or(UDirection::Ltr);
+        traverse_and_set_direction(
+            entity,
+            inherited,
+            &children_query,
+            &direction_query,
+            &mut computed_query,
+        );
+    }
+}
+
+fn traverse_and_set_direction(
+    entity: Entity,
+    inherited: UDirection,
+    children_q: &Query<&Children>,
+    direction_q: &Query<&UDirection>,
+    computed_q: &mut Query<&mut ComputedDirection>,
+) {
+    let direction = direction_q.get(entity).copied().unwrap_or(inherited);
+    if let Ok(mut computed) = computed_q.get_mut(entity) {
+        computed.set_if_neq(ComputedDirection(direction));
+    }
+
+    if let Ok(children) = children_q.get(entity) {
+        for &child in children {
+            traverse_and_set_direction(child, direction, children_q, direction_q, computed_q);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction_is_inherited_and_follows_changes() {
+        let mut app = App::new();
+        app.add_systems(Update, propagate_direction);
+
+        let root = app.world_mut().spawn((UNode::default(), UDirection::Rtl)).id();
+        let child = app.world_mut().spawn((UNode::default(), ChildOf(root))).id();
+        let grandchild = app.world_mut().spawn((UNode::default(), ChildOf(child))).id();
+        app.update();
+        assert!(app.world().get::<ComputedDirection>(grandchild).unwrap().is_rtl());
+
+        app.world_mut().entity_mut(root).remove::<UDirection>();
+        app.update();
+        assert!(!app.world().get::<ComputedDirection>(grandchild).unwrap().is_rtl());
+
+        // A subtree moved under an `Rtl` parent takes its direction.
+        let other = app.world_mut().spawn((UNode::default(), UDirection::Rtl)).id();
+        app.world_mut().entity_mut(child).insert(ChildOf(other));
+        app.update();
+        assert!(app.world().get::<ComputedDirection>(child).unwrap().is_rtl());
+        assert!(app.world().get::<ComputedDirection>(grandchild).unwrap().is_rtl());
+    }
+}
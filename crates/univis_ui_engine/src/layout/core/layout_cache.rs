@@ -18,6 +18,24 @@ pub struct LayoutCache {
     
     /// آخر عمق أقصى معروف
     last_max_depth: usize,
+
+    /// مدخلات ونتيجة آخر حل لكل حاوية
+    solved: HashMap<Entity, (SolveInputs, Vec2)>,
+
+    /// آخر UNode رآه تتبع التغييرات (لتمييز تغييرات الرسم فقط)
+    node_specs: HashMap<Entity, UNode>,
+}
+
+/// Everything a container's solve reads besides its own and its children's
+/// components, which the dirty flags cover.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolveInputs {
+    /// Size given by the parent (or the root size).
+    pub container_size: Vec2,
+    /// Parent content box, the base of percentage limits.
+    pub limit_base: Vec2,
+    /// Viewport and font sizes used by relative units.
+    pub units: UUnitContext,
 }
 
 impl LayoutCache {
@@ -57,6 +75,27 @@ impl LayoutCache {
         self.dirty_nodes.insert(entity);
     }
 
+    /// تعليم الآباء كمتسخين حتى أول حد إعادة تخطيط (يُعلَّم ويتوقف عنده).
+    ///
+    /// حجم الحد لا يتغير مع أبنائه (انظر `UNode::is_relayout_boundary`)، فلا داعي
+    /// لإعادة قياس ما فوقه.
+    pub fn mark_dirty_ancestors_until(
+        &mut self,
+        entity: Entity,
+        parents_query: &Query<&ChildOf>,
+        is_boundary: impl Fn(Entity) -> bool,
+    ) {
+        let mut current = entity;
+
+        while let Ok(parent) = parents_query.get(current) {
+            current = parent.get();
+            self.dirty_nodes.insert(current);
+            if is_boundary(current) {
+                break;
+            }
+        }
+    }
+
     /// تعليم عقدة وكل أبنائها كمتسخة
     pub fn mark_dirty_recursive(
         &mut self,
@@ -117,6 +156,50 @@ impl LayoutCache {
         self.intrinsic_sizes.get(&entity).copied()
     }
 
+    /// Size from the last solve of a container whose inputs didn't change,
+    /// when neither it nor any of its children is dirty.
+    pub fn reusable_solve(&self, entity: Entity, children: &[Entity], inputs: &SolveInputs) -> Option<Vec2> {
+        if self.is_dirty(entity) || children.iter().any(|child| self.is_dirty(*child)) {
+            return None;
+        }
+        self.solved
+            .get(&entity)
+            .filter(|(previous, _)| previous == inputs)
+            .map(|(_, size)| *size)
+    }
+
+    /// Records a container's solve for `reusable_solve`.
+    pub fn record_solve(&mut self, entity: Entity, inputs: SolveInputs, final_size: Vec2) {
+        self.solved.insert(entity, (inputs, final_size));
+    }
+
+    /// يحفظ `node` ويعيد `true` إذا تغير فيه ما يؤثر على التخطيط
+    /// (انظر `UNode::layout_eq`). العقدة الجديدة تُعد متغيرة.
+    pub fn update_node_spec(&mut self, entity: Entity, node: &UNode) -> bool {
+        match self.node_specs.get_mut(&entity) {
+            Some(previous) if previous.layout_eq(node) => {
+                *previous = node.clone();
+                false
+            }
+            Some(previous) => {
+                *previous = node.clone();
+                true
+            }
+            None => {
+                self.node_specs.insert(entity, node.clone());
+                true
+            }
+        }
+    }
+
+    /// نسيان كل ما يخص عقدة محذوفة
+    pub fn forget(&mut self, entity: Entity) {
+        self.node_specs.remove(&entity);
+        self.intrinsic_sizes.remove(&entity);
+        self.solved.remove(&entity);
+        self.dirty_nodes.remove(&entity);
+    }
+
     /// زيادة عداد الإطارات
     pub fn increment_frame(&mut self) {
         self.frame_count += 1;
@@ -149,33 +232,49 @@ pub fn track_layout_changes(
     nodes: Query<
         (
             Entity,
-            Option<&Children>,
+            Option<Ref<Children>>,
             Ref<UNode>,
             Option<Ref<ULayout>>,
             Option<Ref<USelf>>,
+            Option<Ref<UGridPlacement>>,
             Ref<IntrinsicSize>,
+            Option<Ref<ComputedDirection>>,
+            Option<Ref<UZIndex>>,
         ),
         // الفلتر العام: نمر فقط على العقد التي تغير فيها شيء ما
         Or<(
             Changed<UNode>,
             Changed<ULayout>,
             Changed<USelf>,
+            Changed<UGridPlacement>,
             Changed<Children>,
             Changed<IntrinsicSize>,
+            Changed<ComputedDirection>,
+            Changed<UZIndex>,
         )>
     >,
     
     added_nodes: Query<Entity, Added<UNode>>,
-    children_query: Query<&Children>,
     parents_query: Query<&ChildOf>,
+    boundaries: Query<(&UNode, &ULayout)>,
 ) {
+    let is_boundary = |entity: Entity| {
+        boundaries
+            .get(entity)
+            .is_ok_and(|(node, layout)| node.is_relayout_boundary(layout))
+    };
+
     // 1. معالجة التغييرات
-    for (entity, children, node, layout, uself, intrinsic) in nodes.iter() {
+    for (entity, children, node, layout, uself, placement, intrinsic, direction, z_index) in nodes.iter() {
         let change_flags = LayoutChangeFlags {
             intrinsic_changed: intrinsic.is_changed(),
-            node_changed: node.is_changed(),
+            // تغيير الألوان أو الزوايا فقط لا يمس التخطيط
+            node_changed: node.is_changed() && cache.update_node_spec(entity, &node),
+            children_changed: children.as_ref().is_some_and(|kids| kids.is_changed()),
             layout_changed: layout.map_or(false, |l| l.is_changed()),
-            uself_changed: uself.map_or(false, |s| s.is_changed()),
+            uself_changed: uself.map_or(false, |s| s.is_changed())
+                || placement.is_some_and(|p| p.is_changed()),
+            order_changed: direction.is_some_and(|d| d.is_changed()) || z_index.is_some_and(|z| z.is_changed()),
         };
 
         // إذا كان التغيير الوحيد IntrinsicSize على عقدة حاوية، نتجاهله لتفادي
@@ -186,32 +285,54 @@ pub fn track_layout_changes(
         ) {
             continue;
         }
+        if !change_flags.any() {
+            continue;
+        }
 
-        // في جميع الحالات الأخرى، نعتبر العنصر متسخاً
-        cache.mark_dirty_recursive(entity, &children_query);
-        cache.mark_dirty_ancestors(entity, &parents_query);
+        // في جميع الحالات الأخرى، نعتبر العنصر متسخاً. الأبناء لا يُعلَّمون:
+        // التمرير النازل يعيد حل من تغير حجمه أو مدخلاته فقط.
+        cache.mark_dirty(entity);
+        cache.mark_dirty_ancestors_until(entity, &parents_query, is_boundary);
     }
     
     // 2. معالجة العناصر الجديدة
     for entity in added_nodes.iter() {
         cache.mark_dirty(entity);
-        cache.mark_dirty_ancestors(entity, &parents_query);
+        cache.mark_dirty_ancestors_until(entity, &parents_query, is_boundary);
     }
 }
 
 #[derive(Clone, Copy)]
 struct LayoutChangeFlags {
     intrinsic_changed: bool,
+    /// Layout fields of `UNode`; paint-only changes don't count.
     node_changed: bool,
+    children_changed: bool,
     layout_changed: bool,
+    /// `USelf` or `UGridPlacement`.
     uself_changed: bool,
+    /// `ComputedDirection` or `UZIndex`.
+    order_changed: bool,
+}
+
+impl LayoutChangeFlags {
+    fn any(self) -> bool {
+        self.intrinsic_changed
+            || self.node_changed
+            || self.children_changed
+            || self.layout_changed
+            || self.uself_changed
+            || self.order_changed
+    }
 }
 
 fn should_skip_intrinsic_only_container_change(flags: LayoutChangeFlags, has_children: bool) -> bool {
     flags.intrinsic_changed
         && !flags.node_changed
+        && !flags.children_changed
         && !flags.layout_changed
         && !flags.uself_changed
+        && !flags.order_changed
         && has_children
 }
 
@@ -232,7 +353,11 @@ pub fn update_depth_cache(
     mut removed_nodes: RemovedComponents<LayoutDepth>,
 ) {
     // هل تغير الهيكل؟ (إضافة أو حذف عقد)
-    let structure_changed = !added_nodes.is_empty() || removed_nodes.read().count() > 0;
+    let mut structure_changed = !added_nodes.is_empty();
+    for entity in removed_nodes.read() {
+        cache.forget(entity);
+        structure_changed = true;
+    }
 
     // شروط إعادة البناء:
     // 1. تغير الهيكل (عقد جديدة/محذوفة)
@@ -253,12 +378,14 @@ impl Plugin for LayoutCachePlugin {
         app
             .init_resource::<LayoutCache>()
             .add_systems(
-                Update,
+                PostUpdate,
                 (
                     track_layout_changes,
                     update_depth_cache,
                 )
                 .chain()
+                .in_set(UnivisPostUpdateSet::LayoutHierarchy)
+                .after(update_layout_hierarchy)
             );
     }
 }
@@ -271,8 +398,10 @@ mod tests {
         LayoutChangeFlags {
             intrinsic_changed: true,
             node_changed: false,
+            children_changed: false,
             layout_changed: false,
             uself_changed: false,
+            order_changed: false,
         }
     }
 
@@ -315,6 +444,69 @@ mod tests {
 
         assert!(!should_skip_intrinsic_only_container_change(flags, true));
     }
+
+    #[test]
+    fn paint_only_node_change_leaves_layout_clean() {
+        let mut cache = LayoutCache::default();
+        let entity = Entity::from_raw_u32(1).unwrap();
+        let mut node = UNode { width: UVal::Px(40.0), ..default() };
+        assert!(cache.update_node_spec(entity, &node));
+
+        node.background_color = Color::WHITE;
+        node.border_radius = UCornerRadius::all(4.0);
+        assert!(!cache.update_node_spec(entity, &node));
+
+        node.padding = USides::all(2.0);
+        assert!(cache.update_node_spec(entity, &node));
+    }
+
+    #[test]
+    fn order_change_is_not_skipped() {
+        let mut flags = intrinsic_only_flags();
+        flags.order_changed = true;
+
+        assert!(!should_skip_intrinsic_only_container_change(flags, true));
+    }
+
+    fn inputs(width: f32) -> SolveInputs {
+        SolveInputs {
+            container_size: Vec2::new(width, 100.0),
+            limit_base: Vec2::splat(f32::INFINITY),
+            units: UUnitContext::default(),
+        }
+    }
+
+    #[test]
+    fn clean_solve_is_reused_until_inputs_or_children_change() {
+        let mut cache = LayoutCache::default();
+        let container = Entity::from_raw_u32(1).unwrap();
+        let child = Entity::from_raw_u32(2).unwrap();
+        cache.record_solve(container, inputs(200.0), Vec2::new(200.0, 100.0));
+
+        assert_eq!(cache.reusable_solve(container, &[child], &inputs(200.0)), Some(Vec2::new(200.0, 100.0)));
+        assert_eq!(cache.reusable_solve(container, &[child], &inputs(300.0)), None);
+
+        cache.mark_dirty(child);
+        assert_eq!(cache.reusable_solve(container, &[child], &inputs(200.0)), None);
+
+        cache.clear_all_dirty();
+        cache.forget(container);
+        assert_eq!(cache.reusable_solve(container, &[child], &inputs(200.0)), None);
+    }
+
+    #[test]
+    fn fixed_or_clipped_size_is_a_relayout_boundary() {
+        let fixed = UNode { width: UVal::Px(100.0), height: UVal::Px(50.0), ..default() };
+        let stretched = UNode { width: UVal::Percent(1.0), height: UVal::Px(50.0), ..default() };
+        let clipped = ULayout { overflow_x: UOverflow::Hidden, ..default() };
+
+        assert!(fixed.is_relayout_boundary(&ULayout::default()));
+        assert!(!stretched.is_relayout_boundary(&ULayout::default()));
+        assert!(stretched.is_relayout_boundary(&clipped));
+        // `Clip` keeps the content size, so it isn't a boundary.
+        assert!(!stretched.is_relayout_boundary(&ULayout { overflow_x: UOverflow::Clip, ..default() }));
+        assert!(!UNode { width: UVal::Content, ..stretched }.is_relayout_boundary(&clipped));
+    }
 }
 
 // =========================================================
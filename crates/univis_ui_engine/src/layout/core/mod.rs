@@ -1,15 +1,21 @@
 pub mod hierarchy;
 pub mod layout_cache;
+pub mod parallel;
 pub mod pass_down;
 pub mod pass_up;
 pub mod solver;
+pub mod tree;
+pub mod units;
 
 pub mod prelude {
     pub use crate::layout::core::{
         hierarchy::*,
         layout_cache::*,
+        parallel::*,
         pass_down::*,
         pass_up::*,
         solver::*,
+        tree::*,
+        units::*,
     };
 }
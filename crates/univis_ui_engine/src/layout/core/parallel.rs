@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, ParallelSliceMut};
+
+/// How the layout passes split a depth layer across the `ComputeTaskPool`.
+///
+/// Nodes of the same depth don't depend on each other, so a layer with at least
+/// `min_parallel_nodes` nodes is measured and solved on the pool. Reading the ECS
+/// and writing results stay serial and follow the layer order, so the output
+/// doesn't depend on thread scheduling.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct LayoutParallelism {
+    pub enabled: bool,
+    /// Smallest layer processed in parallel; below it the task overhead dominates.
+    pub min_parallel_nodes: usize,
+    /// Nodes handed to each task.
+    pub chunk_size: usize,
+}
+
+impl Default for LayoutParallelism {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_parallel_nodes: 256,
+            chunk_size: 64,
+        }
+    }
+}
+
+impl LayoutParallelism {
+    /// Runs `f` on every work item of a layer. Returns `true` when it ran in parallel.
+    pub fn for_each_mut<T: Send>(&self, mut items: &mut [T], f: impl Fn(&mut T) + Send + Sync) -> bool {
+        let pool = ComputeTaskPool::try_get()
+            .filter(|pool| self.enabled && pool.thread_num() > 1 && items.len() >= self.min_parallel_nodes);
+        let Some(pool) = pool else {
+            items.iter_mut().for_each(f);
+            return false;
+        };
+
+        items.par_chunk_map_mut(pool, self.chunk_size.max(1), |_, chunk| {
+            chunk.iter_mut().for_each(&f);
+        });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::tasks::TaskPoolBuilder;
+
+    #[test]
+    fn small_layers_stay_serial() {
+        let parallelism = LayoutParallelism::default();
+        let mut items = vec![1, 2, 3];
+
+        assert!(!parallelism.for_each_mut(&mut items, |i| *i *= 2));
+        assert_eq!(items, vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn parallel_layers_match_serial_results() {
+        ComputeTaskPool::get_or_init(|| TaskPoolBuilder::new().num_threads(4).build());
+        let parallelism = LayoutParallelism { min_parallel_nodes: 8, chunk_size: 3, ..default() };
+        let mut parallel: Vec<u64> = (0..1000).collect();
+        let mut serial = parallel.clone();
+
+        let ran_parallel = parallelism.for_each_mut(&mut parallel, |i| *i = *i * 31 + 7);
+        serial.iter_mut().for_each(|i| *i = *i * 31 + 7);
+
+        assert_eq!(parallel, serial);
+        assert_eq!(ran_parallel, ComputeTaskPool::get().thread_num() > 1);
+    }
+}
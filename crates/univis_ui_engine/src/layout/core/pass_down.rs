@@ -1,3 +1,5 @@
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
 use crate::internal_prelude::*;
 
@@ -16,7 +18,7 @@ impl SolverItemOwned {
     /// تحويل إلى SolverItem مؤقت (للاستخدام مع الـ Solver)
     pub fn as_solver_item(&mut self) -> SolverItem<'_> {
         SolverItem {
-            spec: self.spec,
+            spec: self.spec.clone(),
             result: &mut *self.result,
             margin: self.margin,
         }
@@ -41,18 +43,49 @@ struct ChildLayoutData {
     margin: USides,
 }
 
+/// A container of the current layer, solved off the ECS.
+struct SolveWork {
+    entity: Entity,
+    inputs: SolveInputs,
+    padding: USides,
+    config: SolverConfig,
+    constraints: BoxConstraints,
+    items: Vec<SolverItemOwned>,
+    entities: Vec<Entity>,
+    measures: Vec<Option<ItemMeasure>>,
+    fixed_root: Option<(Entity, Vec2)>,
+    solved: SolvedContainer,
+    resized: Vec<usize>,
+}
+
 struct SolvedChild {
     entity: Entity,
     result: SolverResult,
 }
 
+/// Child inputs, per-container outputs and solver settings of the downward pass,
+/// besides the node tree itself.
+#[derive(SystemParam)]
+pub struct DownwardSolveParam<'w, 's> {
+    intrinsic: Query<'w, 's, (&'static IntrinsicSize, Option<&'static UBaseline>, Option<&'static UMeasure>)>,
+    grid_placements: Query<'w, 's, &'static UGridPlacement>,
+    z_indices: Query<'w, 's, &'static UZIndex>,
+    directions: Query<'w, 's, &'static ComputedDirection>,
+    overflow: Query<'w, 's, &'static mut ScrollableOverflow>,
+    grid_tracks: Query<'w, 's, &'static mut ComputedGridTracks>,
+    placers: Res<'w, LayoutPlacerRegistry>,
+    /// `UDisplay::Custom` ids already reported as unregistered.
+    missing_placers: Local<'s, HashSet<&'static str>>,
+    parallelism: Res<'w, LayoutParallelism>,
+}
+
 // =========================================================
 // النظام الرئيسي - 100% آمن
 // =========================================================
 
 pub fn downward_solve_pass_safe(
     tree_depth: Res<LayoutTreeDepth>,
-    cache: Res<LayoutCache>,
+    mut cache: ResMut<LayoutCache>,
     mut profiler: Option<ResMut<LayoutProfiler>>, // إضافة Profiler اختياري
     
     mut nodes: Query<(
@@ -66,11 +99,26 @@ pub fn downward_solve_pass_safe(
         &mut Transform
     )>,
     
-    intrinsic_query: Query<&IntrinsicSize>,
-    root_query: Query<&UWorldRoot>,
-    window_query: Query<&Window>,
+    mut solve: DownwardSolveParam,
+    units: UnitContextParam,
+    mut last_z_index_step: Local<f32>,
 ) {
     let start = std::time::Instant::now();
+    let mut remeasured: Vec<Entity> = Vec::new();
+    let mut skipped_count = 0;
+    // خطوة UZIndex ترفع الفرع فوق عمق الشجرة كاملاً
+    let z_index_step = (tree_depth.max_depth + 1) as f32 * DEPTH_Z_STEP;
+
+    // الخطوة قيمة عامة خارج SolveInputs: تغيّرها يعيد رفع عناصر UZIndex هنا
+    // بدل إبطال كل مدخلات الـ cache
+    if *last_z_index_step != z_index_step {
+        *last_z_index_step = z_index_step;
+        for (entity, _, _, depth, _, _, _, mut transform) in nodes.iter_mut() {
+            if depth.0 > 0 && let Ok(z_index) = solve.z_indices.get(entity) {
+                transform.translation.z = DEPTH_Z_STEP + z_index.0 as f32 * z_index_step;
+            }
+        }
+    }
     
     for depth in 0..=tree_depth.max_depth {
         
@@ -79,16 +127,22 @@ pub fn downward_solve_pass_safe(
             continue;
         };
 
+        // القراءة من ECS تسلسلية؛ الحل متوازٍ في الطبقات الكبيرة؛ الكتابة بترتيب الطبقة
+        let mut pending: Vec<SolveWork> = Vec::new();
         for &entity in layer_entities {
             
             // 1. استخراج البيانات
-            let Some(node_data) = extract_node_data(entity, &nodes) else {
+            let Some(mut node_data) = extract_node_data(entity, &nodes) else {
                 continue;
             };
+            let unit_ctx = units.context_for(entity);
+            let limit_base = parent_content_size(entity, &units.parents, &nodes)
+                .unwrap_or(Vec2::splat(f32::INFINITY));
+            node_data.spec = node_data.spec.resolve_units(limit_base, &unit_ctx);
 
             // 2. حساب حجم الحاوية
             let container_size = if depth == 0 {
-                calculate_root_size(entity, &root_query, &window_query)
+                units.root_size(entity)
             } else {
                 Vec2::new(node_data.computed_size.width, node_data.computed_size.height)
             };
@@ -96,50 +150,164 @@ pub fn downward_solve_pass_safe(
             // تحديث الجذر
             if depth == 0 {
                 if let Ok((_, _, _, _, _, _, mut computed, _)) = nodes.get_mut(entity) {
-                    computed.width = container_size.x;
-                    computed.height = container_size.y;
+                    let size = computed.with_size(container_size);
+                    computed.set_if_neq(size);
+                }
+            }
+
+            // 2.1 حاوية نظيفة بنفس المدخلات: نعيد الحجم المحفوظ ونترك أطفالها كما هم.
+            // Sticky و Fixed يعتمدان على تحويلات تُعاد كتابتها كل إطار فلا نتخطاهما.
+            let inputs = SolveInputs { container_size, limit_base, units: unit_ctx };
+            let repositioned_each_frame = node_data.children.iter().any(|&child| {
+                nodes.get(child).is_ok_and(|(_, _, _, _, _, uself, _, _)| {
+                    uself.is_some_and(|u| matches!(u.position_type, UPositionType::Sticky | UPositionType::Fixed))
+                })
+            });
+            if !repositioned_each_frame
+                && let Some(size) = cache.reusable_solve(entity, &node_data.children, &inputs)
+            {
+                if let Ok((_, _, _, _, _, _, mut computed, _)) = nodes.get_mut(entity) {
+                    let size = computed.with_size(size);
+                    computed.set_if_neq(size);
                 }
+                skipped_count += 1;
+                continue;
             }
 
             // 3. جمع بيانات الأطفال
+            let fixed_root = fixed_root_size(entity, &node_data.children, &nodes, &units);
+            let child_base = Vec2::new(
+                (container_size.x - node_data.spec.padding.width_sum()).max(0.0),
+                (container_size.y - node_data.spec.padding.height_sum()).max(0.0),
+            );
             let children_layout_data = collect_children_layout_data(
                 &node_data.children,
                 &nodes,
-                &intrinsic_query,
+                &solve,
+                ChildUnitBases {
+                    content: child_base,
+                    container: container_size,
+                    main: if AxisHelper::new(node_data.layout.solver_direction()).is_row() { child_base.x } else { child_base.y },
+                    root: fixed_root.map(|(_, size)| size),
+                    ctx: unit_ctx,
+                },
             );
 
             if children_layout_data.is_empty() {
+                if let Ok(mut overflow) = solve.overflow.get_mut(entity) {
+                    overflow.set_if_neq(ScrollableOverflow { rect: Rect::from_corners(Vec2::ZERO, container_size) });
+                }
                 continue;
             }
 
             // 4. تحويل إلى Solver (الطريقة الآمنة)
-            let (mut solver_items_owned, entities_map) = 
+            let (solver_items_owned, entities_map) = 
                 prepare_solver_data_safe(children_layout_data);
 
-            // 5. تحويل مؤقت إلى SolverItem
-            let mut solver_items_refs: Vec<SolverItem> = solver_items_owned
-                .iter_mut()
-                .map(|item| item.as_solver_item())
-                .collect();
-
             // 6. إعداد القيود
-            let constraints = build_constraints(container_size, &node_data.spec);
+            let constraints = build_constraints(container_size, &node_data.spec, &node_data.layout, limit_base);
+
+            // نصف القطر بوحدات السياق (vw, em, calc...) يتحول إلى Px
+            let radial = &mut node_data.layout.container_ext.radial;
+            radial.radius = radial.radius.normalize(child_base.min_element() * 0.5, &unit_ctx);
 
             // 7. تشغيل Solver
-            let solver_config = translate_config(
+            let mut solver_config = translate_config(
                 &node_data.layout,
                 &node_data.spec,
             );
-            let final_size = solve_flex_layout(
-                &solver_config,
+            if let UDisplay::Custom(id) = node_data.layout.display {
+                solver_config.custom_placer = solve.placers.get(id);
+                if solver_config.custom_placer.is_none() && solve.missing_placers.insert(id) {
+                    warn!("no layout placer registered under UDisplay::Custom({id:?}); falling back to Flex");
+                }
+            }
+            if let Ok(direction) = solve.directions.get(entity) {
+                solver_config.direction = direction.0;
+            }
+            // 7.1 ارتفاع يعتمد على العرض (UMeasure): نقيس بالعرض المحلول ونعيد الحل مرة واحدة
+            let measures: Vec<Option<ItemMeasure>> = entities_map
+                .iter()
+                .map(|&child| {
+                    let (_, _, Some(measure)) = solve.intrinsic.get(child).ok()? else {
+                        return None;
+                    };
+                    let (_, node, _, _, _, _, computed, _) = nodes.get(child).ok()?;
+                    Some(ItemMeasure {
+                        measure: measure.clone(),
+                        padding: node.padding,
+                        previous_width: computed.width,
+                    })
+                })
+                .collect();
+            pending.push(SolveWork {
+                entity,
+                inputs,
+                padding: node_data.spec.padding,
+                config: solver_config,
                 constraints,
-                &mut solver_items_refs,
+                items: solver_items_owned,
+                entities: entities_map,
+                measures,
+                fixed_root,
+                solved: SolvedContainer::default(),
+                resized: Vec::new(),
+            });
+        }
+
+        solve.parallelism.for_each_mut(&mut pending, |work| {
+            (work.solved, work.resized) = solve_container(
+                &work.config,
+                work.constraints,
+                &mut work.items,
+                &work.measures,
             );
+        });
+
+        for work in pending {
+            let SolveWork {
+                entity,
+                inputs,
+                padding,
+                items: mut solver_items_owned,
+                entities: entities_map,
+                fixed_root,
+                solved,
+                resized,
+                ..
+            } = work;
+            let final_size = solved.size;
+            cache.record_solve(entity, inputs, final_size);
+            remeasured.extend(resized.into_iter().map(|index| entities_map[index]));
+
+            // 7.2 العناصر الثابتة (Fixed) تتموضع بالنسبة للجذر
+            if let Some((root, root_size)) = fixed_root {
+                let center = center_in_root(entity, root, &nodes, &units.parents);
+                let origin = fixed_origin(center, final_size, root_size);
+                for item in solver_items_owned.iter_mut().filter(|i| i.spec.position_type == UPositionType::Fixed) {
+                    let (size, pos) = place_out_of_flow(root_size, &item.spec, item.margin);
+                    item.result.size = size;
+                    item.result.pos = pos - origin;
+                }
+            }
 
             // 8. تحديث حجم الحاوية
-            if let Ok((_, _, _, _, _, _, mut computed, _)) = nodes.get_mut(entity) {
-                computed.width = final_size.x;
-                computed.height = final_size.y;
+            if let Ok((_, node, layout, _, _, _, mut computed, _)) = nodes.get_mut(entity) {
+                // الحاوية الملتفة قاست أسطرها بحجم قديم: نعيد قياسها
+                if let (Some(layout), Ok((intrinsic, _, _))) = (layout, solve.intrinsic.get(entity))
+                    && wrapped_size_moved(layout, node, *intrinsic, final_size)
+                {
+                    remeasured.push(entity);
+                }
+                let size = computed.with_size(final_size);
+                computed.set_if_neq(size);
+            }
+            if let Ok(mut overflow) = solve.overflow.get_mut(entity) {
+                let rect = scrollable_overflow(final_size, padding, &solver_items_owned);
+                overflow.set_if_neq(ScrollableOverflow { rect });
+            }
+            if let (Some(tracks), Ok(mut computed)) = (solved.grid_tracks, solve.grid_tracks.get_mut(entity)) {
+                computed.set_if_neq(tracks);
             }
 
             // 9. ترجمة النتائج
@@ -153,16 +321,37 @@ pub fn downward_solve_pass_safe(
                 .collect();
 
             // 10. تطبيق النتائج
-            apply_results_to_children(&solved_children, final_size, &mut nodes);
+            apply_results_to_children(&solved_children, final_size, &mut nodes, &solve.z_indices, z_index_step);
         }
     }
     
+    // العرض تغير منذ القياس الصاعد: نعلّم الفرع لجولة قياس ثانية
+    // (انظر `layout_remeasure_pending`).
+    cache.clear_all_dirty();
+    let is_boundary = |entity: Entity| {
+        nodes.get(entity).is_ok_and(|(_, node, layout, _, _, _, _, _)| {
+            node.is_relayout_boundary(layout.unwrap_or(&ULayout::default()))
+        })
+    };
+    for entity in remeasured {
+        cache.mark_dirty(entity);
+        cache.mark_dirty_ancestors_until(entity, &units.parents, is_boundary);
+    }
+
     // تحديث Profiler
     if let Some(ref mut prof) = profiler {
         prof.downward_pass_time = start.elapsed().as_secs_f64() * 1000.0;
+        prof.skipped_solves = skipped_count;
     }
 }
 
+/// Run condition of the second measure and solve round: a measured leaf or a
+/// wrapping container was solved at a size its measure didn't assume, so its
+/// ancestors up to the nearest relayout boundary must be measured again.
+pub fn layout_remeasure_pending(cache: Res<LayoutCache>) -> bool {
+    cache.dirty_count() > 0
+}
+
 // =========================================================
 // Helper Functions
 // =========================================================
@@ -189,18 +378,17 @@ fn extract_node_data(
     })
 }
 
-fn calculate_root_size(
-    entity: Entity,
-    root_query: &Query<&UWorldRoot>,
-    window_query: &Query<&Window>,
-) -> Vec2 {
-    if let Ok(world_root) = root_query.get(entity) {
-        world_root.size
-    } else if let Ok(window) = window_query.single() {
-        Vec2::new(window.width(), window.height())
-    } else {
-        Vec2::new(800.0, 600.0)
-    }
+/// Bases used to normalize the children's units before solving.
+pub(crate) struct ChildUnitBases {
+    /// Parent content box (sizes and limits).
+    pub content: Vec2,
+    /// Parent border box (position offsets).
+    pub container: Vec2,
+    /// Parent main-axis content size (flex basis).
+    pub main: f32,
+    /// Layout root size, replacing both boxes for `Fixed` children.
+    pub root: Option<Vec2>,
+    pub ctx: UUnitContext,
 }
 
 fn collect_children_layout_data(
@@ -210,34 +398,145 @@ fn collect_children_layout_data(
         &LayoutDepth, Option<&Children>, Option<&USelf>,
         &mut ComputedSize, &mut Transform
     )>,
-    intrinsic_query: &Query<&IntrinsicSize>,
+    solve: &DownwardSolveParam,
+    bases: ChildUnitBases,
 ) -> Vec<ChildLayoutData> {
     children.iter()
         .filter_map(|&child_entity| {
             let (_, node, _, _, _, uself_opt, _, _) = nodes_query.get(child_entity).ok()?;
-            let intrinsic = intrinsic_query.get(child_entity).ok()?;
-
-            let mut spec = translate_spec(
-                node,
-                uself_opt.as_deref(),
-            );
-
-            if spec.width_mode == SolverSizeMode::Content {
-                spec.width_val = intrinsic.width;
-            }
-            if spec.height_mode == SolverSizeMode::Content {
-                spec.height_val = intrinsic.height;
-            }
+            let (intrinsic, baseline, _) = solve.intrinsic.get(child_entity).ok()?;
+            let placement = solve.grid_placements.get(child_entity).ok();
+            let (spec, margin) = child_spec(node, uself_opt, placement, *intrinsic, baseline.copied(), &bases);
 
             Some(ChildLayoutData {
                 entity: child_entity,
                 spec,
-                margin: node.margin,
+                margin,
             })
         })
         .collect()
 }
 
+/// Solver spec and resolved margin of a child, from its measured intrinsic size.
+pub(crate) fn child_spec(
+    node: &UNode,
+    uself: Option<&USelf>,
+    placement: Option<&UGridPlacement>,
+    intrinsic: IntrinsicSize,
+    baseline: Option<UBaseline>,
+    bases: &ChildUnitBases,
+) -> (SolverSpec, USides) {
+    let (content, container) = match bases.root {
+        Some(root) if uself.is_some_and(|u| u.position_type == UPositionType::Fixed) => (root, root),
+        _ => (bases.content, bases.container),
+    };
+    let node = node.resolve_units(content, &bases.ctx);
+    let uself = uself.map(|u| u.resolve_units(container, bases.main, &bases.ctx));
+    let mut spec = translate_spec(
+        &node,
+        uself.as_ref(),
+    );
+
+    if spec.width_mode == SolverSizeMode::Content {
+        spec.width_val = intrinsic.width;
+    }
+    if spec.height_mode == SolverSizeMode::Content {
+        spec.height_val = intrinsic.height;
+    }
+    spec.content_width = intrinsic.width;
+    spec.content_height = intrinsic.height;
+    spec.min_content_width = intrinsic.min_width;
+    spec.min_content_height = intrinsic.min_height;
+    if let Some(baseline) = baseline {
+        spec.first_baseline = baseline.first;
+        spec.last_baseline = baseline.last;
+    }
+    if let Some(placement) = placement {
+        spec.grid_area = placement.area.clone();
+        spec.grid_column_lines = placement.column.clone();
+        spec.grid_row_lines = placement.row.clone();
+    }
+
+    (spec, node.margin)
+}
+
+/// A `UMeasure` child: its measure, padding and last solved width.
+pub(crate) struct ItemMeasure {
+    pub measure: UMeasure,
+    pub padding: USides,
+    pub previous_width: f32,
+}
+
+/// Solves a container, then measures its `UMeasure` children again at their
+/// solved width and solves once more when a height changed.
+///
+/// `measures` is indexed like `items`. Returns the container size and the
+/// indices of measured children whose width changed since the last frame.
+pub(crate) fn solve_container(
+    config: &SolverConfig,
+    constraints: BoxConstraints,
+    items: &mut [SolverItemOwned],
+    measures: &[Option<ItemMeasure>],
+) -> (SolvedContainer, Vec<usize>) {
+    let mut solver_items_refs: Vec<SolverItem> = items
+        .iter_mut()
+        .map(|item| item.as_solver_item())
+        .collect();
+    let mut solved = solve_layout(config, constraints, &mut solver_items_refs);
+
+    let mut resized = Vec::new();
+    if remeasure_children(items, measures, &mut resized) {
+        for item in items.iter_mut() {
+            *item.result = SolverResult::default();
+        }
+        let mut solver_items_refs: Vec<SolverItem> = items
+            .iter_mut()
+            .map(|item| item.as_solver_item())
+            .collect();
+        solved = solve_layout(config, constraints, &mut solver_items_refs);
+    }
+
+    (solved, resized)
+}
+
+/// Measures `UMeasure` children again at their solved width.
+///
+/// Returns `true` when a height changed and the parent must be solved again.
+/// Indices of children whose width changed are pushed to `resized`.
+fn remeasure_children(
+    items: &mut [SolverItemOwned],
+    measures: &[Option<ItemMeasure>],
+    resized: &mut Vec<usize>,
+) -> bool {
+    let mut changed = false;
+
+    for (index, (item, measure)) in items.iter_mut().zip(measures).enumerate() {
+        let Some(ItemMeasure { measure, padding, previous_width }) = measure else {
+            continue;
+        };
+
+        let width = item.result.size.x;
+        if (width - previous_width).abs() > 0.5 {
+            resized.push(index);
+        }
+        if !matches!(item.spec.height_mode, SolverSizeMode::Content | SolverSizeMode::Auto) {
+            continue;
+        }
+
+        let content_width = (width - padding.width_sum()).max(0.0);
+        let height = measure.measure(Some(content_width)).y + padding.height_sum();
+        if (height - item.spec.content_height).abs() > 0.5 {
+            if item.spec.height_mode == SolverSizeMode::Content {
+                item.spec.height_val = height;
+            }
+            item.spec.content_height = height;
+            changed = true;
+        }
+    }
+
+    changed
+}
+
 /// ✅ الطريقة الآمنة لتحضير بيانات Solver
 fn prepare_solver_data_safe(
     children_data: Vec<ChildLayoutData>,
@@ -259,25 +558,143 @@ fn prepare_solver_data_safe(
     (solver_items, entities_map)
 }
 
-fn build_constraints(
+/// Content box of the parent, used as the base for percentage min/max limits.
+fn parent_content_size(
+    entity: Entity,
+    parent_query: &Query<&ChildOf>,
+    nodes_query: &Query<(
+        Entity, &UNode, Option<&ULayout>,
+        &LayoutDepth, Option<&Children>, Option<&USelf>,
+        &mut ComputedSize, &mut Transform
+    )>,
+) -> Option<Vec2> {
+    let parent = parent_query.get(entity).ok()?.parent();
+    let (_, parent_node, _, _, _, _, parent_size, _) = nodes_query.get(parent).ok()?;
+
+    Some(Vec2::new(
+        (parent_size.width - parent_node.padding.width_sum()).max(0.0),
+        (parent_size.height - parent_node.padding.height_sum()).max(0.0),
+    ))
+}
+
+pub(crate) fn build_constraints(
     container_size: Vec2,
     node_spec: &UNode,
+    layout: &ULayout,
+    limit_base: Vec2,
 ) -> BoxConstraints {
     let mut constraints = BoxConstraints::tight(container_size);
 
-    if matches!(node_spec.width, UVal::Auto | UVal::Content) {
-        constraints.min_width = 0.0;
-        constraints.max_width = f32::INFINITY;
+    // The parent already derived one axis from the other; re-measuring content
+    // here would break the ratio.
+    if node_spec.aspect_ratio.is_some_and(|r| r.is_finite() && r > 0.0) {
+        return constraints;
+    }
+
+    // A scroll container keeps the size its parent assigned instead of
+    // growing back to its content.
+    if matches!(node_spec.width, UVal::Auto | UVal::Content) && !layout.overflow_x.is_scroll_container() {
+        constraints.min_width = node_spec.min_width.resolve_limit(limit_base.x).unwrap_or(0.0);
+        constraints.max_width = node_spec.max_width
+            .resolve_limit(limit_base.x)
+            .unwrap_or(f32::INFINITY)
+            .max(constraints.min_width);
     }
 
-    if matches!(node_spec.height, UVal::Auto | UVal::Content) {
-        constraints.min_height = 0.0;
-        constraints.max_height = f32::INFINITY;
+    if matches!(node_spec.height, UVal::Auto | UVal::Content) && !layout.overflow_y.is_scroll_container() {
+        constraints.min_height = node_spec.min_height.resolve_limit(limit_base.y).unwrap_or(0.0);
+        constraints.max_height = node_spec.max_height
+            .resolve_limit(limit_base.y)
+            .unwrap_or(f32::INFINITY)
+            .max(constraints.min_height);
     }
 
     constraints
 }
 
+/// Union of the container box and its children's margin boxes (before relative
+/// offsets), extended by the end padding. `Fixed` children are skipped.
+pub(crate) fn scrollable_overflow(size: Vec2, padding: USides, items: &[SolverItemOwned]) -> Rect {
+    let mut rect = Rect::from_corners(Vec2::ZERO, size);
+
+    for item in items {
+        if item.spec.position_type == UPositionType::Fixed {
+            continue;
+        }
+        let pos = item.result.pos - relative_offset(&item.spec, size);
+        let min = pos - Vec2::new(item.margin.left, item.margin.top);
+        let max = pos
+            + item.result.size
+            + Vec2::new(item.margin.right + padding.right, item.margin.bottom + padding.bottom);
+        rect = rect.union(Rect::from_corners(min, max));
+    }
+
+    rect
+}
+
+/// Layout root of `entity` and its size, when one of `children` is `Fixed`.
+fn fixed_root_size(
+    entity: Entity,
+    children: &[Entity],
+    nodes_query: &Query<(
+        Entity, &UNode, Option<&ULayout>,
+        &LayoutDepth, Option<&Children>, Option<&USelf>,
+        &mut ComputedSize, &mut Transform
+    )>,
+    units: &UnitContextParam,
+) -> Option<(Entity, Vec2)> {
+    let has_fixed = children.iter().any(|&child| {
+        nodes_query
+            .get(child)
+            .is_ok_and(|(_, _, _, _, _, uself, _, _)| uself.is_some_and(|u| u.position_type == UPositionType::Fixed))
+    });
+    if !has_fixed {
+        return None;
+    }
+
+    let root = units.owning_root(entity);
+    let (_, _, _, _, _, _, root_size, _) = nodes_query.get(root).ok()?;
+    Some((root, Vec2::new(root_size.width, root_size.height)))
+}
+
+/// Center of `entity` relative to the center of `root`, from this frame's translations.
+fn center_in_root(
+    entity: Entity,
+    root: Entity,
+    nodes_query: &Query<(
+        Entity, &UNode, Option<&ULayout>,
+        &LayoutDepth, Option<&Children>, Option<&USelf>,
+        &mut ComputedSize, &mut Transform
+    )>,
+    parent_query: &Query<&ChildOf>,
+) -> Vec2 {
+    let mut center = Vec2::ZERO;
+    let mut current = entity;
+    while current != root {
+        let Ok((_, _, _, _, _, _, _, transform)) = nodes_query.get(current) else {
+            break;
+        };
+        center += transform.translation.truncate();
+        let Ok(child_of) = parent_query.get(current) else {
+            break;
+        };
+        current = child_of.parent();
+    }
+    center
+}
+
+/// Top-left corner of a container in the root's layout space (y-down), given
+/// its center relative to the root center (y-up).
+fn fixed_origin(center: Vec2, size: Vec2, root_size: Vec2) -> Vec2 {
+    Vec2::new(
+        center.x + (root_size.x - size.x) / 2.0,
+        (root_size.y - size.y) / 2.0 - center.y,
+    )
+}
+
+/// Translation z added per tree level, so children draw above their parent.
+const DEPTH_Z_STEP: f32 = 0.1;
+
 fn apply_results_to_children(
     solved_children: &[SolvedChild],
     parent_size: Vec2,
@@ -286,26 +703,44 @@ fn apply_results_to_children(
         &LayoutDepth, Option<&Children>, Option<&USelf>,
         &mut ComputedSize, &mut Transform
     )>,
+    z_indices: &Query<&UZIndex>,
+    z_index_step: f32,
 ) {
     for solved in solved_children.iter() {
         if let Ok((_, _, _, _, _, _, mut computed, mut transform)) =
             nodes_query.get_mut(solved.entity) 
         {
-            computed.width = solved.result.size.x;
-            computed.height = solved.result.size.y;
-
             let child_w = solved.result.size.x;
             let child_h = solved.result.size.y;
 
-            transform.translation.x = (-parent_size.x / 2.0) 
-                + solved.result.pos.x 
-                + (child_w / 2.0);
-                
-            transform.translation.y = (parent_size.y / 2.0) 
-                - solved.result.pos.y 
-                - (child_h / 2.0);
-            
-            transform.translation.z = 0.1;
+            let local_pos = Vec2::new(
+                (-parent_size.x / 2.0) + solved.result.pos.x + (child_w / 2.0),
+                (parent_size.y / 2.0) - solved.result.pos.y - (child_h / 2.0),
+            );
+            // Transform يحمل الموضع المرسوم؛ التقريب والانتقالات تبدأ من local_pos
+            let z_index = z_indices.get(solved.entity).map_or(0, |z| z.0);
+            let translation = local_pos.extend(DEPTH_Z_STEP + z_index as f32 * z_index_step);
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+
+            // نعيد الدوران فقط إذا كتبه الـ placer سابقاً؛ دوران المستخدم يبقى
+            let rotation = match solved.result.rotation {
+                Some(angle) => Some(Quat::from_rotation_z(angle)),
+                None if computed.rotation.is_some() => Some(Quat::IDENTITY),
+                None => None,
+            };
+            if let Some(rotation) = rotation && transform.rotation != rotation {
+                transform.rotation = rotation;
+            }
+
+            // set_if_neq: حاوية أُعيد حلها بنفس النتيجة لا تُعلّم Changed<ComputedSize>
+            computed.set_if_neq(ComputedSize {
+                width: child_w,
+                height: child_h,
+                local_pos,
+                rotation: solved.result.rotation,
+            });
         }
     }
 }
@@ -320,10 +755,12 @@ fn map_uval_to_mode(val: UVal) -> SolverSizeMode {
         UVal::Percent(_) => SolverSizeMode::Percent,
         UVal::Flex(_) => SolverSizeMode::Flex,
         UVal::Content | UVal::Auto => SolverSizeMode::Content,
+        // `resolve_units` turns the remaining units into `Px`.
+        _ => SolverSizeMode::Fixed,
     }
 }
 
-fn translate_config(
+pub(crate) fn translate_config(
     layout: &ULayout,
     node: &UNode,
 ) -> SolverConfig {
@@ -343,6 +780,13 @@ fn translate_config(
         grid_auto_flow: layout.container_ext.grid.auto_flow,
         grid_auto_rows: layout.container_ext.grid.auto_rows,
         grid_auto_columns: layout.container_ext.grid.auto_columns,
+        grid_template_areas: layout.container_ext.grid.template_areas.clone(),
+        grid_column_line_names: layout.container_ext.grid.column_line_names.clone(),
+        grid_row_line_names: layout.container_ext.grid.row_line_names.clone(),
+        masonry: layout.container_ext.masonry,
+        radial: layout.container_ext.radial,
+        custom_placer: None,
+        direction: UDirection::Ltr,
         width_mode: map_uval_to_mode(node.width),
         height_mode: map_uval_to_mode(node.height),
     }
@@ -358,6 +802,11 @@ fn translate_spec(
             UVal::Percent(p) => (SolverSizeMode::Percent, p, 0.0),
             UVal::Flex(f) => (SolverSizeMode::Flex, 0.0, f),
             UVal::Content | UVal::Auto => (SolverSizeMode::Content, 0.0, 0.0),
+            // Removed by `UNode::resolve_units` before solving.
+            other => match other.resolve(f32::INFINITY) {
+                Some(v) => (SolverSizeMode::Fixed, v, 0.0),
+                None => (SolverSizeMode::Content, 0.0, 0.0),
+            },
         }
     };
 
@@ -412,6 +861,12 @@ fn translate_spec(
     SolverSpec {
         width_mode: w_mode, width_val: w_val, width_flex: w_flex,
         height_mode: h_mode, height_val: h_val, height_flex: h_flex,
+        min_width: node.min_width, max_width: node.max_width,
+        min_height: node.min_height, max_height: node.max_height,
+        content_width: 0.0, content_height: 0.0,
+        min_content_width: 0.0, min_content_height: 0.0,
+        first_baseline: None, last_baseline: None,
+        aspect_ratio: node.aspect_ratio.filter(|r| r.is_finite() && *r > 0.0),
         position_type: pos_type,
         left: l, right: r, top: t, bottom: b,
         align_self: align,
@@ -426,6 +881,11 @@ fn translate_spec(
         grid_column_span,
         grid_row_start,
         grid_row_span,
+        grid_area: None,
+        grid_column_lines: UGridLines::default(),
+        grid_row_lines: UGridLines::default(),
+        radial_angle: uself.and_then(|u| u.item_ext.radial.angle),
+        custom: uself.map(|u| u.item_ext.custom).unwrap_or_default(),
         order,
     }
 }
@@ -433,6 +893,7 @@ fn translate_spec(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::layout::UnivisLayoutPlugin;
 
     #[test]
     fn translate_config_reads_container_ext_values() {
@@ -456,6 +917,16 @@ mod tests {
                     auto_flow: UGridAutoFlow::Column,
                     auto_rows: UTrackSize::Px(60.0),
                     auto_columns: UTrackSize::Fr(2.0),
+                    ..default()
+                },
+                masonry: ULayoutMasonryContainer {
+                    min_column_width: Some(120.0),
+                    ..default()
+                },
+                radial: URadialLayout {
+                    sweep_angle: 90.0,
+                    rotate_children: true,
+                    ..default()
                 },
             },
             ..default()
@@ -475,6 +946,333 @@ mod tests {
         assert_eq!(cfg.grid_auto_columns, UTrackSize::Fr(2.0));
         assert_eq!(cfg.grid_template_columns.len(), 2);
         assert_eq!(cfg.grid_template_rows.len(), 1);
+        assert_eq!(cfg.masonry.min_column_width, Some(120.0));
+        assert_eq!(cfg.radial.sweep_angle, 90.0);
+        assert!(cfg.radial.rotate_children);
+    }
+
+    #[test]
+    fn radial_rotation_is_cleared_when_rotate_children_turns_off() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        let world = app.world_mut();
+        let root = world
+            .spawn((
+                UWorldRoot { size: Vec2::splat(200.0), ..default() },
+                UNode { width: UVal::Px(200.0), height: UVal::Px(200.0), ..default() },
+                ULayout {
+                    display: UDisplay::Radial,
+                    container_ext: ULayoutContainerExt {
+                        radial: URadialLayout { rotate_children: true, ..default() },
+                        ..default()
+                    },
+                    ..default()
+                },
+            ))
+            .id();
+        let children: Vec<Entity> = (0..3)
+            .map(|_| {
+                world
+                    .spawn((UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() }, ChildOf(root)))
+                    .id()
+            })
+            .collect();
+        app.update();
+        assert!(children.iter().any(|&c| app.world().get::<Transform>(c).unwrap().rotation != Quat::IDENTITY));
+
+        app.world_mut().get_mut::<ULayout>(root).unwrap().container_ext.radial.rotate_children = false;
+        app.update();
+        for child in children {
+            assert_eq!(app.world().get::<Transform>(child).unwrap().rotation, Quat::IDENTITY);
+        }
+    }
+
+    #[test]
+    fn non_radial_child_keeps_its_user_rotation() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        let world = app.world_mut();
+        let root = world
+            .spawn((
+                UWorldRoot { size: Vec2::splat(200.0), ..default() },
+                UNode { width: UVal::Px(200.0), height: UVal::Px(200.0), ..default() },
+            ))
+            .id();
+        let rotation = Quat::from_rotation_z(0.5);
+        let child = world
+            .spawn((
+                UNode { width: UVal::Px(20.0), height: UVal::Px(20.0), ..default() },
+                Transform::from_rotation(rotation),
+                ChildOf(root),
+            ))
+            .id();
+        app.update();
+        app.world_mut().get_mut::<UNode>(child).unwrap().width = UVal::Px(30.0);
+        app.update();
+        assert_eq!(app.world().get::<Transform>(child).unwrap().rotation, rotation);
+    }
+
+    #[test]
+    fn idle_frames_leave_computed_size_unchanged() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        let world = app.world_mut();
+        let root = world
+            .spawn((
+                UWorldRoot { size: Vec2::splat(200.0), ..default() },
+                UNode { width: UVal::Px(200.0), height: UVal::Px(200.0), ..default() },
+            ))
+            .id();
+        let panel = world
+            .spawn((UNode { width: UVal::Px(100.0), height: UVal::Px(50.0), ..default() }, ChildOf(root)))
+            .id();
+        world.spawn((UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() }, ChildOf(panel)));
+        #[derive(Resource, Default)]
+        struct ChangedSizes(Vec<Entity>);
+        app.init_resource::<ChangedSizes>().add_systems(
+            Last,
+            |sizes: Query<Entity, Changed<ComputedSize>>,
+             intrinsics: Query<Entity, Changed<IntrinsicSize>>,
+             mut seen: ResMut<ChangedSizes>| {
+                seen.0 = sizes.iter().chain(intrinsics.iter()).collect();
+            },
+        );
+        for _ in 0..3 {
+            app.update();
+        }
+        for _ in 0..3 {
+            app.update();
+            assert!(app.world().resource::<ChangedSizes>().0.is_empty());
+        }
+    }
+
+    #[test]
+    fn wrapped_measure_moves_the_parents_siblings_in_the_same_frame() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        let world = app.world_mut();
+        let root = world
+            .spawn((
+                UWorldRoot { size: Vec2::new(100.0, 300.0), ..default() },
+                UNode { width: UVal::Px(100.0), height: UVal::Px(300.0), ..default() },
+                ULayout { flex_direction: UFlexDirection::Column, ..default() },
+            ))
+            .id();
+        let panel = world
+            .spawn((
+                UNode { width: UVal::Percent(1.0), ..default() },
+                ULayout { flex_direction: UFlexDirection::Column, ..default() },
+                ChildOf(root),
+            ))
+            .id();
+        // 200px of text, 10px per line.
+        let wrap = |width: Option<f32>| {
+            let width = width.unwrap_or(200.0).max(1.0);
+            Vec2::new(width.min(200.0), (200.0 / width).ceil() * 10.0)
+        };
+        world.spawn((UNode { width: UVal::Percent(1.0), ..default() }, UMeasure::new(wrap), ChildOf(panel)));
+        let footer = world
+            .spawn((UNode { width: UVal::Px(100.0), height: UVal::Px(10.0), ..default() }, ChildOf(root)))
+            .id();
+
+        app.update();
+
+        assert_eq!(app.world().get::<ComputedSize>(panel).unwrap().height, 20.0);
+        // Footer top edge at 20: 300 / 2 - 20 - 10 / 2.
+        assert_eq!(app.world().get::<Transform>(footer).unwrap().translation.y, 125.0);
+    }
+
+    #[test]
+    fn z_index_follows_tree_depth_without_resolving_clean_containers() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        let fixed = || UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() };
+        let world = app.world_mut();
+        let root = world.spawn((UWorldRoot { size: Vec2::splat(100.0), ..default() }, fixed())).id();
+        let raised = world.spawn((fixed(), UZIndex(1), ChildOf(root))).id();
+        app.update();
+        app.update();
+        // Depth 1: step = 2 * 0.1.
+        let z = app.world().get::<Transform>(raised).unwrap().translation.z;
+        assert!((z - 0.3).abs() < 1e-6);
+
+        // A deeper tree elsewhere leaves this root clean.
+        let world = app.world_mut();
+        let other = world.spawn((UWorldRoot { size: Vec2::splat(100.0), ..default() }, fixed())).id();
+        let inner = world.spawn((fixed(), ChildOf(other))).id();
+        let inner = world.spawn((fixed(), ChildOf(inner))).id();
+        world.spawn((fixed(), ChildOf(inner)));
+        app.update();
+        // Depth 3: step = 4 * 0.1.
+        let z = app.world().get::<Transform>(raised).unwrap().translation.z;
+        assert!((z - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn grid_placement_moves_the_item_when_it_changes() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        let world = app.world_mut();
+        let root = world
+            .spawn((
+                UWorldRoot { size: Vec2::new(100.0, 20.0), ..default() },
+                UNode { width: UVal::Px(100.0), height: UVal::Px(20.0), ..default() },
+                ULayout {
+                    display: UDisplay::Grid,
+                    container_ext: ULayoutContainerExt {
+                        grid: ULayoutGridContainer {
+                            template_columns: vec![UTrackSize::Px(50.0), UTrackSize::Px(50.0)],
+                            template_areas: vec!["first second".into()],
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    ..default()
+                },
+            ))
+            .id();
+        let item = world
+            .spawn((
+                UNode { width: UVal::Px(50.0), height: UVal::Px(20.0), ..default() },
+                UGridPlacement::area("second"),
+                ChildOf(root),
+            ))
+            .id();
+        app.update();
+        app.update();
+        assert_eq!(app.world().get::<Transform>(item).unwrap().translation.x, 25.0);
+
+        *app.world_mut().get_mut::<UGridPlacement>(item).unwrap() = UGridPlacement::area("first");
+        app.update();
+        assert_eq!(app.world().get::<Transform>(item).unwrap().translation.x, -25.0);
+    }
+
+    #[test]
+    fn build_constraints_applies_limits_to_content_axes() {
+        let node = UNode {
+            width: UVal::Content,
+            height: UVal::Px(50.0),
+            min_width: UVal::Px(120.0),
+            max_width: UVal::Percent(0.4),
+            max_height: UVal::Px(10.0),
+            ..default()
+        };
+
+        let constraints = build_constraints(Vec2::new(200.0, 50.0), &node, &ULayout::default(), Vec2::new(1000.0, 400.0));
+
+        assert_eq!(constraints.min_width, 120.0);
+        assert_eq!(constraints.max_width, 400.0);
+        // Fixed axes stay tight to the size the parent already clamped.
+        assert_eq!(constraints.min_height, 50.0);
+        assert_eq!(constraints.max_height, 50.0);
+    }
+
+    #[test]
+    fn build_constraints_ignores_percent_limits_without_parent() {
+        let node = UNode {
+            max_width: UVal::Percent(0.5),
+            ..default()
+        };
+
+        let constraints = build_constraints(Vec2::ZERO, &node, &ULayout::default(), Vec2::splat(f32::INFINITY));
+
+        assert_eq!(constraints.min_width, 0.0);
+        assert_eq!(constraints.max_width, f32::INFINITY);
+    }
+
+    #[test]
+    fn build_constraints_keeps_aspect_ratio_nodes_tight() {
+        let node = UNode {
+            width: UVal::Px(160.0),
+            height: UVal::Content,
+            aspect_ratio: Some(16.0 / 9.0),
+            ..default()
+        };
+
+        let constraints = build_constraints(Vec2::new(160.0, 90.0), &node, &ULayout::default(), Vec2::splat(f32::INFINITY));
+
+        assert_eq!(constraints, BoxConstraints::tight(Vec2::new(160.0, 90.0)));
+    }
+
+    #[test]
+    fn resolve_units_turns_calc_and_viewport_units_into_px() {
+        let ctx = UUnitContext {
+            viewport: Vec2::new(1280.0, 720.0),
+            font_size: 20.0,
+            root_font_size: 20.0,
+        };
+        let node = UNode {
+            width: UVal::calc(UCalcSum::percent(1.0) - UCalcSum::px(48.0)),
+            height: UVal::Vh(0.5),
+            min_width: UVal::Rem(2.0),
+            max_width: UVal::calc_min(UCalcSum::vw(0.5), UCalcSum::px(400.0)),
+            ..default()
+        };
+
+        let resolved = node.resolve_units(Vec2::new(300.0, 200.0), &ctx);
+
+        assert_eq!(resolved.width, UVal::Px(252.0));
+        assert_eq!(resolved.height, UVal::Px(360.0));
+        assert_eq!(resolved.min_width, UVal::Px(40.0));
+        assert_eq!(resolved.max_width, UVal::Px(400.0));
+    }
+
+    #[test]
+    fn calc_with_percent_falls_back_to_content_without_base() {
+        let clamp = UVal::calc_clamp(UCalcSum::px(100.0), UCalcSum::percent(0.5), UCalcSum::px(200.0));
+
+        assert_eq!(clamp.normalize(1000.0, &UUnitContext::default()), UVal::Px(200.0));
+        assert_eq!(clamp.normalize(f32::INFINITY, &UUnitContext::default()), UVal::Content);
+    }
+
+    #[test]
+    fn context_free_resolve_rejects_context_units() {
+        assert!(UVal::Vw(0.5).needs_context());
+        assert!(UVal::calc(UCalcSum::percent(1.0) - UCalcSum::em(2.0)).needs_context());
+        assert!(!UVal::calc(UCalcSum::percent(1.0) - UCalcSum::px(48.0)).needs_context());
+        assert_eq!(UVal::calc(UCalcSum::percent(1.0) - UCalcSum::px(48.0)).resolve(148.0), Some(100.0));
+
+        let ctx = UUnitContext { viewport: Vec2::new(1000.0, 500.0), ..default() };
+        assert_eq!(UVal::Vh(0.1).resolve_in(0.0, &ctx), Some(50.0));
+        assert_eq!(UVal::Vh(0.1).resolve(0.0), None);
+        assert_eq!(UVal::calc(UCalcSum::percent(1.0) - UCalcSum::em(2.0)).resolve(100.0), None);
+        assert_eq!(UVal::Em(2.0).resolve_or_zero(100.0), 0.0);
+        assert_eq!(UVal::Vw(0.5).resolve_limit(100.0), None);
+    }
+
+    #[test]
+    fn fixed_origin_maps_container_into_root_space() {
+        let root = Vec2::new(800.0, 600.0);
+
+        assert_eq!(fixed_origin(Vec2::ZERO, root, root), Vec2::ZERO);
+        assert_eq!(
+            fixed_origin(Vec2::new(100.0, -50.0), Vec2::new(200.0, 100.0), root),
+            Vec2::new(400.0, 300.0),
+        );
+    }
+
+    #[test]
+    fn scrollable_overflow_ignores_relative_offsets_and_fixed_items() {
+        let item = |position_type, top, pos: Vec2| {
+            let node = UNode { width: UVal::Px(100.0), height: UVal::Px(50.0), ..default() };
+            let uself = USelf { position_type, top, ..default() };
+            SolverItemOwned {
+                spec: translate_spec(&node, Some(&uself)),
+                result: Box::new(SolverResult { pos, size: Vec2::new(100.0, 50.0), ..default() }),
+                margin: USides { bottom: 5.0, ..default() },
+            }
+        };
+        let items = vec![
+            // Scrolled up by 80px through `top`.
+            item(UPositionType::Relative, UVal::Px(-80.0), Vec2::new(10.0, -70.0)),
+            item(UPositionType::Relative, UVal::Auto, Vec2::new(10.0, 65.0)),
+            item(UPositionType::Fixed, UVal::Px(0.0), Vec2::new(500.0, 500.0)),
+        ];
+
+        let rect = scrollable_overflow(Vec2::new(120.0, 80.0), USides::all(10.0), &items);
+
+        assert_eq!(rect, Rect::new(0.0, 0.0, 120.0, 130.0));
+        assert_eq!(ScrollableOverflow { rect }.overflow(Vec2::new(120.0, 80.0)), Vec2::new(0.0, 50.0));
     }
 
     #[test]
@@ -5,17 +5,22 @@ use bevy::prelude::*;
 ///
 /// Iterates from the deepest tree depth up to the root.
 /// Calculates the **Intrinsic Size** of containers based on their children.
-/// It ignores `Absolute` items as they are out-of-flow.
+/// It ignores `Absolute` and `Fixed` items as they are out-of-flow.
 
 /// Upward Pass with Caching and Reverse Direction Support
+///
+/// Large depth layers are measured on the `ComputeTaskPool`, see `LayoutParallelism`.
 pub fn upward_measure_pass_cached(
     tree_depth: Res<LayoutTreeDepth>,
     mut cache: ResMut<LayoutCache>,
     mut profiler: Option<ResMut<LayoutProfiler>>,
+    units: UnitContextParam,
+    solved_query: Query<(&ComputedSize, Option<&UMeasure>)>,
+    parallelism: Res<LayoutParallelism>,
     
     mut params: ParamSet<(
-        Query<(&IntrinsicSize, &UNode, Option<&USelf>)>,
-        Query<(Entity, &UNode, &LayoutDepth, Option<&Children>, Option<&ULayout>, &mut IntrinsicSize)>,
+        Query<(&IntrinsicSize, &UNode, Option<&USelf>, Option<&UBaseline>)>,
+        Query<(Entity, &UNode, &LayoutDepth, Option<&Children>, Option<&ULayout>, &mut IntrinsicSize, Option<&mut UBaseline>)>,
     )>,
 ) {
     let start = std::time::Instant::now();
@@ -33,19 +38,22 @@ pub fn upward_measure_pass_cached(
             layer_entities.iter()
                 .filter_map(|&entity| {
                     q_parents.get(entity).ok()
-                        .map(|(e, node, _, children, layout, _)| {
+                        .map(|(e, node, _, children, layout, _, _)| {
                             let kids: Vec<Entity> = children.map(|c| c.iter().collect()).unwrap_or_default();
                             
                             let self_dirty = cache.is_dirty(entity);
                             let children_dirty = kids.iter().any(|child| cache.is_dirty(*child));
                             let effectively_dirty = self_dirty || children_dirty;
 
-                            (e, node.clone(), kids, layout.cloned(), effectively_dirty)
+                            // النسب المئوية غير معروفة أثناء القياس الصاعد
+                            let node = node.resolve_units(Vec2::splat(f32::INFINITY), &units.context_for(e));
+                            (e, node, kids, layout.cloned(), effectively_dirty)
                         })
                 })
                 .collect()
         };
 
+        let mut pending: Vec<MeasureWork> = Vec::new();
         for (entity, node_spec, children, layout_opt, is_dirty) in layer_work_items {
             
             // 1. محاولة استخدام الكاش
@@ -53,8 +61,9 @@ pub fn upward_measure_pass_cached(
 
             if !is_dirty {
                 if let Some(cached) = cache.get_cached_intrinsic(entity) {
-                    if let Ok((_, _, _, _, _, mut intrinsic)) = params.p1().get_mut(entity) {
-                        *intrinsic = cached;
+                    if let Ok((_, _, _, _, _, mut intrinsic, _)) = params.p1().get_mut(entity) {
+                        // كتابة نفس القيمة تعلّم Changed<IntrinsicSize> فتتسخ العقدة في الإطار التالي
+                        intrinsic.set_if_neq(cached);
                         used_cache = true;
                     }
                 }
@@ -63,112 +72,74 @@ pub fn upward_measure_pass_cached(
             if used_cache {
                 continue;
             }
-            
-            // 2. الحساب الفعلي
-            calculated_count += 1;
-            
-            let mut calculated_width = 0.0;
-            let mut calculated_height = 0.0;
-            let has_children = !children.is_empty();
-
-            if has_children {
-                let direction = layout_opt.as_ref()
-                    .map(|l| l.flex_direction)
-                    .unwrap_or(UFlexDirection::Row);
-                let legacy_gap = layout_opt.as_ref().map(|l| l.gap).unwrap_or(0.0);
-                let gap = if matches!(direction, UFlexDirection::Row | UFlexDirection::RowReverse) {
-                    layout_opt
-                        .as_ref()
-                        .and_then(|l| l.container_ext.box_align.column_gap)
-                        .unwrap_or(legacy_gap)
-                } else {
-                    layout_opt
-                        .as_ref()
-                        .and_then(|l| l.container_ext.box_align.row_gap)
-                        .unwrap_or(legacy_gap)
-                };
-                
-                let mut accum_main: f32 = 0.0;
-                let mut max_cross: f32 = 0.0;
-                let mut visible_count = 0;
-
-                let q_children = params.p0();
-
-                for child_entity in children {
-                    if let Ok((child_intrinsic, child_node, child_uself_opt)) = q_children.get(child_entity) {
-                        
-                        if let Some(uself) = child_uself_opt {
-                            if uself.position_type == UPositionType::Absolute {
-                                continue;
-                            }
-                        }
-
-                        let w = child_intrinsic.width;
-                        let h = child_intrinsic.height;
-                        let m = child_node.margin;
-
-                        // === التحديث هنا: دمج الاتجاهات المعكوسة ===
-                        match direction {
-                            // الصفوف (عادي ومعكوس) تحسب العرض تراكمياً
-                            UFlexDirection::Row | UFlexDirection::RowReverse => {
-                                accum_main += w + m.left + m.right;
-                                max_cross = max_cross.max(h + m.top + m.bottom);
-                            },
-                            // الأعمدة (عادي ومعكوس) تحسب الارتفاع تراكمياً
-                            UFlexDirection::Column | UFlexDirection::ColumnReverse => {
-                                accum_main += h + m.top + m.bottom;
-                                max_cross = max_cross.max(w + m.left + m.right);
-                            },
-                        }
-                        visible_count += 1;
-                    }
-                }
 
-                if visible_count > 1 {
-                    accum_main += (visible_count - 1) as f32 * gap;
-                }
+            let q_children = params.p0();
+            let measured_children: Vec<MeasureChild> = children
+                .iter()
+                .filter_map(|&child| q_children.get(child).ok())
+                .filter(|(_, _, uself, _)| !uself.is_some_and(|u| u.position_type.is_out_of_flow()))
+                .map(|(intrinsic, node, _, baseline)| MeasureChild {
+                    size: *intrinsic,
+                    margin: node.margin,
+                    baseline: baseline.copied().unwrap_or_default(),
+                })
+                .collect();
+            let (previous_size, measure) = solved_query
+                .get(entity)
+                .map_or((Vec2::ZERO, None), |(computed, measure)| (computed.size(), measure.cloned()));
+
+            pending.push(MeasureWork {
+                entity,
+                node_spec,
+                layout: layout_opt,
+                children: measured_children,
+                measure,
+                previous_size,
+                result: (IntrinsicSize::default(), None),
+            });
+        }
+
+        // 2. الحساب الفعلي (متوازٍ في الطبقات الكبيرة)
+        calculated_count += pending.len();
+        parallelism.for_each_mut(&mut pending, |work| {
+            work.result = measure_intrinsic(
+                &work.node_spec,
+                work.layout.as_ref(),
+                &work.children,
+                work.measure.as_ref(),
+                work.previous_size,
+            );
+        });
 
-                // === التحديث هنا أيضاً عند تعيين القيم النهائية ===
-                match direction {
-                    UFlexDirection::Row | UFlexDirection::RowReverse => {
-                        calculated_width = accum_main;
-                        calculated_height = max_cross;
-                    },
-                    UFlexDirection::Column | UFlexDirection::ColumnReverse => {
-                        calculated_width = max_cross;
-                        calculated_height = accum_main;
-                    },
+        // 3. الكتابة بترتيب الطبقة
+        let mut q_write = params.p1();
+        for MeasureWork { entity, result: (new_size, new_baseline), .. } in pending {
+            if let Ok((_, _, _, _, _, mut intrinsic, baseline)) = q_write.get_mut(entity) {
+                // العقد بدون أطفال (مثل النصوص) تنشر خط الأساس بنفسها.
+                if let (Some(mut baseline), Some(new_baseline)) = (baseline, new_baseline)
+                    && *baseline != new_baseline
+                {
+                    *baseline = new_baseline;
                 }
-            }
 
-            let h_pad = node_spec.padding.width_sum();
-            let v_pad = node_spec.padding.height_sum();
-
-            let mut q_write = params.p1();
-            if let Ok((_, _, _, _, _, mut intrinsic)) = q_write.get_mut(entity) {
-                
-                let new_width = match node_spec.width {
-                    UVal::Px(v) => v,
-                    _ => calculated_width + h_pad,
-                };
-
-                let new_height = match node_spec.height {
-                    UVal::Px(v) => v,
-                    _ => calculated_height + v_pad,
-                };
-                
                 // منع التكرار اللانهائي (Check diff > epsilon)
-                if (intrinsic.width - new_width).abs() > 0.001 || (intrinsic.height - new_height).abs() > 0.001 {
-                    intrinsic.width = new_width;
-                    intrinsic.height = new_height;
+                let moved = |a: f32, b: f32| (a - b).abs() > 0.001;
+                if moved(intrinsic.width, new_size.width)
+                    || moved(intrinsic.height, new_size.height)
+                    || moved(intrinsic.min_width, new_size.min_width)
+                    || moved(intrinsic.min_height, new_size.min_height)
+                {
+                    *intrinsic = new_size;
+                    // الأب يعيد القياس والحل في هذا الإطار
+                    cache.mark_dirty(entity);
                 }
-                
-                cache.cache_intrinsic(entity, IntrinsicSize { width: new_width, height: new_height });
+
+                cache.cache_intrinsic(entity, new_size);
             }
         }
     }
     
-    cache.clear_all_dirty();
+    // العلامات تبقى للتمرير النازل الذي يمسحها
     cache.increment_frame();
     
     if let Some(ref mut prof) = profiler {
@@ -176,3 +147,332 @@ pub fn upward_measure_pass_cached(
         prof.dirty_nodes = calculated_count;
     }
 }
+
+/// A node of the current layer waiting to be measured.
+struct MeasureWork {
+    entity: Entity,
+    node_spec: UNode,
+    layout: Option<ULayout>,
+    children: Vec<MeasureChild>,
+    measure: Option<UMeasure>,
+    previous_size: Vec2,
+    result: (IntrinsicSize, Option<UBaseline>),
+}
+
+/// An in-flow child, as seen by its parent's intrinsic measurement.
+pub(crate) struct MeasureChild {
+    pub size: IntrinsicSize,
+    pub margin: USides,
+    pub baseline: UBaseline,
+}
+
+/// Intrinsic size of a node from its in-flow `children`, or from `measure` when it has none.
+///
+/// `node_spec` is resolved against an infinite base, since percentages are unknown
+/// here. `previous_size` is the node's last solved size, used to wrap a measured
+/// leaf or the lines of a wrapping flex container.
+/// The baseline is returned only when derived from children.
+pub(crate) fn measure_intrinsic(
+    node_spec: &UNode,
+    layout_opt: Option<&ULayout>,
+    children: &[MeasureChild],
+    measure: Option<&UMeasure>,
+    previous_size: Vec2,
+) -> (IntrinsicSize, Option<UBaseline>) {
+    let h_pad = node_spec.padding.width_sum();
+    let v_pad = node_spec.padding.height_sum();
+    let mut calculated_width = 0.0;
+    let mut calculated_height = 0.0;
+    let mut min_width = 0.0;
+    let mut min_height = 0.0;
+    let mut baseline_children: Vec<BaselineChild> = Vec::new();
+
+    if !children.is_empty() {
+        let direction = layout_opt
+            .map(|l| l.solver_direction())
+            .unwrap_or(UFlexDirection::Row);
+        let legacy_gap = layout_opt.map(|l| l.gap).unwrap_or(0.0);
+        let gap = if matches!(direction, UFlexDirection::Row | UFlexDirection::RowReverse) {
+            layout_opt
+                .and_then(|l| l.container_ext.box_align.column_gap)
+                .unwrap_or(legacy_gap)
+        } else {
+            layout_opt
+                .and_then(|l| l.container_ext.box_align.row_gap)
+                .unwrap_or(legacy_gap)
+        };
+
+        // Wrapping flex lines and grid/masonry tracks break between items, so
+        // their min-content main size is the widest item instead of the sum.
+        let breaks_lines = layout_opt.is_some_and(|l| match l.display {
+            UDisplay::Flex => l.container_ext.flex.wrap != UFlexWrap::NoWrap,
+            UDisplay::Grid | UDisplay::Masonry => true,
+            _ => false,
+        });
+
+        let mut accum_main: f32 = 0.0;
+        let mut max_cross: f32 = 0.0;
+        let mut min_main: f32 = 0.0;
+        let mut min_cross: f32 = 0.0;
+
+        for (visible_count, child) in children.iter().enumerate() {
+            let w = child.size.width;
+            let h = child.size.height;
+            let m = child.margin;
+
+            let top = match direction {
+                UFlexDirection::Row | UFlexDirection::RowReverse => m.top,
+                UFlexDirection::Column | UFlexDirection::ColumnReverse => {
+                    accum_main + visible_count as f32 * gap + m.top
+                }
+            };
+            baseline_children.push(BaselineChild {
+                top,
+                margin_top: m.top,
+                bottom: top + h + m.bottom,
+                first: child.baseline.first.unwrap_or(h),
+                last: child.baseline.last.unwrap_or(h),
+            });
+
+            // === التحديث هنا: دمج الاتجاهات المعكوسة ===
+            match direction {
+                // الصفوف (عادي ومعكوس) تحسب العرض تراكمياً
+                UFlexDirection::Row | UFlexDirection::RowReverse => {
+                    accum_main += w + m.left + m.right;
+                    max_cross = max_cross.max(h + m.top + m.bottom);
+                    let child_min = child.size.min_width + m.left + m.right;
+                    min_main = if breaks_lines { min_main.max(child_min) } else { min_main + child_min };
+                    min_cross = min_cross.max(child.size.min_height + m.top + m.bottom);
+                },
+                // الأعمدة (عادي ومعكوس) تحسب الارتفاع تراكمياً
+                UFlexDirection::Column | UFlexDirection::ColumnReverse => {
+                    accum_main += h + m.top + m.bottom;
+                    max_cross = max_cross.max(w + m.left + m.right);
+                    let child_min = child.size.min_height + m.top + m.bottom;
+                    min_main = if breaks_lines { min_main.max(child_min) } else { min_main + child_min };
+                    min_cross = min_cross.max(child.size.min_width + m.left + m.right);
+                },
+            }
+        }
+
+        if children.len() > 1 {
+            accum_main += (children.len() - 1) as f32 * gap;
+            if !breaks_lines {
+                min_main += (children.len() - 1) as f32 * gap;
+            }
+        }
+
+        // الحاوية الملتفة تكسر أسطرها عند حجمها المحلول السابق، مثل عقد القياس
+        let is_row = matches!(direction, UFlexDirection::Row | UFlexDirection::RowReverse);
+        let limit = if is_row {
+            wrap_limit(node_spec.width, previous_size.x, h_pad)
+        } else {
+            wrap_limit(node_spec.height, previous_size.y, v_pad)
+        };
+        if let (Some(layout), Some(limit)) = (layout_opt.filter(|l| wraps_lines(l)), limit) {
+            let cross_gap = if is_row {
+                layout.container_ext.box_align.row_gap.unwrap_or(legacy_gap)
+            } else {
+                layout.container_ext.box_align.column_gap.unwrap_or(legacy_gap)
+            };
+            max_cross = wrapped_cross_size(children, is_row, limit, gap, cross_gap);
+        }
+
+        // === التحديث هنا أيضاً عند تعيين القيم النهائية ===
+        match direction {
+            UFlexDirection::Row | UFlexDirection::RowReverse => {
+                calculated_width = accum_main;
+                calculated_height = max_cross;
+                (min_width, min_height) = (min_main, min_cross);
+            },
+            UFlexDirection::Column | UFlexDirection::ColumnReverse => {
+                calculated_width = max_cross;
+                calculated_height = accum_main;
+                (min_width, min_height) = (min_cross, min_main);
+            },
+        }
+    }
+
+    // عقد القياس (نص ملتف، ودجات مخصصة): الارتفاع يعتمد على العرض.
+    if baseline_children.is_empty()
+        && let Some(measure) = measure
+    {
+        // بدون عرض ثابت نستخدم عرض الإطار السابق؛ التمرير النازل
+        // يعيد القياس بالعرض الفعلي.
+        let available = wrap_limit(node_spec.width, previous_size.x, h_pad);
+        let max_content = measure.measure(None);
+        let size = available
+            .filter(|w| *w < max_content.x)
+            .map_or(max_content, |w| measure.measure(Some(w)));
+        calculated_width = max_content.x;
+        calculated_height = size.y;
+        // أضيق عرض بلا تجاوز (أعرض كلمة في النص)
+        min_width = measure.measure(Some(0.0)).x.min(max_content.x);
+        min_height = size.y;
+    }
+
+    let baseline = (!baseline_children.is_empty()).then(|| {
+        container_baseline(&baseline_children, layout_opt, calculated_height, node_spec.padding.top)
+    });
+
+    // حاوية تمرير غير محددة بالمحتوى لا تكبر مع أبنائه (مثل نافذة التمرير)؛
+    // `Clip` يقص فقط ويبقى حجمه من المحتوى
+    if let Some(layout) = layout_opt {
+        if layout.overflow_x.is_scroll_container() {
+            min_width = 0.0;
+            if !matches!(node_spec.width, UVal::Content | UVal::Auto) {
+                calculated_width = 0.0;
+            }
+        }
+        if layout.overflow_y.is_scroll_container() {
+            min_height = 0.0;
+            if !matches!(node_spec.height, UVal::Content | UVal::Auto) {
+                calculated_height = 0.0;
+            }
+        }
+    }
+
+    let new_width = match node_spec.width {
+        UVal::Px(v) => v,
+        _ => calculated_width + h_pad,
+    };
+
+    let new_height = match node_spec.height {
+        UVal::Px(v) => v,
+        _ => calculated_height + v_pad,
+    };
+
+    // Percentage limits need the parent size and are applied in the downward pass.
+    let new_width = clamp_to_limits(new_width, node_spec.min_width, node_spec.max_width, f32::INFINITY);
+    let new_height = clamp_to_limits(new_height, node_spec.min_height, node_spec.max_height, f32::INFINITY);
+
+    // Aspect ratio: a fixed axis drives the other one, otherwise width wins.
+    let (new_width, new_height) = match node_spec.aspect_ratio.filter(|r| r.is_finite() && *r > 0.0) {
+        Some(ratio) => match (node_spec.width, node_spec.height) {
+            (UVal::Px(_), UVal::Px(_)) => (new_width, new_height),
+            (_, UVal::Px(h)) => (
+                clamp_to_limits(h * ratio, node_spec.min_width, node_spec.max_width, f32::INFINITY),
+                new_height,
+            ),
+            _ => (
+                new_width,
+                clamp_to_limits(new_width / ratio, node_spec.min_height, node_spec.max_height, f32::INFINITY),
+            ),
+        },
+        None => (new_width, new_height),
+    };
+
+    // الحد الأدنى للمحتوى: الحجم الثابت يغلبه، ولا يتجاوز الحجم المفضل
+    let min_size = |value: UVal, min_content: f32, pad: f32, min: UVal, max: UVal, preferred: f32| {
+        let size = match value {
+            UVal::Px(v) => v,
+            _ => min_content + pad,
+        };
+        clamp_to_limits(size, min, max, f32::INFINITY).min(preferred)
+    };
+    let min_width = min_size(node_spec.width, min_width, h_pad, node_spec.min_width, node_spec.max_width, new_width);
+    let min_height = min_size(node_spec.height, min_height, v_pad, node_spec.min_height, node_spec.max_height, new_height);
+
+    (IntrinsicSize { width: new_width, height: new_height, min_width, min_height }, baseline)
+}
+
+/// Whether `layout` is a flex container that breaks its items into lines.
+pub(crate) fn wraps_lines(layout: &ULayout) -> bool {
+    layout.display == UDisplay::Flex && layout.container_ext.flex.wrap != UFlexWrap::NoWrap
+}
+
+/// Whether a wrapping flex container was solved at a different cross size
+/// than it measured, i.e. its lines must be broken again at the solved size.
+pub(crate) fn wrapped_size_moved(layout: &ULayout, node: &UNode, intrinsic: IntrinsicSize, solved: Vec2) -> bool {
+    if !wraps_lines(layout) {
+        return false;
+    }
+    let (cross, measured, solved) = if AxisHelper::new(layout.solver_direction()).is_row() {
+        (node.height, intrinsic.height, solved.y)
+    } else {
+        (node.width, intrinsic.width, solved.x)
+    };
+    matches!(cross, UVal::Content | UVal::Auto) && (measured - solved).abs() > 0.5
+}
+
+/// Content size available for wrapping: a fixed size, or else the last solved
+/// one. `None` before the first solve.
+fn wrap_limit(size: UVal, previous: f32, padding: f32) -> Option<f32> {
+    match size {
+        UVal::Px(v) => Some((v - padding).max(0.0)),
+        _ if previous > 0.0 => Some((previous - padding).max(0.0)),
+        _ => None,
+    }
+}
+
+/// Cross size of flex lines broken at `limit`, the way `FlexPlacer` breaks them.
+fn wrapped_cross_size(children: &[MeasureChild], is_row: bool, limit: f32, gap: f32, cross_gap: f32) -> f32 {
+    let mut total = 0.0;
+    let mut line_main = 0.0;
+    let mut line_cross: f32 = 0.0;
+    for (i, child) in children.iter().enumerate() {
+        let m = child.margin;
+        let (main, cross) = if is_row {
+            (child.size.width + m.left + m.right, child.size.height + m.top + m.bottom)
+        } else {
+            (child.size.height + m.top + m.bottom, child.size.width + m.left + m.right)
+        };
+        if i > 0 && line_main + gap + main > limit {
+            total += line_cross + cross_gap;
+            line_main = main;
+            line_cross = cross;
+        } else {
+            line_main = if i == 0 { main } else { line_main + gap + main };
+            line_cross = line_cross.max(cross);
+        }
+    }
+    total + line_cross
+}
+
+/// An in-flow child in its parent's content box, used to derive the parent's baseline.
+struct BaselineChild {
+    /// Top edge of the border box.
+    top: f32,
+    margin_top: f32,
+    /// Bottom edge of the margin box.
+    bottom: f32,
+    first: f32,
+    last: f32,
+}
+
+/// Derives a container's baselines from its first/last child, assuming the
+/// children sit at the start of the cross axis. A row aligned by baseline
+/// shares the largest child baseline.
+fn container_baseline(
+    children: &[BaselineChild],
+    layout: Option<&ULayout>,
+    content_height: f32,
+    padding_top: f32,
+) -> UBaseline {
+    let (Some(first_child), Some(last_child)) = (children.first(), children.last()) else {
+        return UBaseline::default();
+    };
+    let direction = layout.map(|l| l.flex_direction).unwrap_or(UFlexDirection::Row);
+
+    let (first, last) = match direction {
+        UFlexDirection::Row | UFlexDirection::RowReverse => {
+            if layout.is_some_and(|l| l.align_items == UAlignItems::Baseline) {
+                let max_of = |f: fn(&BaselineChild) -> f32| children.iter().map(f).fold(f32::MIN, f32::max);
+                (max_of(|c| c.top + c.first), max_of(|c| c.top + c.last))
+            } else {
+                (first_child.top + first_child.first, last_child.top + last_child.last)
+            }
+        }
+        UFlexDirection::Column => (first_child.top + first_child.first, last_child.top + last_child.last),
+        // المعكوس: أول عنصر بصرياً هو آخر طفل.
+        UFlexDirection::ColumnReverse => (
+            content_height - last_child.bottom + last_child.margin_top + last_child.first,
+            content_height - first_child.bottom + first_child.margin_top + first_child.last,
+        ),
+    };
+
+    UBaseline {
+        first: Some(padding_top + first),
+        last: Some(padding_top + last),
+    }
+}
@@ -10,6 +10,18 @@ use crate::internal_prelude::*;
 pub struct SolverResult {
     pub size: Vec2,
     pub pos: Vec2,
+    /// Rotation around Z in radians, applied to the child's `Transform`.
+    /// `None` leaves the rotation untouched.
+    pub rotation: Option<f32>,
+}
+
+/// The output of a container's solve, besides the items' results.
+#[derive(Debug, Clone, Default)]
+pub struct SolvedContainer {
+    pub size: Vec2,
+    /// Tracks sized by the grid placer, in the same space as the items' `pos`.
+    /// `None` for other displays.
+    pub grid_tracks: Option<ComputedGridTracks>,
 }
 
 /// Combines spec, result, and margin for processing.
@@ -37,6 +49,15 @@ pub struct SolverConfig {
     pub grid_auto_flow: UGridAutoFlow,
     pub grid_auto_rows: UTrackSize,
     pub grid_auto_columns: UTrackSize,
+    pub grid_template_areas: Vec<String>,
+    pub grid_column_line_names: Vec<UGridLineName>,
+    pub grid_row_line_names: Vec<UGridLineName>,
+    pub masonry: ULayoutMasonryContainer,
+    pub radial: URadialLayout,
+    /// Placer for `UDisplay::Custom`, looked up in `LayoutPlacerRegistry`.
+    pub custom_placer: Option<SharedPlacer>,
+    /// Inline direction; `Rtl` mirrors the in-flow items after placement.
+    pub direction: UDirection,
     
     // Width/Height modes to determine sizing constraints
     pub width_mode: SolverSizeMode,
@@ -65,6 +86,12 @@ pub struct PlacementContext {
     pub grid_auto_flow: UGridAutoFlow,
     pub grid_auto_rows: UTrackSize,
     pub grid_auto_columns: UTrackSize,
+    pub grid_template_areas: Vec<String>,
+    pub grid_column_line_names: Vec<UGridLineName>,
+    pub grid_row_line_names: Vec<UGridLineName>,
+    pub masonry: ULayoutMasonryContainer,
+    pub radial: URadialLayout,
+    pub custom_placer: Option<SharedPlacer>,
 }
 
 fn map_align_items_to_ext(align: UAlignItems) -> UAlignItemsExt {
@@ -93,6 +120,7 @@ fn resolve_flex_basis(basis: UVal, default_content: f32, available_main: f32) ->
         UVal::Percent(p) => Some((p * available_main).max(0.0)),
         UVal::Content => Some(default_content.max(0.0)),
         UVal::Auto | UVal::Flex(_) => None,
+        other => other.resolve(available_main).map(|v| v.max(0.0)),
     }
 }
 
@@ -100,6 +128,91 @@ fn is_ext_stretch(value: UAlignSelfExt) -> bool {
     matches!(value, UAlignSelfExt::Stretch)
 }
 
+/// Per-item state for resolving flexible lengths on the main axis.
+#[derive(Debug, Clone, Copy)]
+struct FlexSizingItem {
+    idx: usize,
+    base: f32,
+    grow: f32,
+    shrink_weight: f32,
+    min: f32,
+    max: f32,
+    target: f32,
+    /// Main-axis margins, counted when breaking flex lines.
+    margin: f32,
+    frozen: bool,
+}
+
+/// Breaks items into flex lines by their hypothetical outer main size, the
+/// same way `FlexPlacer` breaks them after sizing.
+fn break_flex_lines(items: &[FlexSizingItem], available_main: f32, gap: f32) -> Vec<std::ops::Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut line_main = 0.0;
+    for (i, item) in items.iter().enumerate() {
+        let span = item.target + item.margin;
+        if i > start && line_main + gap + span > available_main {
+            lines.push(start..i);
+            start = i;
+            line_main = span;
+        } else {
+            line_main = if i == start { span } else { line_main + gap + span };
+        }
+    }
+    lines.push(start..items.len());
+    lines
+}
+
+/// Distributes `items_space` between the items (CSS "resolve flexible lengths").
+///
+/// Free space is shared by grow factor (or shrink weight), each share is clamped
+/// to the item's min/max, and items that violate a limit are frozen before the
+/// remaining space is distributed again.
+fn resolve_flexible_lengths(items: &mut [FlexSizingItem], items_space: f32, growing: bool) {
+    for item in items.iter_mut() {
+        let flexible = if growing { item.grow > 0.0 } else { item.shrink_weight > 0.0 };
+        let already_clamped = if growing { item.base > item.target } else { item.base < item.target };
+        item.frozen = !flexible || already_clamped;
+    }
+
+    loop {
+        let frozen_space: f32 = items.iter().filter(|i| i.frozen).map(|i| i.target).sum();
+        let unfrozen_base: f32 = items.iter().filter(|i| !i.frozen).map(|i| i.base).sum();
+        let total_factor: f32 = items
+            .iter()
+            .filter(|i| !i.frozen)
+            .map(|i| if growing { i.grow } else { i.shrink_weight })
+            .sum();
+        if total_factor <= 0.0 {
+            break;
+        }
+
+        let free = items_space - frozen_space - unfrozen_base;
+        let mut total_violation = 0.0;
+        let mut violations = Vec::with_capacity(items.len());
+        for (i, item) in items.iter_mut().enumerate() {
+            if item.frozen {
+                continue;
+            }
+            let factor = if growing { item.grow } else { item.shrink_weight };
+            let unclamped = item.base + free * (factor / total_factor);
+            item.target = unclamped.min(item.max).max(item.min).max(0.0);
+            let violation = item.target - unclamped;
+            total_violation += violation;
+            violations.push((i, violation));
+        }
+
+        if total_violation.abs() <= 0.001 {
+            break;
+        }
+        for (i, violation) in violations {
+            if (total_violation > 0.0 && violation > 0.0) || (total_violation < 0.0 && violation < 0.0) {
+                items[i].frozen = true;
+            }
+        }
+    }
+}
+
 // =========================================================
 // 3. The Core Engine
 // =========================================================
@@ -112,18 +225,30 @@ fn is_ext_stretch(value: UAlignSelfExt) -> bool {
 /// 3. Cross axis sizing (including Stretch).
 /// 4. Delegating placement to the specific algorithm (Bridge).
 /// 5. Handling absolute positioning.
+///
+/// Returns the container size; see [`solve_layout`] for the grid tracks.
 pub fn solve_flex_layout(
+    config: &SolverConfig,
+    constraints: BoxConstraints,
+    items: &mut [SolverItem],
+) -> Vec2 {
+    solve_layout(config, constraints, items).size
+}
+
+/// [`solve_flex_layout`], also returning what the placer reports about the container.
+pub fn solve_layout(
     config: &SolverConfig,
     constraints: BoxConstraints, 
     items: &mut [SolverItem],   
-) -> Vec2 {
-    let axis = AxisHelper::new(config.layout.flex_direction);
+) -> SolvedContainer {
+    let direction = config.layout.solver_direction();
+    let axis = AxisHelper::new(direction);
 
     // 1. Separate Normal / Absolute (Check position_type)
     let mut normal_indices = Vec::new();
     let mut absolute_indices = Vec::new();
     for (i, item) in items.iter().enumerate() {
-        if item.spec.position_type == UPositionType::Absolute {
+        if item.spec.position_type.is_out_of_flow() {
             absolute_indices.push(i);
         } else {
             normal_indices.push(i);
@@ -131,10 +256,27 @@ pub fn solve_flex_layout(
     }
     normal_indices.sort_by_key(|&i| items[i].spec.order);
 
+    // Items are placed left-to-right and mirrored afterwards, so physical
+    // `Left`/`Right` alignments on the horizontal axis (justify in a row,
+    // align in a column) are swapped up front to survive the mirror.
+    let rtl = config.direction == UDirection::Rtl && config.layout.display != UDisplay::Radial;
+    let mirror_justify = rtl && axis.is_row();
+    let mirror_align = rtl && !axis.is_row();
+    for item in items.iter_mut() {
+        if mirror_justify {
+            item.spec.justify_self_ext = item.spec.justify_self_ext.map(UAlignSelfExt::mirrored);
+        }
+        if mirror_align {
+            item.spec.align_self_ext = item.spec.align_self_ext.map(UAlignSelfExt::mirrored);
+        }
+    }
+    let justify_items = config.justify_items.map(|j| if mirror_justify { j.mirrored() } else { j });
+
     // 2. Prepare Constraints
     let (min_main, max_main, min_cross, max_cross) = axis.extract_constraints(constraints);
     let padding = axis.extract_padding(config.padding);
     let available_main = (max_main - padding.main).max(0.0);
+    let available_cross = (max_cross - padding.cross).max(0.0);
     let main_gap = if axis.is_row() {
         config.column_gap.unwrap_or(config.gap)
     } else {
@@ -147,13 +289,13 @@ pub fn solve_flex_layout(
     };
     
     // 3. Calculate Sizes (Flexbox Sizing Loop)
-    let mut used_main = 0.0;
-    let mut total_grow = 0.0;
-    let mut total_shrink_weight = 0.0;
-    let mut shrink_data: Vec<(usize, f32)> = Vec::with_capacity(normal_indices.len());
+    // Flex items get the CSS automatic minimum: content-sized and flexible items
+    // never shrink below their intrinsic content unless `min_*` says otherwise.
+    let apply_auto_min = config.layout.display == UDisplay::Flex;
+    let mut sizing: Vec<FlexSizingItem> = Vec::with_capacity(normal_indices.len());
     
     for &idx in &normal_indices {
-        let item = &mut items[idx];
+        let item = &items[idx];
         let (m_start, m_end, _, _) = axis.extract_margin_sides(item.margin);
         let margin_span = m_start + m_end;
         let (main_mode, main_val, main_flex_factor) = axis.get_main_spec(&item.spec);
@@ -167,67 +309,84 @@ pub fn solve_flex_layout(
             SolverSizeMode::Content => main_val,
             SolverSizeMode::Auto => main_val,
         };
+        // A definite cross size transfers through the aspect ratio to a
+        // content-sized main axis.
+        if let Some(ratio) = item.spec.aspect_ratio {
+            let (cross_mode, cross_val, _) = axis.get_cross_spec(&item.spec);
+            let definite_cross = match cross_mode {
+                SolverSizeMode::Fixed => Some(cross_val),
+                SolverSizeMode::Percent if available_cross.is_finite() => Some(cross_val * available_cross),
+                _ => None,
+            };
+            if let Some(cross) = definite_cross {
+                if matches!(main_mode, SolverSizeMode::Content | SolverSizeMode::Auto) && grow_factor <= 0.0 {
+                    base_size = axis.main_from_ratio(ratio, axis.clamp_cross(&item.spec, cross, available_cross));
+                }
+            }
+        }
         if let Some(basis) = item.spec.flex_basis {
             if let Some(resolved_basis) = resolve_flex_basis(basis, base_size, available_main) {
                 base_size = resolved_basis;
             }
         }
 
+        let (min_limit, max_limit) = axis.get_main_limits(&item.spec);
+        let max_size = max_limit.resolve_limit(available_main).unwrap_or(f32::INFINITY);
+        let min_size = match min_limit.resolve_limit(available_main) {
+            Some(min) => min,
+            None if apply_auto_min
+                && min_limit == UVal::Auto
+                && !matches!(main_mode, SolverSizeMode::Fixed | SolverSizeMode::Percent) =>
+            {
+                axis.get_main_min_content(&item.spec).min(max_size)
+            }
+            None => 0.0,
+        };
+
         let shrink_factor = item.spec.flex_shrink.unwrap_or(1.0).max(0.0);
-        let shrink_weight = (base_size.max(1.0)) * shrink_factor;
-        total_shrink_weight += shrink_weight;
-        shrink_data.push((idx, shrink_weight));
-
-        if grow_factor > 0.0 {
-            total_grow += grow_factor;
-            item.result.size = axis.to_world(base_size, 0.0);
-            used_main += margin_span; 
-        } else {
-            used_main += base_size + margin_span;
-            item.result.size = axis.to_world(base_size, 0.0);
-        }
+        let hypothetical = base_size.min(max_size).max(min_size);
+        sizing.push(FlexSizingItem {
+            idx,
+            base: base_size,
+            grow: grow_factor,
+            shrink_weight: (base_size.max(1.0)) * shrink_factor,
+            min: min_size,
+            max: max_size,
+            target: hypothetical,
+            margin: margin_span,
+            frozen: false,
+        });
     }
 
-    if normal_indices.len() > 1 {
-        used_main += (normal_indices.len() as f32 - 1.0) * main_gap;
-    }
-    
-    // 4. Apply Flex Grow
-    let positive_free_space = (available_main - used_main).max(0.0);
-    if total_grow > 0.0 && positive_free_space > 0.0 {
-        let unit = positive_free_space / total_grow;
-        for &idx in &normal_indices {
-            let item = &mut items[idx];
-            let (_, _, legacy_main_flex_factor) = axis.get_main_spec(&item.spec);
-            let ext_grow = item.spec.flex_grow.unwrap_or(0.0).max(0.0);
-            let grow_factor = if ext_grow > 0.0 { ext_grow } else { legacy_main_flex_factor.max(0.0) };
-            if grow_factor > 0.0 {
-                let current_base = axis.from_world(item.result.size).0;
-                let added = grow_factor * unit;
-                item.result.size = axis.to_world(current_base + added, 0.0);
-                used_main += added;
-            }
+    // 4. Resolve flexible lengths (grow into free space or shrink on overflow),
+    //    freezing items as they hit their min/max limits. A wrapping container
+    //    breaks its items into lines first and resolves each line on its own.
+    let wraps = config.layout.display == UDisplay::Flex
+        && config.flex_wrap != UFlexWrap::NoWrap
+        && available_main.is_finite();
+    let lines = if wraps {
+        break_flex_lines(&sizing, available_main, main_gap)
+    } else {
+        std::iter::once(0..sizing.len()).collect()
+    };
+    let mut used_main: f32 = 0.0;
+    for line in lines {
+        let line_items = &mut sizing[line];
+        let outer: f32 = line_items.iter().map(|s| s.margin).sum::<f32>()
+            + line_items.len().saturating_sub(1) as f32 * main_gap;
+        let items_space = available_main - outer;
+        let free_space = items_space - line_items.iter().map(|s| s.target).sum::<f32>();
+        let total_grow: f32 = line_items.iter().map(|s| s.grow).sum();
+        if available_main.is_finite() && ((free_space > 0.0 && total_grow > 0.0) || free_space < 0.0) {
+            resolve_flexible_lengths(line_items, items_space, free_space > 0.0);
         }
+        used_main = used_main.max(line_items.iter().map(|s| s.target).sum::<f32>() + outer);
     }
 
-    // 4b. Apply Flex Shrink if content overflows the main axis.
-    let overflow = (used_main - available_main).max(0.0);
-    if overflow > 0.0 && total_shrink_weight > 0.0 {
-        for (idx, weight) in shrink_data {
-            if weight <= 0.0 {
-                continue;
-            }
-            let item = &mut items[idx];
-            let current_main = axis.from_world(item.result.size).0;
-            let shrink_share = overflow * (weight / total_shrink_weight);
-            let new_main = (current_main - shrink_share).max(0.0);
-            used_main -= current_main - new_main;
-            item.result.size = axis.to_world(new_main, 0.0);
-        }
+    for sized in &sizing {
+        items[sized.idx].result.size = axis.to_world(sized.target, 0.0);
     }
-
     // 5. Cross Axis Sizing
-    let available_cross = (max_cross - padding.cross).max(0.0);
     let mut max_child_cross: f32 = 0.0;
     for &idx in &normal_indices {
         let item = &mut items[idx];
@@ -252,20 +411,26 @@ pub fn solve_flex_layout(
             Some(value) => is_ext_stretch(value),
         };
 
-        if should_stretch && cross_mode != SolverSizeMode::Fixed {
+        let current_main = axis.from_world(item.result.size).0;
+        let ratio_cross = item.spec.aspect_ratio
+            .filter(|_| !matches!(cross_mode, SolverSizeMode::Fixed | SolverSizeMode::Percent));
+        if let Some(ratio) = ratio_cross {
+            // The resolved main size decides the cross size; stretch does not apply.
+            child_cross = axis.cross_from_ratio(ratio, current_main);
+        } else if should_stretch && cross_mode != SolverSizeMode::Fixed {
             child_cross = (available_cross - m_cross_start - m_cross_end).max(0.0);
         }
+        child_cross = axis.clamp_cross(&item.spec, child_cross, available_cross);
 
         max_child_cross = max_child_cross.max(child_cross + m_cross_start + m_cross_end);
-        let current_main = axis.from_world(item.result.size).0;
         item.result.size = axis.to_world(current_main, child_cross);
     }
 
     // 6. Initial Container Size
     let container_main = (used_main + padding.main).clamp(min_main, max_main);
-    let container_cross = if constraints.min_width == constraints.max_width && config.layout.flex_direction == UFlexDirection::Column {
+    let container_cross = if constraints.min_width == constraints.max_width && direction == UFlexDirection::Column {
         max_cross
-    } else if constraints.min_height == constraints.max_height && config.layout.flex_direction == UFlexDirection::Row {
+    } else if constraints.min_height == constraints.max_height && direction == UFlexDirection::Row {
         max_cross
     } else {
         max_child_cross + padding.cross
@@ -285,7 +450,7 @@ pub fn solve_flex_layout(
         cross_gap,
         justify_content: config.layout.justify_content, 
         align_items: config.layout.align_items,
-        justify_items: config.justify_items,
+        justify_items,
         align_content: config.flex_align_content.or(config.align_content),
         flex_wrap: config.flex_wrap,
         grid_columns: config.grid_columns,
@@ -294,10 +459,16 @@ pub fn solve_flex_layout(
         grid_auto_flow: config.grid_auto_flow,
         grid_auto_rows: config.grid_auto_rows,
         grid_auto_columns: config.grid_auto_columns,
+        grid_template_areas: config.grid_template_areas.clone(),
+        grid_column_line_names: config.grid_column_line_names.clone(),
+        grid_row_line_names: config.grid_row_line_names.clone(),
+        masonry: config.masonry,
+        radial: config.radial,
+        custom_placer: config.custom_placer.clone(),
     };
 
     // Receive actual size from the placer
-    let used_size_from_placer = final_size_with_indices(
+    let (used_size_from_placer, mut grid_tracks) = final_size_with_indices(
         config.layout.clone(),
         items,
         &normal_indices,
@@ -318,35 +489,32 @@ pub fn solve_flex_layout(
         final_container_size.y = placer_size_world.y.clamp(constraints.min_height, constraints.max_height);
     }
 
-    // 9. Relative Offsets
+    // 8.1 Right-to-left: mirror the in-flow items inside the container
+    if rtl {
+        for &idx in &normal_indices {
+            let item = &mut items[idx];
+            item.result.pos.x = final_container_size.x - item.result.pos.x - item.result.size.x;
+        }
+        for track in grid_tracks.iter_mut().flat_map(|tracks| tracks.columns.iter_mut()) {
+            track.start = final_container_size.x - track.start - track.size;
+        }
+    }
+
+    // 9. Relative Offsets (Sticky thresholds are applied after layout, see `UPositionType::Sticky`)
     for &idx in &normal_indices {
         let item = &mut items[idx];
-        if item.spec.position_type == UPositionType::Relative {
-            let offset_x = item.spec.left.resolve_or_zero(final_container_size.x) - item.spec.right.resolve_or_zero(final_container_size.x);
-            let offset_y = item.spec.top.resolve_or_zero(final_container_size.y) - item.spec.bottom.resolve_or_zero(final_container_size.y);
-            item.result.pos.x += offset_x;
-            item.result.pos.y += offset_y;
-        }
+        item.result.pos += relative_offset(&item.spec, final_container_size);
     }
 
-    // 10. Handle Absolute Items
+    // 10. Handle Absolute Items (Fixed items are moved to the root by the downward pass)
     for &idx in &absolute_indices {
         let item = &mut items[idx];
-        let intrinsic = Vec2::new(
-            if item.spec.width_mode == SolverSizeMode::Content { item.spec.width_val } else { 0.0 },
-            if item.spec.height_mode == SolverSizeMode::Content { item.spec.height_val } else { 0.0 }
-        );
-        let (new_size, new_pos) = solve_absolute_box(
-            final_container_size,
-            &item.spec,
-            item.margin,
-            intrinsic
-        );
+        let (new_size, new_pos) = place_out_of_flow(final_container_size, &item.spec, item.margin);
         item.result.size = new_size;
         item.result.pos = new_pos;
     }
 
-    final_container_size
+    SolvedContainer { size: final_container_size, grid_tracks }
 }
 
 // 2. Spec Translation Helper
@@ -360,6 +528,11 @@ pub fn translate_spec(
             UVal::Percent(p) => (SolverSizeMode::Percent, p, 0.0),
             UVal::Flex(f) => (SolverSizeMode::Flex, 0.0, f),
             UVal::Content | UVal::Auto => (SolverSizeMode::Content, 0.0, 0.0),
+            // Removed by `UNode::resolve_units` before solving.
+            other => match other.resolve(f32::INFINITY) {
+                Some(v) => (SolverSizeMode::Fixed, v, 0.0),
+                None => (SolverSizeMode::Content, 0.0, 0.0),
+            },
         }
     };
 
@@ -408,6 +581,12 @@ pub fn translate_spec(
     SolverSpec {
         width_mode: w_mode, width_val: w_val, width_flex: w_flex,
         height_mode: h_mode, height_val: h_val, height_flex: h_flex,
+        min_width: node.min_width, max_width: node.max_width,
+        min_height: node.min_height, max_height: node.max_height,
+        content_width: 0.0, content_height: 0.0,
+        min_content_width: 0.0, min_content_height: 0.0,
+        first_baseline: None, last_baseline: None,
+        aspect_ratio: node.aspect_ratio.filter(|r| r.is_finite() && *r > 0.0),
         
         position_type: pos_type,
         left: l, right: r, top: t, bottom: b,
@@ -423,10 +602,35 @@ pub fn translate_spec(
         grid_column_span,
         grid_row_start,
         grid_row_span,
+        grid_area: None,
+        grid_column_lines: UGridLines::default(),
+        grid_row_lines: UGridLines::default(),
+        radial_angle: uself.and_then(|u| u.item_ext.radial.angle),
+        custom: uself.map(|u| u.item_ext.custom).unwrap_or_default(),
         order,
     }
 }
 
+/// Offset applied to a `Relative` item after placement (zero for other position types).
+pub(crate) fn relative_offset(spec: &SolverSpec, container_size: Vec2) -> Vec2 {
+    if spec.position_type != UPositionType::Relative {
+        return Vec2::ZERO;
+    }
+    Vec2::new(
+        spec.left.resolve_or_zero(container_size.x) - spec.right.resolve_or_zero(container_size.x),
+        spec.top.resolve_or_zero(container_size.y) - spec.bottom.resolve_or_zero(container_size.y),
+    )
+}
+
+/// Size and position of an out-of-flow (`Absolute`/`Fixed`) item inside `container_size`.
+pub(crate) fn place_out_of_flow(container_size: Vec2, spec: &SolverSpec, margin: USides) -> (Vec2, Vec2) {
+    let intrinsic = Vec2::new(
+        if spec.width_mode == SolverSizeMode::Content { spec.width_val } else { 0.0 },
+        if spec.height_mode == SolverSizeMode::Content { spec.height_val } else { 0.0 }
+    );
+    solve_absolute_box(container_size, spec, margin, intrinsic)
+}
+
 // 4. Isolated Box Solver (for Absolute Positioning)
 fn solve_absolute_box(
     container_size: Vec2,
@@ -447,6 +651,7 @@ fn solve_absolute_box(
             _ => intrinsic_size.x
         }
     };
+    let width = clamp_to_limits(width, spec.min_width, spec.max_width, container_size.x);
 
     // b. Calculate height (with Stretch support)
     let is_v_stretch = !matches!(spec.top, UVal::Auto) && !matches!(spec.bottom, UVal::Auto);
@@ -461,6 +666,23 @@ fn solve_absolute_box(
             _ => intrinsic_size.y
         }
     };
+    let height = clamp_to_limits(height, spec.min_height, spec.max_height, container_size.y);
+
+    // Derive the indefinite axis from the aspect ratio (width wins when both are indefinite).
+    let (width, height) = match spec.aspect_ratio {
+        Some(ratio) => {
+            let definite_w = is_h_stretch || matches!(spec.width_mode, SolverSizeMode::Fixed | SolverSizeMode::Percent);
+            let definite_h = is_v_stretch || matches!(spec.height_mode, SolverSizeMode::Fixed | SolverSizeMode::Percent);
+            if definite_h && !definite_w {
+                (clamp_to_limits(height * ratio, spec.min_width, spec.max_width, container_size.x), height)
+            } else if !definite_h {
+                (width, clamp_to_limits(width / ratio, spec.min_height, spec.max_height, container_size.y))
+            } else {
+                (width, height)
+            }
+        }
+        None => (width, height),
+    };
 
     // c. Calculate X Position
     let x = if let Some(l) = spec.left.resolve(container_size.x) {
@@ -487,12 +709,349 @@ fn solve_absolute_box(
 mod tests {
     use super::*;
 
+    fn row_config() -> SolverConfig {
+        SolverConfig {
+            layout: ULayout::default(),
+            gap: 0.0,
+            row_gap: None,
+            column_gap: None,
+            padding: USides::default(),
+            grid_columns: 1,
+            justify_items: None,
+            align_content: None,
+            flex_wrap: UFlexWrap::NoWrap,
+            flex_align_content: None,
+            grid_template_columns: Vec::new(),
+            grid_template_rows: Vec::new(),
+            grid_auto_flow: UGridAutoFlow::Row,
+            grid_auto_rows: UTrackSize::Auto,
+            grid_auto_columns: UTrackSize::Auto,
+            grid_template_areas: Vec::new(),
+            grid_column_line_names: Vec::new(),
+            grid_row_line_names: Vec::new(),
+            masonry: ULayoutMasonryContainer::default(),
+            radial: URadialLayout::default(),
+            custom_placer: None,
+            direction: UDirection::Ltr,
+            width_mode: SolverSizeMode::Fixed,
+            height_mode: SolverSizeMode::Fixed,
+        }
+    }
+
+    fn spec_with_content(node: UNode, content: Vec2) -> SolverSpec {
+        let mut spec = translate_spec(&node, None);
+        spec.content_width = content.x;
+        spec.content_height = content.y;
+        spec.min_content_width = content.x;
+        spec.min_content_height = content.y;
+        spec
+    }
+
     #[test]
     fn resolve_flex_basis_handles_percent() {
         let value = resolve_flex_basis(UVal::Percent(0.5), 10.0, 300.0);
         assert_eq!(value, Some(150.0));
     }
 
+    #[test]
+    fn flex_grow_redistributes_space_from_max_limited_item() {
+        let capped = UNode {
+            width: UVal::Flex(1.0),
+            max_width: UVal::Px(100.0),
+            ..default()
+        };
+        let free = UNode {
+            width: UVal::Flex(1.0),
+            ..default()
+        };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(capped, Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(free, Vec2::ZERO), result: &mut r2, margin: USides::default() },
+        ];
+
+        solve_flex_layout(&row_config(), BoxConstraints::tight(Vec2::new(300.0, 50.0)), &mut items);
+
+        assert!((r1.size.x - 100.0).abs() < 0.01);
+        assert!((r2.size.x - 200.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn flex_items_keep_automatic_content_minimum() {
+        let panel = UNode {
+            width: UVal::Flex(1.0),
+            ..default()
+        };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(panel.clone(), Vec2::new(80.0, 10.0)), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(panel, Vec2::new(80.0, 10.0)), result: &mut r2, margin: USides::default() },
+        ];
+
+        solve_flex_layout(&row_config(), BoxConstraints::tight(Vec2::new(100.0, 50.0)), &mut items);
+
+        assert!((r1.size.x - 80.0).abs() < 0.01);
+        assert!((r2.size.x - 80.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn wrapping_row_resolves_flexible_lengths_per_line() {
+        let chip = UNode { width: UVal::Px(100.0), ..default() };
+        let grow = USelf {
+            item_ext: ULayoutItemExt {
+                flex: ULayoutFlexItem { flex_grow: Some(1.0), ..default() },
+                ..default()
+            },
+            ..default()
+        };
+        let mut results = [SolverResult::default(); 3];
+        let mut items: Vec<SolverItem> = results
+            .iter_mut()
+            .map(|result| SolverItem {
+                spec: translate_spec(&chip, Some(&grow)),
+                result,
+                margin: USides::default(),
+            })
+            .collect();
+        let config = SolverConfig { flex_wrap: UFlexWrap::Wrap, ..row_config() };
+
+        solve_flex_layout(&config, BoxConstraints::tight(Vec2::new(250.0, 100.0)), &mut items);
+
+        // Two items fit on the first line and share its free space; the third fills the second line.
+        let widths: Vec<f32> = items.iter().map(|i| i.result.size.x).collect();
+        assert_eq!(widths, vec![125.0, 125.0, 250.0]);
+    }
+
+    #[test]
+    fn automatic_minimum_is_the_min_content_size() {
+        let panel = UNode { width: UVal::Flex(1.0), ..default() };
+        let rigid = UNode { width: UVal::Px(100.0), ..default() };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(rigid, Vec2::new(100.0, 10.0)), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(panel, Vec2::new(400.0, 10.0)), result: &mut r2, margin: USides::default() },
+        ];
+        // Wrapping content: 400px on one line, but fits in 120px.
+        items[1].spec.min_content_width = 120.0;
+
+        solve_flex_layout(&row_config(), BoxConstraints::tight(Vec2::new(300.0, 50.0)), &mut items);
+
+        assert!((r1.size.x - 100.0).abs() < 0.01);
+        assert!((r2.size.x - 200.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn explicit_min_overrides_automatic_minimum_when_shrinking() {
+        let shrinkable = UNode {
+            width: UVal::Content,
+            min_width: UVal::Px(30.0),
+            ..default()
+        };
+        let rigid = UNode {
+            width: UVal::Px(60.0),
+            ..default()
+        };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(shrinkable, Vec2::new(80.0, 10.0)), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(rigid, Vec2::new(60.0, 10.0)), result: &mut r2, margin: USides::default() },
+        ];
+        items[0].spec.width_val = 80.0;
+        items[1].spec.flex_shrink = Some(0.0);
+
+        solve_flex_layout(&row_config(), BoxConstraints::tight(Vec2::new(100.0, 50.0)), &mut items);
+
+        assert!((r1.size.x - 40.0).abs() < 0.01);
+        assert!((r2.size.x - 60.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn stretched_cross_size_respects_percent_max() {
+        let node = UNode {
+            width: UVal::Px(20.0),
+            height: UVal::Content,
+            max_height: UVal::Percent(0.5),
+            ..default()
+        };
+        let mut config = row_config();
+        config.layout.align_items = UAlignItems::Stretch;
+        let mut r1 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node, Vec2::new(20.0, 0.0)), result: &mut r1, margin: USides::default() },
+        ];
+
+        solve_flex_layout(&config, BoxConstraints::tight(Vec2::new(100.0, 80.0)), &mut items);
+
+        assert!((r1.size.y - 40.0).abs() < 0.01);
+    }
+
+    /// يضع كل عنصر عند الإحداثيات المخزنة في `custom.params.xy`.
+    struct OffsetPlacer;
+
+    impl LayoutPlacer for OffsetPlacer {
+        fn place(&self, items: &mut [SolverItem], _axis: &AxisHelper, _ctx: &PlacementContext) -> Vec2 {
+            let mut used = Vec2::ZERO;
+            for item in items.iter_mut() {
+                item.result.pos = item.spec.custom.params.xy();
+                used = used.max(item.result.pos + item.result.size);
+            }
+            used
+        }
+    }
+
+    fn custom_items_config(placer: Option<SharedPlacer>) -> SolverConfig {
+        let mut config = row_config();
+        config.layout.display = UDisplay::Custom("offset");
+        config.custom_placer = placer;
+        config
+    }
+
+    #[test]
+    fn custom_display_uses_registered_placer_and_item_params() {
+        let mut registry = LayoutPlacerRegistry::default();
+        registry.insert("offset", OffsetPlacer);
+        let node = UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(node, Vec2::ZERO), result: &mut r2, margin: USides::default() },
+        ];
+        items[0].spec.custom.params = Vec4::new(5.0, 0.0, 0.0, 0.0);
+        items[1].spec.custom.params = Vec4::new(30.0, 40.0, 0.0, 0.0);
+
+        let config = custom_items_config(registry.get("offset"));
+        solve_flex_layout(&config, BoxConstraints::tight(Vec2::new(200.0, 200.0)), &mut items);
+
+        assert_eq!(r1.size, Vec2::new(10.0, 10.0));
+        assert_eq!(r1.pos, Vec2::new(5.0, 0.0));
+        assert_eq!(r2.pos, Vec2::new(30.0, 40.0));
+    }
+
+    #[test]
+    fn sticky_items_stay_in_flow_without_offsets() {
+        let node = UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() };
+        let sticky = USelf { position_type: UPositionType::Sticky, top: UVal::Px(25.0), ..default() };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: translate_spec(&node, Some(&sticky)), result: &mut r2, margin: USides::default() },
+        ];
+
+        solve_flex_layout(&row_config(), BoxConstraints::tight(Vec2::new(200.0, 200.0)), &mut items);
+
+        assert_eq!(r2.pos, Vec2::new(10.0, 0.0));
+    }
+
+    #[test]
+    fn fixed_items_leave_the_flow() {
+        let node = UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() };
+        let fixed = USelf { position_type: UPositionType::Fixed, right: UVal::Px(5.0), ..default() };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut r3 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: translate_spec(&node, Some(&fixed)), result: &mut r2, margin: USides::default() },
+            SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r3, margin: USides::default() },
+        ];
+
+        solve_flex_layout(&row_config(), BoxConstraints::tight(Vec2::new(200.0, 200.0)), &mut items);
+
+        assert_eq!(r3.pos, Vec2::new(10.0, 0.0));
+        assert_eq!(r2.pos, Vec2::new(185.0, 0.0));
+    }
+
+    #[test]
+    fn rtl_row_starts_at_the_right_edge() {
+        let node = UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(node, Vec2::ZERO), result: &mut r2, margin: USides::left(5.0) },
+        ];
+        let config = SolverConfig { direction: UDirection::Rtl, ..row_config() };
+
+        solve_flex_layout(&config, BoxConstraints::tight(Vec2::new(200.0, 200.0)), &mut items);
+
+        assert_eq!(r1.pos, Vec2::new(190.0, 0.0));
+        // The start margin now sits on the right of the item.
+        assert_eq!(r2.pos, Vec2::new(175.0, 0.0));
+    }
+
+    #[test]
+    fn rtl_grid_runs_columns_from_the_right_after_the_start_padding() {
+        let node = UNode { width: UVal::Px(50.0), height: UVal::Px(10.0), ..default() };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(node, Vec2::ZERO), result: &mut r2, margin: USides::default() },
+        ];
+        let config = SolverConfig {
+            layout: ULayout { display: UDisplay::Grid, ..default() },
+            grid_template_columns: vec![UTrackSize::Px(50.0); 2],
+            padding: USides { left: 20.0, ..default() },
+            direction: UDirection::Rtl,
+            ..row_config()
+        };
+
+        solve_flex_layout(&config, BoxConstraints::tight(Vec2::new(200.0, 100.0)), &mut items);
+
+        // `padding.left` is the start side: the first column sits against the right padding.
+        assert_eq!(r1.pos, Vec2::new(130.0, 0.0));
+        assert_eq!(r2.pos, Vec2::new(80.0, 0.0));
+    }
+
+    #[test]
+    fn rtl_keeps_container_left_and_right_physical() {
+        let node = UNode { width: UVal::Px(20.0), height: UVal::Px(10.0), ..default() };
+        let solve = |justify: UAlignItemsExt, direction: UDirection| {
+            let mut r1 = SolverResult::default();
+            let mut items = vec![
+                SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            ];
+            let config = SolverConfig {
+                layout: ULayout { display: UDisplay::Grid, ..default() },
+                grid_template_columns: vec![UTrackSize::Px(100.0)],
+                justify_items: Some(justify),
+                direction,
+                ..row_config()
+            };
+            solve_flex_layout(&config, BoxConstraints::tight(Vec2::new(100.0, 100.0)), &mut items);
+            r1.pos.x
+        };
+
+        assert_eq!(solve(UAlignItemsExt::Left, UDirection::Rtl), 0.0);
+        assert_eq!(solve(UAlignItemsExt::Right, UDirection::Rtl), 80.0);
+        // `Start` follows the direction.
+        assert_eq!(solve(UAlignItemsExt::Start, UDirection::Rtl), 80.0);
+        assert_eq!(solve(UAlignItemsExt::Start, UDirection::Ltr), 0.0);
+    }
+
+    #[test]
+    fn unregistered_custom_display_falls_back_to_flex() {
+        let node = UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() };
+        let mut r1 = SolverResult::default();
+        let mut r2 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node.clone(), Vec2::ZERO), result: &mut r1, margin: USides::default() },
+            SolverItem { spec: spec_with_content(node, Vec2::ZERO), result: &mut r2, margin: USides::default() },
+        ];
+        items[1].spec.custom.params = Vec4::new(30.0, 40.0, 0.0, 0.0);
+
+        solve_flex_layout(&custom_items_config(None), BoxConstraints::tight(Vec2::new(200.0, 200.0)), &mut items);
+
+        assert!((r2.pos.x - r1.pos.x - 10.0).abs() < 0.01);
+        assert!((r2.pos.y - r1.pos.y).abs() < 0.01);
+    }
+
     #[test]
     fn translate_spec_copies_ext_fields() {
         let node = UNode {
@@ -520,6 +1079,8 @@ mod tests {
                     row_start: Some(1),
                     row_span: 2,
                 },
+                radial: ULayoutRadialItem { angle: Some(45.0) },
+                custom: ULayoutCustomItem { params: Vec4::new(1.0, 2.0, 3.0, 4.0) },
             },
             ..default()
         };
@@ -538,5 +1099,89 @@ mod tests {
         assert_eq!(spec.grid_column_start, Some(2));
         assert_eq!(spec.grid_column_span, 3);
         assert_eq!(spec.grid_row_span, 2);
+        assert_eq!(spec.radial_angle, Some(45.0));
+        assert_eq!(spec.custom.params, Vec4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn aspect_ratio_derives_cross_size_from_flexed_main() {
+        let node = UNode {
+            width: UVal::Flex(1.0),
+            height: UVal::Content,
+            aspect_ratio: Some(2.0),
+            ..default()
+        };
+        let mut r1 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node, Vec2::ZERO), result: &mut r1, margin: USides::default() },
+        ];
+        let mut config = row_config();
+        config.layout.align_items = UAlignItems::Stretch;
+
+        solve_flex_layout(&config, BoxConstraints::tight(Vec2::new(300.0, 500.0)), &mut items);
+
+        assert!((r1.size.x - 300.0).abs() < 0.01);
+        assert!((r1.size.y - 150.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn aspect_ratio_derives_main_size_from_fixed_cross() {
+        let node = UNode {
+            width: UVal::Content,
+            height: UVal::Px(50.0),
+            aspect_ratio: Some(2.0),
+            ..default()
+        };
+        let mut r1 = SolverResult::default();
+        let mut items = vec![
+            SolverItem { spec: spec_with_content(node, Vec2::ZERO), result: &mut r1, margin: USides::default() },
+        ];
+
+        solve_flex_layout(&row_config(), BoxConstraints::tight(Vec2::new(300.0, 100.0)), &mut items);
+
+        assert!((r1.size.x - 100.0).abs() < 0.01);
+        assert!((r1.size.y - 50.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn absolute_box_derives_height_from_aspect_ratio() {
+        let node = UNode {
+            width: UVal::Px(200.0),
+            height: UVal::Content,
+            aspect_ratio: Some(4.0),
+            ..default()
+        };
+        let spec = translate_spec(&node, Some(&USelf { position_type: UPositionType::Absolute, ..default() }));
+
+        let (size, _) = solve_absolute_box(Vec2::new(400.0, 400.0), &spec, USides::default(), Vec2::ZERO);
+
+        assert_eq!(size, Vec2::new(200.0, 50.0));
+    }
+
+    #[test]
+    fn translate_spec_copies_size_limits() {
+        let node = UNode {
+            min_width: UVal::Px(120.0),
+            max_width: UVal::Percent(0.4),
+            max_height: UVal::Px(300.0),
+            ..default()
+        };
+
+        let spec = translate_spec(&node, None);
+
+        assert_eq!(spec.min_width, UVal::Px(120.0));
+        assert_eq!(spec.max_width, UVal::Percent(0.4));
+        assert_eq!(spec.min_height, UVal::Auto);
+        assert_eq!(spec.max_height, UVal::Px(300.0));
+    }
+
+    #[test]
+    fn translate_spec_drops_invalid_aspect_ratio() {
+        let node = UNode {
+            aspect_ratio: Some(0.0),
+            ..default()
+        };
+
+        assert_eq!(translate_spec(&node, None).aspect_ratio, None);
     }
 }
@@ -0,0 +1,482 @@
+use bevy::prelude::*;
+use crate::internal_prelude::*;
+
+/// Handle of a node inside a `LayoutTree`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LayoutNodeId(usize);
+
+struct TreeNode {
+    node: UNode,
+    layout: ULayout,
+    uself: Option<USelf>,
+    grid_placement: Option<UGridPlacement>,
+    direction: Option<UDirection>,
+    measure: Option<UMeasure>,
+    parent: Option<LayoutNodeId>,
+    children: Vec<LayoutNodeId>,
+
+    intrinsic: IntrinsicSize,
+    baseline: UBaseline,
+    size: Vec2,
+    /// Top-left corner relative to the parent's top-left corner.
+    position: Vec2,
+    rotation: Option<f32>,
+    overflow: Rect,
+}
+
+/// Layout without a Bevy `World`: the same measure and solve steps as
+/// `upward_measure_pass_cached` and `downward_solve_pass_safe`, on plain data.
+///
+/// Results use layout space (y-down, in pixels) relative to the top-left corner
+/// of the topmost node. `Vw`/`Vh` resolve against the size given to `compute`.
+///
+/// ```
+/// use univis_ui_engine::prelude::*;
+/// use bevy::math::Vec2;
+///
+/// let mut tree = LayoutTree::new();
+/// let root = tree.add_node(UNode { padding: USides::all(10.0), ..Default::default() });
+/// let item = tree.add_node(UNode { width: UVal::Px(50.0), height: UVal::Px(20.0), ..Default::default() });
+/// tree.add_child(root, item);
+///
+/// tree.compute(root, Vec2::new(200.0, 100.0));
+/// assert_eq!(tree.rect(item).min, Vec2::new(10.0, 10.0));
+/// ```
+pub struct LayoutTree {
+    nodes: Vec<TreeNode>,
+    placers: LayoutPlacerRegistry,
+    font_size: f32,
+}
+
+impl Default for LayoutTree {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            placers: LayoutPlacerRegistry::default(),
+            font_size: DEFAULT_BASE_FONT_SIZE,
+        }
+    }
+}
+
+impl LayoutTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a detached node with a default `ULayout`.
+    pub fn add_node(&mut self, node: UNode) -> LayoutNodeId {
+        let id = LayoutNodeId(self.nodes.len());
+        self.nodes.push(TreeNode {
+            node,
+            layout: ULayout::default(),
+            uself: None,
+            grid_placement: None,
+            direction: None,
+            measure: None,
+            parent: None,
+            children: Vec::new(),
+            intrinsic: IntrinsicSize::default(),
+            baseline: UBaseline::default(),
+            size: Vec2::ZERO,
+            position: Vec2::ZERO,
+            rotation: None,
+            overflow: Rect::default(),
+        });
+        id
+    }
+
+    /// Appends `child` to `parent`, detaching it from its previous parent.
+    pub fn add_child(&mut self, parent: LayoutNodeId, child: LayoutNodeId) {
+        if let Some(old) = self.nodes[child.0].parent {
+            self.nodes[old.0].children.retain(|&c| c != child);
+        }
+        self.nodes[child.0].parent = Some(parent);
+        self.nodes[parent.0].children.push(child);
+    }
+
+    pub fn children(&self, id: LayoutNodeId) -> &[LayoutNodeId] {
+        &self.nodes[id.0].children
+    }
+
+    pub fn parent(&self, id: LayoutNodeId) -> Option<LayoutNodeId> {
+        self.nodes[id.0].parent
+    }
+
+    pub fn set_node(&mut self, id: LayoutNodeId, node: UNode) {
+        self.nodes[id.0].node = node;
+    }
+
+    pub fn set_layout(&mut self, id: LayoutNodeId, layout: ULayout) {
+        self.nodes[id.0].layout = layout;
+    }
+
+    pub fn set_self(&mut self, id: LayoutNodeId, uself: USelf) {
+        self.nodes[id.0].uself = Some(uself);
+    }
+
+    /// Named grid placement, like the `UGridPlacement` component.
+    pub fn set_grid_placement(&mut self, id: LayoutNodeId, placement: UGridPlacement) {
+        self.nodes[id.0].grid_placement = Some(placement);
+    }
+
+    /// Sets the node's own `UDirection`; without one it inherits its parent's.
+    pub fn set_direction(&mut self, id: LayoutNodeId, direction: UDirection) {
+        self.nodes[id.0].direction = Some(direction);
+    }
+
+    /// Height-for-width measure of a leaf, like the `UMeasure` component.
+    pub fn set_measure(&mut self, id: LayoutNodeId, measure: impl UMeasureFunc) {
+        self.nodes[id.0].measure = Some(UMeasure::new(measure));
+    }
+
+    /// Font size used for `Em` and `Rem`.
+    pub fn set_font_size(&mut self, font_size: f32) {
+        self.font_size = font_size;
+    }
+
+    /// Placers for `UDisplay::Custom`.
+    pub fn placers_mut(&mut self) -> &mut LayoutPlacerRegistry {
+        &mut self.placers
+    }
+
+    /// Lays out the subtree of `root` inside `available`, like a `UWorldRoot` of that size.
+    ///
+    /// As with ECS roots, a root sized `Content`/`Auto` shrinks to its children;
+    /// use `UVal::Percent(1.0)` to fill `available`.
+    pub fn compute(&mut self, root: LayoutNodeId, available: Vec2) {
+        let order = self.breadth_first(root);
+        let ctx = UUnitContext {
+            viewport: available,
+            font_size: self.font_size,
+            root_font_size: self.font_size,
+        };
+
+        self.measure_up(&order, &ctx);
+        // عرض عقد القياس تغير: نعيد القياس مرة واحدة بدل انتظار الإطار التالي
+        if self.solve_down(root, available, &order, &ctx) {
+            self.measure_up(&order, &ctx);
+            self.solve_down(root, available, &order, &ctx);
+        }
+    }
+
+    /// Border box of `id` relative to the topmost node.
+    pub fn rect(&self, id: LayoutNodeId) -> Rect {
+        let mut min = Vec2::ZERO;
+        let mut current = Some(id);
+        while let Some(node) = current {
+            min += self.nodes[node.0].position;
+            current = self.nodes[node.0].parent;
+        }
+        Rect::from_corners(min, min + self.nodes[id.0].size)
+    }
+
+    /// Border box of `id` relative to its parent's top-left corner.
+    pub fn local_rect(&self, id: LayoutNodeId) -> Rect {
+        let node = &self.nodes[id.0];
+        Rect::from_corners(node.position, node.position + node.size)
+    }
+
+    pub fn size(&self, id: LayoutNodeId) -> Vec2 {
+        self.nodes[id.0].size
+    }
+
+    /// Rotation set by the parent's placer (radial `rotate_children`), in radians.
+    pub fn rotation(&self, id: LayoutNodeId) -> Option<f32> {
+        self.nodes[id.0].rotation
+    }
+
+    pub fn baseline(&self, id: LayoutNodeId) -> UBaseline {
+        self.nodes[id.0].baseline
+    }
+
+    /// Same as the `ScrollableOverflow` component, in the node's own space.
+    pub fn scrollable_overflow(&self, id: LayoutNodeId) -> Rect {
+        self.nodes[id.0].overflow
+    }
+
+    /// Nodes of the subtree, parents before children.
+    fn breadth_first(&self, root: LayoutNodeId) -> Vec<LayoutNodeId> {
+        let mut order = vec![root];
+        let mut index = 0;
+        while index < order.len() {
+            order.extend_from_slice(&self.nodes[order[index].0].children);
+            index += 1;
+        }
+        order
+    }
+
+    fn measure_up(&mut self, order: &[LayoutNodeId], ctx: &UUnitContext) {
+        for &id in order.iter().rev() {
+            let node = &self.nodes[id.0];
+            let spec = node.node.resolve_units(Vec2::splat(f32::INFINITY), ctx);
+            let children: Vec<MeasureChild> = node
+                .children
+                .iter()
+                .map(|child| &self.nodes[child.0])
+                .filter(|child| !child.uself.as_ref().is_some_and(|u| u.position_type.is_out_of_flow()))
+                .map(|child| MeasureChild {
+                    size: child.intrinsic,
+                    margin: child.node.margin,
+                    baseline: child.baseline,
+                })
+                .collect();
+            let (intrinsic, baseline) = measure_intrinsic(&spec, Some(&node.layout), &children, node.measure.as_ref(), node.size);
+            let node = &mut self.nodes[id.0];
+            node.intrinsic = intrinsic;
+            node.baseline = baseline.unwrap_or_default();
+        }
+    }
+
+    /// Returns `true` when a measured node's width or a wrapping container's lines changed.
+    fn solve_down(&mut self, root: LayoutNodeId, available: Vec2, order: &[LayoutNodeId], ctx: &UUnitContext) -> bool {
+        let mut resized = false;
+        let mut directions = vec![UDirection::Ltr; self.nodes.len()];
+        self.nodes[root.0].size = available;
+
+        for &id in order {
+            let node = &self.nodes[id.0];
+            let inherited = node.parent.map_or(UDirection::Ltr, |parent| directions[parent.0]);
+            directions[id.0] = node.direction.unwrap_or(inherited);
+
+            let limit_base = node
+                .parent
+                .filter(|_| id != root)
+                .map(|parent| {
+                    let parent = &self.nodes[parent.0];
+                    let padding = parent.node.padding;
+                    Vec2::new(
+                        (parent.size.x - padding.width_sum()).max(0.0),
+                        (parent.size.y - padding.height_sum()).max(0.0),
+                    )
+                })
+                .unwrap_or(Vec2::splat(f32::INFINITY));
+            let spec = node.node.resolve_units(limit_base, ctx);
+            let container_size = node.size;
+
+            let child_base = Vec2::new(
+                (container_size.x - spec.padding.width_sum()).max(0.0),
+                (container_size.y - spec.padding.height_sum()).max(0.0),
+            );
+            let bases = ChildUnitBases {
+                content: child_base,
+                container: container_size,
+                main: if AxisHelper::new(node.layout.solver_direction()).is_row() { child_base.x } else { child_base.y },
+                root: Some(available),
+                ctx: *ctx,
+            };
+
+            let children = node.children.clone();
+            if children.is_empty() {
+                self.nodes[id.0].overflow = Rect::from_corners(Vec2::ZERO, container_size);
+                continue;
+            }
+
+            let mut items: Vec<SolverItemOwned> = Vec::with_capacity(children.len());
+            let mut measures: Vec<Option<ItemMeasure>> = Vec::with_capacity(children.len());
+            for child in &children {
+                let child = &self.nodes[child.0];
+                let (spec, margin) = child_spec(&child.node, child.uself.as_ref(), child.grid_placement.as_ref(), child.intrinsic, Some(child.baseline), &bases);
+                items.push(SolverItemOwned {
+                    spec,
+                    result: Box::new(SolverResult::default()),
+                    margin,
+                });
+                measures.push(child.measure.clone().map(|measure| ItemMeasure {
+                    measure,
+                    padding: child.node.padding,
+                    previous_width: child.size.x,
+                }));
+            }
+
+            let constraints = build_constraints(container_size, &spec, &node.layout, limit_base);
+            let mut layout = node.layout.clone();
+            let radial = &mut layout.container_ext.radial;
+            radial.radius = radial.radius.normalize(child_base.min_element() * 0.5, ctx);
+
+            let mut config = translate_config(&layout, &spec);
+            if let UDisplay::Custom(id) = layout.display {
+                config.custom_placer = self.placers.get(id);
+            }
+            config.direction = directions[id.0];
+
+            let (solved, resized_items) = solve_container(&config, constraints, &mut items, &measures);
+            let final_size = solved.size;
+            resized |= !resized_items.is_empty();
+
+            // Fixed: بالنسبة للجذر، والموضع المطلق للحاوية معروف هنا
+            let origin = self.rect(id).min - self.rect(root).min;
+            for item in items.iter_mut().filter(|i| i.spec.position_type == UPositionType::Fixed) {
+                let (size, pos) = place_out_of_flow(available, &item.spec, item.margin);
+                item.result.size = size;
+                item.result.pos = pos - origin;
+            }
+
+            let node = &mut self.nodes[id.0];
+            resized |= wrapped_size_moved(&node.layout, &node.node, node.intrinsic, final_size);
+            node.size = final_size;
+            node.overflow = scrollable_overflow(final_size, spec.padding, &items);
+
+            for (child, item) in children.iter().zip(&items) {
+                let child = &mut self.nodes[child.0];
+                child.size = item.result.size;
+                child.position = item.result.pos;
+                child.rotation = item.result.rotation;
+            }
+        }
+
+        resized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn px(width: f32, height: f32) -> UNode {
+        UNode { width: UVal::Px(width), height: UVal::Px(height), ..default() }
+    }
+
+    #[test]
+    fn row_places_children_after_padding_and_gap() {
+        let mut tree = LayoutTree::new();
+        let root = tree.add_node(UNode {
+            width: UVal::Percent(1.0),
+            height: UVal::Percent(1.0),
+            padding: USides::all(10.0),
+            ..default()
+        });
+        tree.set_layout(root, ULayout { gap: 5.0, ..default() });
+        let a = tree.add_node(px(40.0, 20.0));
+        let b = tree.add_node(px(30.0, 20.0));
+        tree.add_child(root, a);
+        tree.add_child(root, b);
+
+        tree.compute(root, Vec2::new(300.0, 200.0));
+
+        assert_eq!(tree.size(root), Vec2::new(300.0, 200.0));
+        assert_eq!(tree.rect(a), Rect::new(10.0, 10.0, 50.0, 30.0));
+        assert_eq!(tree.rect(b), Rect::new(55.0, 10.0, 85.0, 30.0));
+    }
+
+    #[test]
+    fn nested_rects_are_relative_to_the_root() {
+        let mut tree = LayoutTree::new();
+        let root = tree.add_node(UNode { padding: USides::all(10.0), ..default() });
+        let panel = tree.add_node(UNode { padding: USides::all(4.0), ..default() });
+        let leaf = tree.add_node(px(20.0, 20.0));
+        tree.add_child(root, panel);
+        tree.add_child(panel, leaf);
+
+        tree.compute(root, Vec2::new(300.0, 200.0));
+
+        assert_eq!(tree.size(panel), Vec2::new(28.0, 28.0));
+        assert_eq!(tree.local_rect(leaf).min, Vec2::new(4.0, 4.0));
+        assert_eq!(tree.rect(leaf).min, Vec2::new(14.0, 14.0));
+    }
+
+    #[test]
+    fn measure_wraps_to_the_solved_width() {
+        let mut tree = LayoutTree::new();
+        let root = tree.add_node(UNode { width: UVal::Percent(1.0), height: UVal::Percent(1.0), ..default() });
+        tree.set_layout(root, ULayout { flex_direction: UFlexDirection::Column, ..default() });
+        let text = tree.add_node(UNode { width: UVal::Percent(1.0), ..default() });
+        // 10px per word, 4 words, 10px per line
+        tree.set_measure(text, |width: Option<f32>| {
+            let per_line = width.map_or(4.0, |w| (w / 10.0).floor().max(1.0));
+            Vec2::new(40.0_f32.min(per_line * 10.0), (4.0 / per_line).ceil() * 10.0)
+        });
+        tree.add_child(root, text);
+
+        tree.compute(root, Vec2::new(20.0, 100.0));
+
+        assert_eq!(tree.size(text), Vec2::new(20.0, 20.0));
+    }
+
+    #[test]
+    fn wrapping_row_only_needs_its_widest_item() {
+        let mut tree = LayoutTree::new();
+        let root = tree.add_node(UNode { width: UVal::Percent(1.0), height: UVal::Percent(1.0), ..default() });
+        tree.set_layout(root, ULayout { gap: 10.0, ..default() });
+        let left = tree.add_node(px(100.0, 40.0));
+        let center = tree.add_node(UNode { width: UVal::Flex(1.0), ..default() });
+        let chips = tree.add_node(UNode { width: UVal::Percent(1.0), ..default() });
+        let wrap = ULayoutContainerExt {
+            flex: ULayoutFlexContainer { wrap: UFlexWrap::Wrap, ..default() },
+            ..default()
+        };
+        tree.set_layout(chips, ULayout { container_ext: wrap, ..default() });
+        tree.add_child(root, left);
+        tree.add_child(root, center);
+        tree.add_child(center, chips);
+        for _ in 0..3 {
+            let chip = tree.add_node(px(120.0, 20.0));
+            tree.add_child(chips, chip);
+        }
+
+        tree.compute(root, Vec2::new(320.0, 200.0));
+
+        assert_eq!(tree.size(left).x, 100.0);
+        assert_eq!(tree.size(center).x, 210.0);
+        assert_eq!(tree.size(chips), Vec2::new(210.0, 60.0));
+    }
+
+    #[test]
+    fn wrapped_row_pushes_the_next_sibling_down() {
+        let mut tree = LayoutTree::new();
+        let root = tree.add_node(UNode { width: UVal::Percent(1.0), height: UVal::Percent(1.0), ..default() });
+        tree.set_layout(root, ULayout { flex_direction: UFlexDirection::Column, ..default() });
+        let chips = tree.add_node(UNode { width: UVal::Percent(1.0), ..default() });
+        let wrap = ULayoutContainerExt {
+            flex: ULayoutFlexContainer { wrap: UFlexWrap::Wrap, ..default() },
+            ..default()
+        };
+        tree.set_layout(chips, ULayout { container_ext: wrap, ..default() });
+        for _ in 0..3 {
+            let chip = tree.add_node(px(80.0, 20.0));
+            tree.add_child(chips, chip);
+        }
+        let footer = tree.add_node(px(100.0, 10.0));
+        tree.add_child(root, chips);
+        tree.add_child(root, footer);
+
+        tree.compute(root, Vec2::new(200.0, 200.0));
+
+        assert_eq!(tree.size(chips), Vec2::new(200.0, 40.0));
+        assert_eq!(tree.rect(footer).min.y, 40.0);
+    }
+
+    #[test]
+    fn scroll_containers_shrink_below_their_content() {
+        let width = |overflow: UOverflow| {
+            let mut tree = LayoutTree::new();
+            let root = tree.add_node(UNode { width: UVal::Percent(1.0), height: UVal::Percent(1.0), ..default() });
+            let list = tree.add_node(UNode { width: UVal::Content, ..default() });
+            tree.set_layout(list, ULayout { overflow_x: overflow, ..default() });
+            let item = tree.add_node(px(500.0, 20.0));
+            tree.add_child(list, item);
+            tree.add_child(root, list);
+            tree.compute(root, Vec2::new(300.0, 100.0));
+            tree.size(list).x
+        };
+
+        assert_eq!(width(UOverflow::Scroll), 300.0);
+        assert_eq!(width(UOverflow::Hidden), 300.0);
+        // `Clip` keeps the content-based minimum.
+        assert_eq!(width(UOverflow::Clip), 500.0);
+    }
+
+    #[test]
+    fn fixed_child_uses_the_root_box() {
+        let mut tree = LayoutTree::new();
+        let root = tree.add_node(UNode { padding: USides::all(10.0), ..default() });
+        let panel = tree.add_node(px(50.0, 50.0));
+        let badge = tree.add_node(px(10.0, 10.0));
+        tree.set_self(badge, USelf { position_type: UPositionType::Fixed, right: UVal::Px(0.0), ..default() });
+        tree.add_child(root, panel);
+        tree.add_child(panel, badge);
+
+        tree.compute(root, Vec2::new(200.0, 100.0));
+
+        assert_eq!(tree.rect(badge).min, Vec2::new(190.0, 0.0));
+    }
+}
@@ -0,0 +1,76 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::internal_prelude::*;
+
+/// Everything the layout passes need to resolve viewport- and font-relative units.
+#[derive(SystemParam)]
+pub struct UnitContextParam<'w, 's> {
+    pub parents: Query<'w, 's, &'static ChildOf>,
+    ui_nodes: Query<'w, 's, (), With<UNode>>,
+    roots: Query<'w, 's, &'static UWorldRoot>,
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    target_cameras: Query<'w, 's, &'static UTargetCamera>,
+    cameras: Query<'w, 's, &'static Camera>,
+    theme: Option<Res<'w, Theme>>,
+    scale: Option<Res<'w, UiScaleSettings>>,
+}
+
+impl UnitContextParam<'_, '_> {
+    /// Size of a layout root: the `UWorldRoot` size, else the viewport of its
+    /// `UTargetCamera`, else the primary window size (divided by the UI scale).
+    pub fn root_size(&self, root: Entity) -> Vec2 {
+        if let Ok(world_root) = self.roots.get(root) {
+            return world_root.size;
+        }
+        let scale = self.scale.as_ref().map_or(1.0, |s| s.root_scale(self.scale_factor(root)));
+        if let Some(viewport) = self.target_camera(root).and_then(Camera::logical_viewport_size) {
+            viewport / scale
+        } else if let Ok(window) = self.windows.single() {
+            Vec2::new(window.width(), window.height()) / scale
+        } else {
+            Vec2::new(800.0, 600.0)
+        }
+    }
+
+    /// The camera a screen root is bound to with `UTargetCamera`.
+    pub fn target_camera(&self, root: Entity) -> Option<&Camera> {
+        let target = self.target_cameras.get(root).ok()?;
+        self.cameras.get(target.0).ok()
+    }
+
+    /// Scale factor of the window `root` is shown in.
+    pub fn scale_factor(&self, root: Entity) -> f32 {
+        self.target_camera(root)
+            .and_then(Camera::target_scaling_factor)
+            .or_else(|| self.windows.single().ok().map(Window::scale_factor))
+            .unwrap_or(1.0)
+    }
+
+    /// Topmost `UNode` ancestor of `entity` (or `entity` itself).
+    pub fn owning_root(&self, entity: Entity) -> Entity {
+        let mut root = entity;
+        while let Ok(child_of) = self.parents.get(root) {
+            let parent = child_of.parent();
+            if self.ui_nodes.get(parent).is_err() {
+                break;
+            }
+            root = parent;
+        }
+        root
+    }
+
+    /// Unit context for the children of `entity`.
+    pub fn context_for(&self, entity: Entity) -> UUnitContext {
+        let font_size = self
+            .theme
+            .as_ref()
+            .map(|theme| theme.text.base_font_size)
+            .unwrap_or(DEFAULT_BASE_FONT_SIZE);
+        UUnitContext {
+            viewport: self.root_size(self.owning_root(entity)),
+            font_size,
+            root_font_size: font_size,
+        }
+    }
+}
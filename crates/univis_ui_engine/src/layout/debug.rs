@@ -0,0 +1,436 @@
+use bevy::picking::backend::ray::RayMap;
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use crate::internal_prelude::*;
+
+const BOX_COLOR: Color = Color::srgb(0.35, 0.75, 1.0);
+const PADDING_COLOR: Color = Color::srgb(0.45, 0.9, 0.45);
+const MARGIN_COLOR: Color = Color::srgb(1.0, 0.65, 0.3);
+const GAP_COLOR: Color = Color::srgb(0.85, 0.45, 1.0);
+const GRID_COLOR: Color = Color::srgb(1.0, 0.9, 0.35);
+const CLIP_COLOR: Color = Color::srgb(1.0, 0.35, 0.35);
+const HOVER_COLOR: Color = Color::WHITE;
+
+// =========================================================
+// Resources
+// =========================================================
+
+/// What the layout debug overlay draws.
+#[derive(Resource)]
+pub struct LayoutDebugSettings {
+    pub enabled: bool,
+    /// `ComputedSize` rect of every node.
+    pub show_boxes: bool,
+    pub show_padding: bool,
+    pub show_margin: bool,
+    /// Space between consecutive flex items.
+    pub show_gaps: bool,
+    /// Track lines of grid containers as the grid placer sized them
+    /// (see `ComputedGridTracks`).
+    pub show_grid_tracks: bool,
+    /// Nodes with an enabled `UClip`.
+    pub show_clips: bool,
+    /// Specs and resolved sizes of the node under the cursor.
+    pub show_hover_info: bool,
+}
+
+impl Default for LayoutDebugSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            show_boxes: true,
+            show_padding: true,
+            show_margin: true,
+            show_gaps: true,
+            show_grid_tracks: true,
+            show_clips: true,
+            show_hover_info: true,
+        }
+    }
+}
+
+// =========================================================
+// Systems
+// =========================================================
+
+/// F8: overlay ON/OFF, F7: hover info ON/OFF.
+pub fn layout_debug_controls(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<LayoutDebugSettings>,
+) {
+    if keyboard.just_pressed(KeyCode::F8) {
+        settings.enabled = !settings.enabled;
+    }
+
+    if keyboard.just_pressed(KeyCode::F7) {
+        settings.show_hover_info = !settings.show_hover_info;
+    }
+}
+
+/// Adds `ComputedGridTracks` to grid containers so the solver reports their
+/// tracks, and removes it once the overlay no longer draws them.
+pub fn track_grid_containers(
+    mut commands: Commands,
+    settings: Res<LayoutDebugSettings>,
+    mut cache: ResMut<LayoutCache>,
+    containers: Query<(Entity, &ULayout, Has<ComputedGridTracks>)>,
+    tracked: Query<Entity, With<ComputedGridTracks>>,
+) {
+    if !settings.enabled || !settings.show_grid_tracks {
+        for entity in tracked.iter() {
+            commands.entity(entity).remove::<ComputedGridTracks>();
+        }
+        return;
+    }
+
+    for (entity, layout, has_tracks) in containers.iter() {
+        let is_grid = layout.display == UDisplay::Grid;
+        if is_grid && !has_tracks {
+            commands.entity(entity).try_insert(ComputedGridTracks::default());
+            // الحاوية قد تكون نظيفة: نعيد حلها لتمتلئ المسارات
+            cache.mark_dirty(entity);
+        } else if !is_grid && has_tracks {
+            commands.entity(entity).remove::<ComputedGridTracks>();
+        }
+    }
+}
+
+/// Data `draw_layout_debug` reads for each node.
+type DebugNodeData = (
+    &'static UNode,
+    &'static ComputedSize,
+    &'static GlobalTransform,
+    Option<&'static ULayout>,
+    Option<&'static Children>,
+    Option<&'static UClip>,
+    Option<&'static ComputedGridTracks>,
+);
+
+/// Draws boxes, padding and margin bands, gaps, grid tracks and clip regions.
+pub fn draw_layout_debug(
+    mut gizmos: Gizmos,
+    settings: Res<LayoutDebugSettings>,
+    nodes: Query<DebugNodeData>,
+    items: Query<(&UNode, &ComputedSize, &Transform, Option<&USelf>)>,
+) {
+    if !settings.enabled {
+        return;
+    }
+
+    for (node, size, global, layout, children, clip, tracks) in nodes.iter() {
+        let size = size.size();
+        let half = size * 0.5;
+
+        if settings.show_boxes {
+            draw_local_rect(&mut gizmos, global, Rect::from_center_half_size(Vec2::ZERO, half), BOX_COLOR);
+        }
+        if settings.show_padding && node.padding != USides::default() {
+            draw_local_rect(&mut gizmos, global, inset(half, node.padding), PADDING_COLOR);
+        }
+        if settings.show_margin && node.margin != USides::default() {
+            draw_local_rect(&mut gizmos, global, outset(half, node.margin), MARGIN_COLOR);
+        }
+        if settings.show_clips && clip.is_some_and(|clip| clip.enabled) {
+            draw_local_rect(&mut gizmos, global, inset(half, USides::all(1.0)), CLIP_COLOR);
+        }
+        if settings.show_grid_tracks && let Some(tracks) = tracks {
+            for rect in track_rects(tracks, half) {
+                draw_local_rect(&mut gizmos, global, rect, GRID_COLOR);
+            }
+        }
+
+        let Some(children) = children else {
+            continue;
+        };
+        let layout = layout.cloned().unwrap_or_default();
+
+        if settings.show_gaps && layout.display == UDisplay::Flex {
+            let is_row = AxisHelper::new(layout.flex_direction).is_row();
+            let mut boxes: Vec<Rect> = children
+                .iter()
+                .filter_map(|child| items.get(child).ok())
+                .filter(|(_, _, _, uself)| !uself.is_some_and(|u| u.position_type.is_out_of_flow()))
+                .map(|(child, size, transform, _)| {
+                    let rect = outset(size.size() * 0.5, child.margin);
+                    let center = transform.translation.truncate();
+                    Rect { min: rect.min + center, max: rect.max + center }
+                })
+                .collect();
+            let main = |rect: &Rect| if is_row { rect.min.x } else { -rect.max.y };
+            boxes.sort_by(|a, b| main(a).total_cmp(&main(b)));
+            for pair in boxes.windows(2) {
+                if let Some(gap) = gap_between(pair[0], pair[1], is_row) {
+                    draw_local_rect(&mut gizmos, global, gap, GAP_COLOR);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Component)]
+struct LayoutDebugHoverText;
+
+fn setup_layout_debug_text(mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn((
+        Text2d::new(""),
+        TextLayout::new(Justify::Left, LineBreak::NoWrap),
+        TextFont {
+            font: theme.text.font.inter_regular.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(HOVER_COLOR),
+        Anchor::TOP_LEFT,
+        Transform::from_xyz(0.0, 0.0, 60.0),
+        Visibility::Hidden,
+        LayoutDebugHoverText,
+    ));
+}
+
+/// Data `layout_debug_hover` reads for each node.
+type HoverNodeData = (
+    Entity,
+    &'static UNode,
+    &'static ComputedSize,
+    &'static IntrinsicSize,
+    &'static GlobalTransform,
+    &'static LayoutDepth,
+    Option<&'static ULayout>,
+    Option<&'static Name>,
+);
+
+/// Outlines the deepest node under the cursor and prints its specs next to it.
+fn layout_debug_hover(
+    mut gizmos: Gizmos,
+    settings: Res<LayoutDebugSettings>,
+    ray_map: Option<Res<RayMap>>,
+    cameras: Query<&Camera, With<Camera2d>>,
+    nodes: Query<HoverNodeData>,
+    mut text: Query<(&mut Text2d, &mut Transform, &mut Visibility), With<LayoutDebugHoverText>>,
+) {
+    let Ok((mut label, mut label_transform, mut visibility)) = text.single_mut() else {
+        return;
+    };
+    *visibility = Visibility::Hidden;
+    if !settings.enabled || !settings.show_hover_info {
+        return;
+    }
+
+    let Some(cursor) = ray_map.and_then(|rays| cursor_world(&rays, &cameras)) else {
+        return;
+    };
+
+    let hovered = nodes
+        .iter()
+        .filter(|(_, _, size, _, global, ..)| {
+            let local = global.affine().inverse().transform_point3(cursor.extend(0.0)).truncate();
+            Rect::from_center_size(Vec2::ZERO, size.size()).contains(local)
+        })
+        .max_by(|(.., a_global, a_depth, _, _), (.., b_global, b_depth, _, _)| {
+            a_depth.0
+                .cmp(&b_depth.0)
+                .then(a_global.translation().z.total_cmp(&b_global.translation().z))
+        });
+    let Some((entity, node, size, intrinsic, global, depth, layout, name)) = hovered else {
+        return;
+    };
+
+    draw_local_rect(&mut gizmos, global, Rect::from_center_size(Vec2::ZERO, size.size()), HOVER_COLOR);
+
+    let title = name.map_or_else(|| format!("{entity}"), |name| format!("{name} ({entity})"));
+    **label = format!(
+        "{title}  depth {}\n\
+width {:?}  height {:?}\n\
+min {:?} x {:?}  max {:?} x {:?}\n\
+computed {:.1} x {:.1}  intrinsic {:.1} x {:.1}\n\
+padding {:?}\n\
+margin {:?}\n\
+display {:?}",
+        depth.0,
+        node.width,
+        node.height,
+        node.min_width,
+        node.min_height,
+        node.max_width,
+        node.max_height,
+        size.width,
+        size.height,
+        intrinsic.width,
+        intrinsic.height,
+        node.padding,
+        node.margin,
+        layout.cloned().unwrap_or_default().display,
+    );
+    label_transform.translation = (cursor + Vec2::new(16.0, -16.0)).extend(60.0);
+    *visibility = Visibility::Visible;
+}
+
+// =========================================================
+// Helper Functions
+// =========================================================
+
+fn draw_local_rect(gizmos: &mut Gizmos, global: &GlobalTransform, rect: Rect, color: Color) {
+    let (scale, rotation, _) = global.to_scale_rotation_translation();
+    let center = global.transform_point(rect.center().extend(0.0));
+    gizmos.rect(Isometry3d::new(center, rotation), rect.size() * scale.truncate(), color);
+}
+
+/// Content box of a node of half size `half` (local space, y up).
+fn inset(half: Vec2, sides: USides) -> Rect {
+    Rect {
+        min: Vec2::new(-half.x + sides.left, -half.y + sides.bottom),
+        max: Vec2::new(half.x - sides.right, half.y - sides.top),
+    }
+}
+
+/// Margin box of a node of half size `half` (local space, y up).
+fn outset(half: Vec2, sides: USides) -> Rect {
+    Rect {
+        min: Vec2::new(-half.x - sides.left, -half.y - sides.bottom),
+        max: Vec2::new(half.x + sides.right, half.y + sides.top),
+    }
+}
+
+/// Space between two margin boxes that follow each other on the main axis
+/// and overlap on the cross axis.
+fn gap_between(first: Rect, second: Rect, is_row: bool) -> Option<Rect> {
+    let rect = if is_row {
+        Rect {
+            min: Vec2::new(first.max.x, first.min.y.max(second.min.y)),
+            max: Vec2::new(second.min.x, first.max.y.min(second.max.y)),
+        }
+    } else {
+        Rect {
+            min: Vec2::new(first.min.x.max(second.min.x), second.max.y),
+            max: Vec2::new(first.max.x.min(second.max.x), first.min.y),
+        }
+    };
+    (rect.width() > 0.5 && rect.height() > 0.5).then_some(rect)
+}
+
+/// Column and row tracks as rects in the local space of a node of half size
+/// `half`; each spans the extent of the tracks on the other axis.
+fn track_rects(tracks: &ComputedGridTracks, half: Vec2) -> Vec<Rect> {
+    let extent = |tracks: &[ComputedTrack]| {
+        let start = tracks.iter().map(|t| t.start).fold(f32::INFINITY, f32::min);
+        let end = tracks.iter().map(|t| t.start + t.size).fold(f32::NEG_INFINITY, f32::max);
+        (start, end)
+    };
+    if tracks.columns.is_empty() || tracks.rows.is_empty() {
+        return Vec::new();
+    }
+    let (top, bottom) = extent(&tracks.rows);
+    let (left, right) = extent(&tracks.columns);
+
+    // مساحة التخطيط (أعلى اليسار، y للأسفل) إلى المساحة المحلية
+    let local = |x0: f32, y0: f32, x1: f32, y1: f32| {
+        Rect::from_corners(Vec2::new(x0 - half.x, half.y - y0), Vec2::new(x1 - half.x, half.y - y1))
+    };
+    let columns = tracks.columns.iter().map(|t| local(t.start, top, t.start + t.size, bottom));
+    let rows = tracks.rows.iter().map(|t| local(left, t.start, right, t.start + t.size));
+    columns.chain(rows).collect()
+}
+
+/// Cursor position from the mouse ray of the top-most 2D camera under it,
+/// like the picking backend (so viewports and multiple windows work).
+fn cursor_world(ray_map: &RayMap, cameras: &Query<&Camera, With<Camera2d>>) -> Option<Vec2> {
+    ray_map
+        .iter()
+        .filter(|(id, _)| id.pointer == PointerId::Mouse)
+        .filter_map(|(id, ray)| Some((cameras.get(id.camera).ok()?, ray)))
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+        .map(|(_, ray)| ray.origin.truncate())
+}
+
+// =========================================================
+// Plugin
+// =========================================================
+
+/// Gizmo overlay for debugging layout, toggled with F8 (hover info with F7).
+pub struct LayoutDebugPlugin;
+
+impl Plugin for LayoutDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<LayoutDebugSettings>()
+            .add_systems(Startup, setup_layout_debug_text)
+            .add_systems(Update, (layout_debug_controls, track_grid_containers).chain())
+            .add_systems(
+                PostUpdate,
+                (draw_layout_debug, layout_debug_hover)
+                    .after(TransformSystems::Propagate),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::UnivisLayoutPlugin;
+
+    #[test]
+    fn gap_spans_the_space_between_row_items() {
+        let first = Rect::from_corners(Vec2::new(-50.0, -10.0), Vec2::new(-10.0, 10.0));
+        let second = Rect::from_corners(Vec2::new(0.0, -20.0), Vec2::new(40.0, 5.0));
+
+        let gap = gap_between(first, second, true).unwrap();
+        assert_eq!(gap, Rect::from_corners(Vec2::new(-10.0, -10.0), Vec2::new(0.0, 5.0)));
+        assert!(gap_between(first, second, false).is_none());
+    }
+
+    #[test]
+    fn column_gap_runs_downwards() {
+        let first = Rect::from_corners(Vec2::new(0.0, 20.0), Vec2::new(30.0, 40.0));
+        let second = Rect::from_corners(Vec2::new(0.0, -10.0), Vec2::new(30.0, 12.0));
+
+        let gap = gap_between(first, second, false).unwrap();
+        assert_eq!(gap, Rect::from_corners(Vec2::new(0.0, 12.0), Vec2::new(30.0, 20.0)));
+    }
+
+    #[test]
+    fn track_rects_cover_each_track_across_the_other_axis() {
+        let track = |start, size| ComputedTrack { start, size };
+        let tracks = ComputedGridTracks {
+            columns: vec![track(0.0, 40.0), track(50.0, 50.0)],
+            rows: vec![track(10.0, 30.0)],
+        };
+
+        let rects = track_rects(&tracks, Vec2::new(50.0, 25.0));
+        assert_eq!(rects, vec![
+            Rect::from_corners(Vec2::new(-50.0, 15.0), Vec2::new(-10.0, -15.0)),
+            Rect::from_corners(Vec2::new(0.0, 15.0), Vec2::new(50.0, -15.0)),
+            Rect::from_corners(Vec2::new(-50.0, 15.0), Vec2::new(50.0, -15.0)),
+        ]);
+        assert!(track_rects(&ComputedGridTracks::default(), Vec2::ONE).is_empty());
+    }
+
+    #[test]
+    fn grid_tracks_are_removed_when_the_overlay_turns_off() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        app.insert_resource(LayoutDebugSettings { enabled: true, ..default() });
+        app.add_systems(Update, track_grid_containers);
+        let grid = app
+            .world_mut()
+            .spawn((
+                UWorldRoot { size: Vec2::splat(100.0), ..default() },
+                UNode { width: UVal::Px(100.0), height: UVal::Px(100.0), ..default() },
+                ULayout { display: UDisplay::Grid, grid_columns: 2, ..default() },
+            ))
+            .id();
+        for _ in 0..3 {
+            app.world_mut().spawn((UNode { width: UVal::Px(10.0), height: UVal::Px(10.0), ..default() }, ChildOf(grid)));
+        }
+
+        app.update();
+        app.update();
+        let tracks = app.world().get::<ComputedGridTracks>(grid).unwrap();
+        assert_eq!((tracks.columns.len(), tracks.rows.len()), (2, 2));
+
+        app.world_mut().resource_mut::<LayoutDebugSettings>().enabled = false;
+        app.update();
+        assert!(app.world().get::<ComputedGridTracks>(grid).is_none());
+    }
+}
@@ -7,15 +7,22 @@ use crate::internal_prelude::*;
 ///
 /// This is the result of the layout solver. It is updated automatically during
 /// the `downward_solve_pass`.
-#[derive(Component, Default, Clone, Copy, Debug, Reflect)]
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Reflect)]
 #[reflect(Component)]
 pub struct ComputedSize {
     /// The calculated width of the node in logical pixels.
     pub width: f32,
     /// The calculated height of the node in logical pixels.
     pub height: f32,
-    /// The local position of the node relative to its parent's center.
-    pub local_pos: Vec2, 
+    /// The solved translation of the node relative to its parent's center.
+    ///
+    /// `Transform` may hold a drawn variant of it (pixel snapping, layout
+    /// transitions); this one always stays the exact layout result.
+    pub local_pos: Vec2,
+    /// Rotation (radians) the placer gave the node, e.g. radial `rotate_children`.
+    ///
+    /// `None` means the layout leaves `Transform.rotation` to the user.
+    pub rotation: Option<f32>,
 }
 
 
@@ -24,6 +31,69 @@ impl ComputedSize {
     pub fn size(&self) -> Vec2 {
         Vec2::new(self.width, self.height)
     }
+
+    /// Copy with the given size, keeping the position and rotation.
+    pub fn with_size(self, size: Vec2) -> Self {
+        Self { width: size.x, height: size.y, ..self }
+    }
+
+    /// Where the layout places the node: `local_pos` plus its sticky offset.
+    ///
+    /// Layout transitions ease toward it and pixel snapping starts from it.
+    pub fn placed_translation(&self, sticky: Option<&StickyOffset>) -> Vec2 {
+        sticky.map_or(self.local_pos, |sticky| self.local_pos + sticky.0)
+    }
+}
+
+/// Offset a `UPositionType::Sticky` node is moved by on top of `local_pos` to
+/// stay inside its scroll viewport.
+///
+/// Written by the scroll view after the solve and removed once the node stops
+/// being sticky.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct StickyOffset(pub Vec2);
+
+/// Scrollable overflow of a node: its own box, the margin boxes of its children
+/// and its end padding, in the node's layout space (origin at the top-left
+/// corner, y down).
+///
+/// Measured before relative offsets, so moving content through `USelf.top`/`left`
+/// (as scroll widgets do) doesn't change it. `Fixed` children are not included.
+/// It is updated during the `downward_solve_pass`.
+#[derive(Component, Default, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ScrollableOverflow {
+    pub rect: Rect,
+}
+
+impl ScrollableOverflow {
+    /// How far the content reaches past the right/bottom edges of a box of `size`.
+    pub fn overflow(&self, size: Vec2) -> Vec2 {
+        (self.rect.max - size).max(Vec2::ZERO)
+    }
+}
+
+/// Tracks of a grid container as the grid placer sized them, in the
+/// container's layout space (origin at the top-left corner, y down).
+///
+/// Only containers that carry the component are updated (the
+/// `LayoutDebugPlugin` adds it to grid containers); it is written during the
+/// `downward_solve_pass`.
+#[derive(Component, Default, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ComputedGridTracks {
+    /// Tracks along x, in column order.
+    pub columns: Vec<ComputedTrack>,
+    /// Tracks along y, in row order.
+    pub rows: Vec<ComputedTrack>,
+}
+
+/// Start offset and size of one grid track (collapsed tracks have size zero).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct ComputedTrack {
+    pub start: f32,
+    pub size: f32,
 }
 
 // --- 1. Basic Data Structures ---
@@ -40,25 +110,264 @@ pub enum UVal {
     /// Automatic sizing (fills remaining space or adapts to context).
     Auto,
     /// Flex grow factor. Takes a share of the remaining space.
-    Flex(f32)
+    Flex(f32),
+    /// A fraction of the owning root's width (0.0 to 1.0).
+    Vw(f32),
+    /// A fraction of the owning root's height (0.0 to 1.0).
+    Vh(f32),
+    /// A fraction of the smaller root axis (0.0 to 1.0).
+    VMin(f32),
+    /// A fraction of the larger root axis (0.0 to 1.0).
+    VMax(f32),
+    /// A multiple of `UUnitContext::font_size`.
+    ///
+    /// Nodes have no font size of their own, so layout resolves `Em` against
+    /// the theme's base font size, exactly like `Rem`.
+    Em(f32),
+    /// A multiple of the theme's base font size (`UUnitContext::root_font_size`).
+    Rem(f32),
+    /// A computed expression such as `100% - 48px` or `clamp(...)`.
+    Calc(UCalc),
 }
 
 impl Default for UVal {
     fn default() -> Self { Self::Px(0.0) }
 }
 
+/// Values needed to resolve viewport- and font-relative units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UUnitContext {
+    /// Size of the owning `UScreenRoot` / `UWorldRoot`.
+    pub viewport: Vec2,
+    /// Font size used by `Em`. Layout sets it to the theme's base font size.
+    pub font_size: f32,
+    /// Font size used by `Rem`.
+    pub root_font_size: f32,
+}
+
+impl Default for UUnitContext {
+    fn default() -> Self {
+        Self {
+            viewport: Vec2::ZERO,
+            font_size: DEFAULT_BASE_FONT_SIZE,
+            root_font_size: DEFAULT_BASE_FONT_SIZE,
+        }
+    }
+}
+
+/// Font size used for `Em`/`Rem` when no theme is available.
+pub const DEFAULT_BASE_FONT_SIZE: f32 = 16.0;
+
+/// A sum of length terms, e.g. `100% - 48px` is `UCalcSum::percent(1.0) - UCalcSum::px(48.0)`.
+///
+/// Fractions follow `UVal`: `percent`, `vw`, `vh`, `vmin` and `vmax` use 0.0 to 1.0.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq)]
+pub struct UCalcSum {
+    pub px: f32,
+    pub percent: f32,
+    pub vw: f32,
+    pub vh: f32,
+    pub vmin: f32,
+    pub vmax: f32,
+    pub em: f32,
+    pub rem: f32,
+}
+
+impl UCalcSum {
+    pub fn px(v: f32) -> Self { Self { px: v, ..default() } }
+    pub fn percent(v: f32) -> Self { Self { percent: v, ..default() } }
+    pub fn vw(v: f32) -> Self { Self { vw: v, ..default() } }
+    pub fn vh(v: f32) -> Self { Self { vh: v, ..default() } }
+    pub fn vmin(v: f32) -> Self { Self { vmin: v, ..default() } }
+    pub fn vmax(v: f32) -> Self { Self { vmax: v, ..default() } }
+    pub fn em(v: f32) -> Self { Self { em: v, ..default() } }
+    pub fn rem(v: f32) -> Self { Self { rem: v, ..default() } }
+
+    /// Resolves the sum. Returns `None` when a percentage term has no definite base.
+    pub fn resolve(&self, base: f32, ctx: &UUnitContext) -> Option<f32> {
+        let percent = if self.percent == 0.0 {
+            0.0
+        } else if base.is_finite() {
+            self.percent * base
+        } else {
+            return None;
+        };
+        Some(
+            self.px
+                + percent
+                + self.vw * ctx.viewport.x
+                + self.vh * ctx.viewport.y
+                + self.vmin * ctx.viewport.min_element()
+                + self.vmax * ctx.viewport.max_element()
+                + self.em * ctx.font_size
+                + self.rem * ctx.root_font_size,
+        )
+    }
+}
+
+impl std::ops::Add for UCalcSum {
+    type Output = Self;
+    fn add(self, o: Self) -> Self {
+        Self {
+            px: self.px + o.px,
+            percent: self.percent + o.percent,
+            vw: self.vw + o.vw,
+            vh: self.vh + o.vh,
+            vmin: self.vmin + o.vmin,
+            vmax: self.vmax + o.vmax,
+            em: self.em + o.em,
+            rem: self.rem + o.rem,
+        }
+    }
+}
+
+impl std::ops::Neg for UCalcSum {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            px: -self.px,
+            percent: -self.percent,
+            vw: -self.vw,
+            vh: -self.vh,
+            vmin: -self.vmin,
+            vmax: -self.vmax,
+            em: -self.em,
+            rem: -self.rem,
+        }
+    }
+}
+
+impl std::ops::Sub for UCalcSum {
+    type Output = Self;
+    fn sub(self, o: Self) -> Self { self + (-o) }
+}
+
+/// A `calc()` expression: a sum, or `min()`/`max()`/`clamp()` over sums.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub enum UCalc {
+    Sum(UCalcSum),
+    Min(UCalcSum, UCalcSum),
+    Max(UCalcSum, UCalcSum),
+    /// `clamp(min, preferred, max)`; the minimum wins on conflict.
+    Clamp(UCalcSum, UCalcSum, UCalcSum),
+}
+
+impl UCalc {
+    /// `true` when a term uses viewport or font units.
+    pub fn needs_context(&self) -> bool {
+        let sums = match self {
+            UCalc::Sum(a) => [Some(a), None, None],
+            UCalc::Min(a, b) | UCalc::Max(a, b) => [Some(a), Some(b), None],
+            UCalc::Clamp(a, b, c) => [Some(a), Some(b), Some(c)],
+        };
+        sums.into_iter().flatten().any(|sum| {
+            [sum.vw, sum.vh, sum.vmin, sum.vmax, sum.em, sum.rem].iter().any(|v| *v != 0.0)
+        })
+    }
+
+    pub fn resolve(&self, base: f32, ctx: &UUnitContext) -> Option<f32> {
+        match self {
+            UCalc::Sum(a) => a.resolve(base, ctx),
+            UCalc::Min(a, b) => Some(a.resolve(base, ctx)?.min(b.resolve(base, ctx)?)),
+            UCalc::Max(a, b) => Some(a.resolve(base, ctx)?.max(b.resolve(base, ctx)?)),
+            UCalc::Clamp(min, val, max) => {
+                let (min, val, max) = (min.resolve(base, ctx)?, val.resolve(base, ctx)?, max.resolve(base, ctx)?);
+                Some(val.min(max).max(min))
+            }
+        }
+    }
+}
+
 impl UVal {
+    /// `calc(sum)`, e.g. `UVal::calc(UCalcSum::percent(1.0) - UCalcSum::px(48.0))`.
+    pub fn calc(sum: UCalcSum) -> Self { UVal::Calc(UCalc::Sum(sum)) }
+    /// `min(a, b)`.
+    pub fn calc_min(a: UCalcSum, b: UCalcSum) -> Self { UVal::Calc(UCalc::Min(a, b)) }
+    /// `max(a, b)`.
+    pub fn calc_max(a: UCalcSum, b: UCalcSum) -> Self { UVal::Calc(UCalc::Max(a, b)) }
+    /// `clamp(min, preferred, max)`.
+    pub fn calc_clamp(min: UCalcSum, preferred: UCalcSum, max: UCalcSum) -> Self {
+        UVal::Calc(UCalc::Clamp(min, preferred, max))
+    }
+
+    /// Resolves a value that only uses `Px` and `Percent` terms, such as the
+    /// output of [`UVal::normalize`].
+    ///
+    /// Viewport and font units need a [`UUnitContext`], so they return `None`
+    /// here (also inside `Calc`): use [`UVal::resolve_in`] for them.
     pub fn resolve(&self, base: f32) -> Option<f32> {
+        if self.needs_context() {
+            return None;
+        }
+        self.resolve_in(base, &UUnitContext::default())
+    }
+
+    /// `true` for viewport- and font-relative units (also inside `Calc`).
+    pub fn needs_context(&self) -> bool {
+        match self {
+            UVal::Vw(_) | UVal::Vh(_) | UVal::VMin(_) | UVal::VMax(_) | UVal::Em(_) | UVal::Rem(_) => true,
+            UVal::Calc(calc) => calc.needs_context(),
+            _ => false,
+        }
+    }
+
+    /// Resolves the value against `base` (for percentages) and `ctx` (for viewport/font units).
+    pub fn resolve_in(&self, base: f32, ctx: &UUnitContext) -> Option<f32> {
         match *self {
             UVal::Px(v) => Some(v),
             UVal::Percent(p) => Some(p * base),
+            UVal::Vw(v) => Some(v * ctx.viewport.x),
+            UVal::Vh(v) => Some(v * ctx.viewport.y),
+            UVal::VMin(v) => Some(v * ctx.viewport.min_element()),
+            UVal::VMax(v) => Some(v * ctx.viewport.max_element()),
+            UVal::Em(v) => Some(v * ctx.font_size),
+            UVal::Rem(v) => Some(v * ctx.root_font_size),
+            UVal::Calc(c) => c.resolve(base, ctx),
             UVal::Content | UVal::Auto | UVal::Flex(_) => None,
         }
     }
 
+    /// Rewrites context-dependent units as `Px` so the solver only sees
+    /// `Px`, `Percent`, `Content`, `Auto` and `Flex`.
+    ///
+    /// A `Calc` with a percentage term and no definite `base` becomes `Content`.
+    pub fn normalize(&self, base: f32, ctx: &UUnitContext) -> UVal {
+        match *self {
+            UVal::Px(_) | UVal::Percent(_) | UVal::Content | UVal::Auto | UVal::Flex(_) => *self,
+            _ => self.resolve_in(base, ctx).map(UVal::Px).unwrap_or(UVal::Content),
+        }
+    }
+
     pub fn resolve_or_zero(&self, base: f32) -> f32 {
         self.resolve(base).unwrap_or(0.0)
     }
+
+    /// Resolves a `min_*`/`max_*` size limit against `base`.
+    ///
+    /// Returns `None` when the value does not constrain the size (`Auto`, `Content`, `Flex`)
+    /// or when a percentage has no definite base.
+    pub fn resolve_limit(&self, base: f32) -> Option<f32> {
+        match *self {
+            UVal::Px(v) => Some(v.max(0.0)),
+            UVal::Percent(p) if base.is_finite() => Some((p * base).max(0.0)),
+            UVal::Content | UVal::Auto | UVal::Flex(_) | UVal::Percent(_) => None,
+            _ => self.resolve(base).filter(|v| v.is_finite()).map(|v| v.max(0.0)),
+        }
+    }
+}
+
+/// Clamps `value` between the `min` and `max` limits resolved against `base`.
+///
+/// Like CSS, the minimum wins when the two limits conflict.
+pub fn clamp_to_limits(value: f32, min: UVal, max: UVal, base: f32) -> f32 {
+    let mut clamped = value;
+    if let Some(max) = max.resolve_limit(base) {
+        clamped = clamped.min(max);
+    }
+    if let Some(min) = min.resolve_limit(base) {
+        clamped = clamped.max(min);
+    }
+    clamped
 }
 
 /// Defines spacing (Padding or Margin) for the four sides of a box.
@@ -282,6 +591,65 @@ impl AxisHelper {
             (spec.width_mode, spec.width_val, spec.width_flex)
         }
     }
+
+    /// Returns the `(min, max)` size limits on the main axis.
+    pub fn get_main_limits(&self, spec: &SolverSpec) -> (UVal, UVal) {
+        if self.is_row() {
+            (spec.min_width, spec.max_width)
+        } else {
+            (spec.min_height, spec.max_height)
+        }
+    }
+
+    /// Returns the `(min, max)` size limits on the cross axis.
+    pub fn get_cross_limits(&self, spec: &SolverSpec) -> (UVal, UVal) {
+        if self.is_row() {
+            (spec.min_height, spec.max_height)
+        } else {
+            (spec.min_width, spec.max_width)
+        }
+    }
+
+    /// Returns the intrinsic content size on the main axis.
+    pub fn get_main_content(&self, spec: &SolverSpec) -> f32 {
+        if self.is_row() { spec.content_width } else { spec.content_height }
+    }
+
+    pub fn get_cross_content(&self, spec: &SolverSpec) -> f32 {
+        if self.is_row() { spec.content_height } else { spec.content_width }
+    }
+
+    /// Returns the min-content size on the main axis.
+    pub fn get_main_min_content(&self, spec: &SolverSpec) -> f32 {
+        if self.is_row() { spec.min_content_width } else { spec.min_content_height }
+    }
+
+    /// Returns the min-content size on the cross axis.
+    pub fn get_cross_min_content(&self, spec: &SolverSpec) -> f32 {
+        if self.is_row() { spec.min_content_height } else { spec.min_content_width }
+    }
+
+    /// Clamps a main-axis size to the item's min/max limits.
+    pub fn clamp_main(&self, spec: &SolverSpec, value: f32, base: f32) -> f32 {
+        let (min, max) = self.get_main_limits(spec);
+        clamp_to_limits(value, min, max, base)
+    }
+
+    /// Clamps a cross-axis size to the item's min/max limits.
+    pub fn clamp_cross(&self, spec: &SolverSpec, value: f32, base: f32) -> f32 {
+        let (min, max) = self.get_cross_limits(spec);
+        clamp_to_limits(value, min, max, base)
+    }
+
+    /// Cross-axis size implied by a `width / height` aspect ratio and a main-axis size.
+    pub fn cross_from_ratio(&self, ratio: f32, main: f32) -> f32 {
+        if self.is_row() { main / ratio } else { main * ratio }
+    }
+
+    /// Main-axis size implied by a `width / height` aspect ratio and a cross-axis size.
+    pub fn main_from_ratio(&self, ratio: f32, cross: f32) -> f32 {
+        if self.is_row() { cross * ratio } else { cross / ratio }
+    }
 }
 
 /// Layout constraints passed down from parent to child.
@@ -12,6 +12,8 @@ pub struct UImage {
     pub width: UVal,
     pub height: UVal,
     pub radius: Option<UCornerRadius>,
+    /// When only one axis is `Auto`, derive it from the texture's natural aspect ratio.
+    pub keep_aspect_ratio: bool,
 }
 
 impl Default for UImage {
@@ -22,6 +24,7 @@ impl Default for UImage {
             width: UVal::Auto,
             height: UVal::Auto,
             radius: None,
+            keep_aspect_ratio: true,
         }
     }
 }
@@ -41,4 +44,9 @@ impl UImage {
         self.radius = Some(radius);
         self
     }
+
+    pub fn with_keep_aspect_ratio(mut self, keep: bool) -> Self {
+        self.keep_aspect_ratio = keep;
+        self
+    }
 }
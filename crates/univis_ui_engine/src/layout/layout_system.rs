@@ -1,4 +1,4 @@
-use bevy::{ecs::relationship::Relationship, prelude::*};
+use bevy::{camera::visibility::RenderLayers, ecs::relationship::Relationship, prelude::*};
 
 use crate::internal_prelude::*;
 
@@ -10,6 +10,14 @@ use crate::internal_prelude::*;
 #[require(UNode)]
 pub struct UScreenRoot; 
 
+/// Binds a `UScreenRoot` to a camera (and so to its window and viewport).
+///
+/// The root is sized from that camera's viewport, follows the camera, takes
+/// its `RenderLayers`, and is only picked through it. Use one root per
+/// viewport for split-screen HUDs or secondary windows.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UTargetCamera(pub Entity);
+
 /// Marker for World Space UI Root.
 ///
 /// Use this for UI elements that exist in the 3D world (e.g., floating over a character).
@@ -62,4 +70,235 @@ pub fn auto_propagate_ui3d(
             commands.entity(child_entity).insert(UI3d);
         }
     }
-}
\ No newline at end of file
+}
+
+/// Marks a `UClip` that was inserted or enabled by `sync_overflow_clip`.
+#[derive(Component)]
+pub struct UOverflowClip;
+
+/// Data `sync_overflow_clip` reads and writes for each changed layout.
+type OverflowClipData = (Entity, &'static ULayout, Option<&'static mut UClip>, Has<UOverflowClip>);
+
+/// Keeps `UClip` in line with `ULayout.overflow_x/overflow_y`, clipping only
+/// the axes whose overflow isn't `Visible`.
+///
+/// A `UClip` the user set on a `Visible` node is left alone.
+pub fn sync_overflow_clip(
+    mut commands: Commands,
+    mut layouts: Query<OverflowClipData, Changed<ULayout>>,
+) {
+    for (entity, layout, clip, owned) in layouts.iter_mut() {
+        let axes = BVec2::new(layout.overflow_x.clips(), layout.overflow_y.clips());
+        match (axes.any(), clip) {
+            (true, Some(mut clip)) if owned || !clip.enabled => {
+                clip.enabled = true;
+                commands.entity(entity).insert((UClipAxes(axes), UOverflowClip));
+            }
+            (true, None) => {
+                commands.entity(entity).insert((UClip::axes(axes.x, axes.y), UOverflowClip));
+            }
+            (false, Some(mut clip)) if owned => {
+                clip.enabled = false;
+                commands.entity(entity).remove::<(UClipAxes, UOverflowClip)>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Centers every `UTargetCamera` root on its camera.
+pub fn follow_target_camera(
+    cameras: Query<&GlobalTransform, With<Camera>>,
+    mut roots: Query<(&UTargetCamera, &mut Transform), With<UScreenRoot>>,
+) {
+    for (target, mut transform) in roots.iter_mut() {
+        let Ok(camera) = cameras.get(target.0) else {
+            continue;
+        };
+        let center = camera.translation().truncate();
+        if transform.translation.truncate() != center {
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+        }
+    }
+}
+
+/// Gives each `UTargetCamera` tree the `RenderLayers` of its camera, so other
+/// cameras don't draw it.
+///
+/// Only trees whose target or camera layers changed are walked, plus new and
+/// reparented nodes under a targeted root.
+pub fn propagate_target_layers(
+    mut commands: Commands,
+    cameras: Query<Option<Ref<RenderLayers>>, With<Camera>>,
+    mut removed_layers: RemovedComponents<RenderLayers>,
+    roots: Query<(Entity, Ref<UTargetCamera>), With<UScreenRoot>>,
+    moved_nodes: Query<Entity, Changed<ChildOf>>,
+    parents: Query<&ChildOf>,
+    tree: Query<(Option<&RenderLayers>, Option<&Children>)>,
+) {
+    let removed: Vec<Entity> = removed_layers.read().collect();
+    let mut walked: Vec<Entity> = Vec::new();
+    for (root, target) in roots.iter() {
+        let Ok(camera_layers) = cameras.get(target.0) else {
+            continue;
+        };
+        let camera_changed = camera_layers.as_ref().is_some_and(|layers| layers.is_changed())
+            || removed.contains(&target.0);
+        if target.is_changed() || camera_changed {
+            let wanted = camera_layers.map(|layers| layers.clone()).unwrap_or_default();
+            apply_target_layers(&mut commands, root, &wanted, &tree);
+            walked.push(root);
+        }
+    }
+
+    for entity in moved_nodes.iter() {
+        // فرع أبوه جديد أيضاً يُعالج مع أبيه
+        if parents.get(entity).is_ok_and(|parent| moved_nodes.contains(parent.parent())) {
+            continue;
+        }
+        let Some((root, target)) = std::iter::once(entity)
+            .chain(parents.iter_ancestors(entity))
+            .find_map(|ancestor| roots.get(ancestor).ok())
+        else {
+            continue;
+        };
+        if walked.contains(&root) {
+            continue;
+        }
+        let Ok(camera_layers) = cameras.get(target.0) else {
+            continue;
+        };
+        let wanted = camera_layers.map(|layers| layers.clone()).unwrap_or_default();
+        apply_target_layers(&mut commands, entity, &wanted, &tree);
+    }
+}
+
+fn apply_target_layers(
+    commands: &mut Commands,
+    entity: Entity,
+    wanted: &RenderLayers,
+    tree: &Query<(Option<&RenderLayers>, Option<&Children>)>,
+) {
+    let Ok((current, children)) = tree.get(entity) else {
+        return;
+    };
+    // غياب المكون يعني الطبقة الافتراضية 0
+    if current.map_or(*wanted != RenderLayers::default(), |layers| layers != wanted) {
+        commands.entity(entity).insert(wanted.clone());
+    }
+    if let Some(children) = children {
+        for &child in children {
+            apply_target_layers(commands, child, wanted, tree);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn target_camera_roots_follow_the_camera_and_take_its_layers() {
+        let mut app = App::new();
+        app.add_systems(Update, (follow_target_camera, propagate_target_layers));
+
+        let world = app.world_mut();
+        let camera = world
+            .spawn((
+                Camera2d,
+                GlobalTransform::from_xyz(640.0, -40.0, 0.0),
+                RenderLayers::layer(2),
+            ))
+            .id();
+        let other = world.spawn((Camera2d, GlobalTransform::from_xyz(-640.0, 0.0, 0.0))).id();
+        let root = world
+            .spawn((UScreenRoot, UTargetCamera(camera)))
+            .with_children(|root| {
+                root.spawn(UNode::default());
+            })
+            .id();
+        let untargeted = world.spawn(UScreenRoot).id();
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<Transform>(root).unwrap().translation, Vec3::new(640.0, -40.0, 0.0));
+        let child = world.get::<Children>(root).unwrap()[0];
+        for entity in [root, child] {
+            assert_eq!(world.get::<RenderLayers>(entity), Some(&RenderLayers::layer(2)));
+        }
+        assert!(world.get::<RenderLayers>(untargeted).is_none());
+        assert!(world.get::<RenderLayers>(other).is_none());
+    }
+
+    #[test]
+    fn target_layers_follow_changes_instead_of_every_frame() {
+        let mut app = App::new();
+        app.add_systems(Update, propagate_target_layers);
+
+        let camera = app.world_mut().spawn((Camera2d, RenderLayers::layer(2))).id();
+        let root = app.world_mut().spawn((UScreenRoot, UTargetCamera(camera))).id();
+        let child = app.world_mut().spawn((UNode::default(), ChildOf(root))).id();
+        app.update();
+
+        // Nothing changed: a layer set by hand stays.
+        app.world_mut().entity_mut(child).insert(RenderLayers::layer(5));
+        app.update();
+        assert_eq!(app.world().get::<RenderLayers>(child), Some(&RenderLayers::layer(5)));
+
+        // New nodes under the root take the camera's layers.
+        let item = app.world_mut().spawn(UNode::default()).id();
+        let leaf = app.world_mut().spawn((UNode::default(), ChildOf(item))).id();
+        app.world_mut().entity_mut(item).insert(ChildOf(child));
+        app.update();
+        for entity in [item, leaf] {
+            assert_eq!(app.world().get::<RenderLayers>(entity), Some(&RenderLayers::layer(2)));
+        }
+
+        // A camera layer change walks the whole tree.
+        app.world_mut().entity_mut(camera).insert(RenderLayers::layer(3));
+        app.update();
+        for entity in [root, child, item, leaf] {
+            assert_eq!(app.world().get::<RenderLayers>(entity), Some(&RenderLayers::layer(3)));
+        }
+
+        app.world_mut().entity_mut(camera).remove::<RenderLayers>();
+        app.update();
+        for entity in [root, child, item, leaf] {
+            assert_eq!(app.world().get::<RenderLayers>(entity), Some(&RenderLayers::default()));
+        }
+    }
+
+    #[test]
+    fn overflow_clips_each_axis_on_its_own() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_overflow_clip);
+
+        let node = app
+            .world_mut()
+            .spawn(ULayout { overflow_y: UOverflow::Scroll, ..default() })
+            .id();
+        app.update();
+        assert!(app.world().get::<UClip>(node).unwrap().enabled);
+        let axes = *app.world().get::<UClipAxes>(node).unwrap();
+        assert_eq!(axes, UClipAxes(BVec2::new(false, true)));
+        assert_eq!(axes.mask_size(Vec2::new(100.0, 50.0)), Vec2::new(UClip::UNCLIPPED_EXTENT, 50.0));
+
+        app.world_mut().get_mut::<ULayout>(node).unwrap().overflow_x = UOverflow::Clip;
+        app.update();
+        assert_eq!(app.world().get::<UClipAxes>(node).unwrap().0, BVec2::TRUE);
+
+        let mut layout = app.world_mut().get_mut::<ULayout>(node).unwrap();
+        layout.overflow_x = UOverflow::Visible;
+        layout.overflow_y = UOverflow::Visible;
+        app.update();
+        assert!(!app.world().get::<UClip>(node).unwrap().enabled);
+        assert!(!app.world().entity(node).contains::<UOverflowClip>());
+        assert!(!app.world().entity(node).contains::<UClipAxes>());
+
+        // A clip the user enabled on a Visible node is not the sync's to change.
+        let user = app.world_mut().spawn((ULayout::default(), UClip::axes(true, false))).id();
+        app.update();
+        assert_eq!(app.world().get::<UClipAxes>(user).unwrap().0, BVec2::new(true, false));
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+/// Measures a leaf node's content for a given available width.
+///
+/// Implemented for any `Fn(Option<f32>) -> Vec2`.
+pub trait UMeasureFunc: Send + Sync + 'static {
+    /// Returns the content-box size for `available_width`.
+    /// `None` asks for the unconstrained (max-content) size.
+    fn measure(&self, available_width: Option<f32>) -> Vec2;
+}
+
+impl<F> UMeasureFunc for F
+where
+    F: Fn(Option<f32>) -> Vec2 + Send + Sync + 'static,
+{
+    fn measure(&self, available_width: Option<f32>) -> Vec2 {
+        self(available_width)
+    }
+}
+
+/// Height-for-width hook for leaf nodes such as wrapping text.
+///
+/// The upward pass uses it for the node's intrinsic size, and the downward pass
+/// measures again with the solved width so siblings move in the same frame.
+/// Only nodes without in-flow `UNode` children are measured.
+#[derive(Component, Clone)]
+pub struct UMeasure(Arc<dyn UMeasureFunc>);
+
+impl UMeasure {
+    pub fn new(func: impl UMeasureFunc) -> Self {
+        Self(Arc::new(func))
+    }
+
+    pub fn measure(&self, available_width: Option<f32>) -> Vec2 {
+        self.0.measure(available_width)
+    }
+}
+
+impl std::fmt::Debug for UMeasure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("UMeasure").finish_non_exhaustive()
+    }
+}
@@ -1,21 +1,39 @@
 pub mod algorithms;
 pub mod components;
 pub mod core;
+pub mod debug;
 pub mod geometry;
 pub mod image;
 pub mod layout_system;
+pub mod measure;
 pub mod pipeline;
 pub mod pbr;
 pub mod profiling;
 pub mod render;
+pub mod responsive;
+pub mod scale;
+pub mod snapshot;
 pub mod solver_types;
+pub mod transition;
 pub mod univis_node;
 
 pub mod prelude {
-    pub use crate::layout::geometry::{UCornerRadius, USides, UVal};
+    pub use crate::layout::algorithms::bridge::LayoutPlacer;
+    pub use crate::layout::algorithms::registry::{LayoutPlacerAppExt, LayoutPlacerRegistry};
+    pub use crate::layout::core::solver::{PlacementContext, SolverItem, SolverResult};
+    pub use crate::layout::core::parallel::LayoutParallelism;
+    pub use crate::layout::core::tree::{LayoutNodeId, LayoutTree};
+    pub use crate::layout::geometry::AxisHelper;
+    pub use crate::layout::solver_types::SolverSpec;
+    pub use crate::layout::transition::{LayoutTransitionPlugin, LayoutTransitionState, ULayoutTransition};
+    pub use crate::layout::geometry::{UCalc, UCalcSum, UCornerRadius, USides, UVal};
     pub use crate::layout::image::UImage;
-    pub use crate::layout::layout_system::{UScreenRoot, UWorldRoot};
+    pub use crate::layout::layout_system::{UScreenRoot, UTargetCamera, UWorldRoot};
+    pub use crate::layout::measure::{UMeasure, UMeasureFunc};
     pub use crate::layout::pbr::UPbr;
+    pub use crate::layout::responsive::{UQuery, UQueryContainer, UQueryTarget, UResponsive, UResponsiveRule};
+    pub use crate::layout::scale::{PixelSnap, UiScalePlugin, UiScaleSettings};
+    pub use crate::layout::snapshot::{LayoutSnapshot, SnapshotMismatch, SnapshotNode};
     pub use crate::layout::univis_node::*;
     pub use crate::layout::UnivisLayoutPlugin;
 }
@@ -46,7 +64,14 @@ impl Plugin for UnivisLayoutPlugin {
             .register_type::<UFlexWrap>()
             .register_type::<UTrackSize>()
             .register_type::<UGridAutoFlow>()
+            .register_type::<UBaseline>()
+            .register_type::<UZIndex>()
+            .register_type::<UResponsive>()
+            .register_type::<UQueryContainer>()
+            .register_type::<UQuery>()
             .init_resource::<LayoutTreeDepth>()
+            .init_resource::<LayoutPlacerRegistry>()
+            .init_resource::<LayoutParallelism>()
             .add_plugins(LayoutCachePlugin)
             .configure_sets(
                 PostUpdate,
@@ -59,6 +84,18 @@ impl Plugin for UnivisLayoutPlugin {
                 )
                     .chain(),
             )
+            .add_systems(
+                PostUpdate,
+                (apply_responsive_overrides, sync_overflow_clip, propagate_direction)
+                    .chain()
+                    .in_set(UnivisPostUpdateSet::WidgetSync),
+            )
+            .add_systems(
+                PostUpdate,
+                (follow_target_camera, propagate_target_layers)
+                    .in_set(UnivisPostUpdateSet::WidgetSync)
+                    .before(TransformSystems::Propagate),
+            )
             .add_systems(
                 PostUpdate,
                 update_layout_hierarchy.in_set(UnivisPostUpdateSet::LayoutHierarchy),
@@ -69,7 +106,15 @@ impl Plugin for UnivisLayoutPlugin {
             )
             .add_systems(
                 PostUpdate,
-                downward_solve_pass_safe.in_set(UnivisPostUpdateSet::LayoutSolve),
+                (
+                    downward_solve_pass_safe,
+                    // عقد تغير قياسها بعد الحل: جولة قياس وحل ثانية في نفس الإطار
+                    (upward_measure_pass_cached, downward_solve_pass_safe)
+                        .chain()
+                        .run_if(layout_remeasure_pending),
+                )
+                    .chain()
+                    .in_set(UnivisPostUpdateSet::LayoutSolve),
             );
     }
 }
@@ -39,6 +39,8 @@ pub struct LayoutProfiler {
     pub total_nodes: usize,
     pub dirty_nodes: usize,
     pub visible_nodes: usize,
+    /// Containers whose downward solve was reused from the last frame.
+    pub skipped_solves: usize,
 
     // Frame history
     pub frame_history: Vec<FrameStats>,
@@ -72,6 +74,7 @@ impl Default for LayoutProfiler {
             total_nodes: 0,
             dirty_nodes: 0,
             visible_nodes: 0,
+            skipped_solves: 0,
             frame_history: Vec::new(),
             max_history: 300,
             materials_created: 0,
@@ -6,6 +6,11 @@ use crate::internal_prelude::*;
 pub struct MaterialHandles {
     pub material_2d: Option<Handle<UNodeMaterial>>,
     pub material_3d: Option<Handle<UNodeMaterial3d>>,
+    /// الشبكة المستخدمة حالياً والحجم الذي بُنيت به
+    pub mesh: Option<Handle<Mesh>>,
+    pub mesh_size: Vec2,
+    /// هل المكونات الحالية 3D؟
+    pub is_3d: bool,
 }
 
 impl Default for MaterialHandles {
@@ -13,6 +18,9 @@ impl Default for MaterialHandles {
         Self {
             material_2d: None,
             material_3d: None,
+            mesh: None,
+            mesh_size: Vec2::ZERO,
+            is_3d: false,
         }
     }
 }
@@ -34,6 +42,9 @@ impl MaterialPool {
 }
 
 /// نظام محسّن لتحديث المواد بدون تسرب
+///
+/// إذا بقي الحجم ونوع العرض (2D/3D) كما هما، فالتغيير رسم فقط (لون، زوايا، حد):
+/// نحدّث قيم المادة الموجودة دون بناء شبكة جديدة أو إعادة إدراج المكونات.
 pub fn update_materials_optimized(
     mut commands: Commands,
     mut pool: ResMut<MaterialPool>,
@@ -45,6 +56,8 @@ pub fn update_materials_optimized(
             Entity,
             &UNode,
             &ComputedSize,
+            Option<&LayoutTransitionState>,
+            Option<&PixelSnap>,
             Option<&UBorder>,
             Option<&UImage>,
             Option<&UI3d>, // <--- نحتاج هذا للتمييز بين 2D و 3D
@@ -54,6 +67,8 @@ pub fn update_materials_optimized(
         Or<(
             Changed<UNode>,
             Changed<ComputedSize>,
+            Changed<LayoutTransitionState>,
+            Changed<PixelSnap>,
             Changed<UBorder>,
             Changed<UImage>,
             Changed<UI3d>,
@@ -64,7 +79,7 @@ pub fn update_materials_optimized(
 
     // استعلامات القص
     parents_query: Query<&ChildOf>,
-    clipper_query: Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip)>,
+    clipper_query: Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip, Option<&UClipAxes>)>,
 
     // الموارد
     mut meshes: ResMut<Assets<Mesh>>,
@@ -75,9 +90,10 @@ pub fn update_materials_optimized(
     let created_before = pool.created_count;
     let reused_before = pool.reused_count;
     
-    for (entity, node, size, border, image, ui3d_opt, pbr_opt, handles_opt) in query.iter_mut() {
+    for (entity, node, size, transition, snap, border, image, ui3d_opt, pbr_opt, handles_opt) in query.iter_mut() {
         
-        let size_vec = Vec2::new(size.width, size.height);
+        // نرسم بالحجم المقرّب أو المتحرك لا بنتيجة التخطيط
+        let size_vec = drawn_size(size, transition, snap);
         if size_vec.x <= 0.0 || size_vec.y <= 0.0 { continue; }
 
         // --- البيانات المشتركة ---
@@ -108,7 +124,14 @@ pub fn update_materials_optimized(
             entity, &parents_query, &clipper_query
         );
 
-        let mesh = meshes.add(Rectangle::new(size_vec.x, size_vec.y));
+        let is_3d = ui3d_opt.is_some();
+        let paint_only = handles_opt
+            .as_ref()
+            .and_then(|handles| handles.mesh.clone().filter(|_| handles.mesh_size == size_vec && handles.is_3d == is_3d));
+        let created_at_node = pool.created_count;
+        let mesh = paint_only
+            .clone()
+            .unwrap_or_else(|| meshes.add(Rectangle::new(size_vec.x, size_vec.y)));
 
         // =========================================================
         // التفرع: هل نحن في وضع 3D أم 2D؟
@@ -124,6 +147,9 @@ pub fn update_materials_optimized(
             };
 
             let material_handle = if let Some(mut handles) = handles_opt {
+                handles.mesh = Some(mesh.clone());
+                handles.mesh_size = size_vec;
+                handles.is_3d = is_3d;
                 if let Some(existing_handle) = &handles.material_3d {
                     if let Some(existing_mat) = materials_3d.get_mut(existing_handle) {
                         // تحديث المادة 3D الموجودة
@@ -168,12 +194,18 @@ pub fn update_materials_optimized(
                 commands.entity(entity).insert(MaterialHandles {
                     material_2d: None,
                     material_3d: Some(new_mat.clone()),
+                    mesh: Some(mesh.clone()),
+                    mesh_size: size_vec,
+                    is_3d,
                 });
                 pool.created_count += 1;
                 new_mat
             };
 
             // تطبيق مكونات 3D وإزالة 2D
+            if paint_only.is_some() && pool.created_count == created_at_node {
+                continue;
+            }
             commands.entity(entity)
                 .insert((
                     Mesh3d(mesh),
@@ -185,6 +217,9 @@ pub fn update_materials_optimized(
             // >>>> مسار 2D (مع القص) <<<<
             
             let material_handle = if let Some(mut handles) = handles_opt {
+                handles.mesh = Some(mesh.clone());
+                handles.mesh_size = size_vec;
+                handles.is_3d = is_3d;
                 if let Some(existing_handle) = &handles.material_2d {
                     if let Some(existing_mat) = materials_2d.get_mut(existing_handle) {
                         // تحديث المادة 2D الموجودة
@@ -236,12 +271,18 @@ pub fn update_materials_optimized(
                 commands.entity(entity).insert(MaterialHandles {
                     material_2d: Some(new_mat.clone()),
                     material_3d: None,
+                    mesh: Some(mesh.clone()),
+                    mesh_size: size_vec,
+                    is_3d,
                 });
                 pool.created_count += 1;
                 new_mat
             };
 
             // تطبيق مكونات 2D وإزالة 3D
+            if paint_only.is_some() && pool.created_count == created_at_node {
+                continue;
+            }
             commands.entity(entity)
                 .insert((
                     Mesh2d(mesh),
@@ -263,19 +304,20 @@ pub fn update_materials_optimized(
 fn find_clipper(
     start_entity: Entity,
     parents_query: &Query<&ChildOf>,
-    clipper_query: &Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip)>,
+    clipper_query: &Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip, Option<&UClipAxes>)>,
 ) -> (Vec2, Vec2, Vec4, u32) {
     let mut current_entity = start_entity;
     while let Ok(parent) = parents_query.get(current_entity) {
         current_entity = parent.get();
-        if let Ok((transform, size, node, clip)) = clipper_query.get(current_entity) {
+        if let Ok((transform, size, node, clip, axes)) = clipper_query.get(current_entity) {
             if clip.enabled {
+                let axes = axes.copied().unwrap_or_default();
                 let center = transform.translation().truncate();
-                let clip_size = Vec2::new(size.width, size.height);
-                let radius = Vec4::new(
+                let clip_size = axes.mask_size(Vec2::new(size.width, size.height));
+                let radius = axes.mask_radius(Vec4::new(
                     node.border_radius.top_right, node.border_radius.bottom_right,
                     node.border_radius.top_left, node.border_radius.bottom_left,
-                );
+                ));
                 return (center, clip_size, radius, 1);
             }
         }
@@ -0,0 +1,338 @@
+use bevy::prelude::*;
+use crate::internal_prelude::*;
+
+/// What a `UQuery` measures.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum UQueryTarget {
+    /// Size of the owning `UScreenRoot` / `UWorldRoot` (like a media query).
+    #[default]
+    Root,
+    /// `ComputedSize` of the nearest ancestor marked `UQueryContainer`, from
+    /// the previous frame (like a container query). Never matches without one.
+    Container,
+}
+
+/// Makes a node the container that `UQuery::container()` rules of its
+/// descendants measure.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct UQueryContainer;
+
+/// Size condition of a `UResponsiveRule`.
+///
+/// Bounds are inclusive and unset bounds always match.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct UQuery {
+    pub target: UQueryTarget,
+    pub min_width: Option<f32>,
+    pub max_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_height: Option<f32>,
+}
+
+impl UQuery {
+    /// Query on the root size.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    /// Query on the size of the nearest `UQueryContainer` ancestor.
+    pub fn container() -> Self {
+        Self { target: UQueryTarget::Container, ..default() }
+    }
+
+    pub fn min_width(mut self, value: f32) -> Self {
+        self.min_width = Some(value);
+        self
+    }
+
+    pub fn max_width(mut self, value: f32) -> Self {
+        self.max_width = Some(value);
+        self
+    }
+
+    pub fn min_height(mut self, value: f32) -> Self {
+        self.min_height = Some(value);
+        self
+    }
+
+    pub fn max_height(mut self, value: f32) -> Self {
+        self.max_height = Some(value);
+        self
+    }
+
+    pub fn matches(&self, size: Vec2) -> bool {
+        self.min_width.is_none_or(|v| size.x >= v)
+            && self.max_width.is_none_or(|v| size.x <= v)
+            && self.min_height.is_none_or(|v| size.y >= v)
+            && self.max_height.is_none_or(|v| size.y <= v)
+    }
+}
+
+/// An alternative for `UResponsive`: while `query` matches, `layout` and `node`
+/// replace the node's own components (`None` leaves the component alone).
+#[derive(Clone, Reflect)]
+pub struct UResponsiveRule {
+    pub query: UQuery,
+    pub layout: Option<ULayout>,
+    pub node: Option<UNode>,
+}
+
+impl UResponsiveRule {
+    pub fn layout(query: UQuery, layout: ULayout) -> Self {
+        Self { query, layout: Some(layout), node: None }
+    }
+
+    pub fn node(query: UQuery, node: UNode) -> Self {
+        Self { query, layout: None, node: Some(node) }
+    }
+}
+
+/// Switches a node between alternative `ULayout` / `UNode` values by size.
+///
+/// The first matching rule wins. Overrides replace the whole component, and
+/// the value they replaced comes back once no rule overrides that component.
+/// A component no rule overrides is never written, so edits made to it while
+/// a rule is active are kept.
+///
+/// After a switch the node settles for one frame and the sizes it reaches are
+/// recorded; rules are evaluated again only once the root or container size
+/// moves away from them. A switch that resizes a content-sized container
+/// therefore can't undo itself.
+#[derive(Component, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct UResponsive {
+    pub rules: Vec<UResponsiveRule>,
+    #[reflect(ignore)]
+    active: Option<usize>,
+    #[reflect(ignore)]
+    base_layout: Option<ULayout>,
+    #[reflect(ignore)]
+    base_node: Option<UNode>,
+    #[reflect(ignore)]
+    settled: Option<(Vec2, Vec2)>,
+    #[reflect(ignore)]
+    settling: bool,
+}
+
+impl UResponsive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rule(mut self, rule: UResponsiveRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Index of the rule currently applied.
+    pub fn active_rule(&self) -> Option<usize> {
+        self.active
+    }
+
+    /// Advances the switching state. Returns the rule to switch to, if any.
+    ///
+    /// `container_size` is `None` without a `UQueryContainer` ancestor, and
+    /// zero until that container was solved once.
+    fn step(&mut self, root_size: Vec2, container_size: Option<Vec2>) -> Option<Option<usize>> {
+        // الحاوية لم تُحل بعد: لا نقيّم ولا نسجل حتى يصل حجمها الفعلي
+        let has_container_rules = self.rules.iter().any(|rule| rule.query.target == UQueryTarget::Container);
+        if has_container_rules && container_size == Some(Vec2::ZERO) {
+            return None;
+        }
+        let sizes = (root_size, container_size.unwrap_or(Vec2::NEG_ONE));
+        if self.settling {
+            self.settling = false;
+            self.settled = Some(sizes);
+            return None;
+        }
+        if self.settled.is_some_and(|(root, container)| {
+            root.abs_diff_eq(sizes.0, 0.5) && container.abs_diff_eq(sizes.1, 0.5)
+        }) {
+            return None;
+        }
+        self.settled = Some(sizes);
+
+        let next = self.rules.iter().position(|rule| match rule.query.target {
+            UQueryTarget::Root => rule.query.matches(root_size),
+            UQueryTarget::Container => container_size.is_some_and(|size| rule.query.matches(size)),
+        });
+        if next == self.active {
+            return None;
+        }
+        self.settling = true;
+        Some(next)
+    }
+}
+
+/// Applies `UResponsive` overrides. Runs in `WidgetSync`, before the layout passes.
+pub fn apply_responsive_overrides(
+    mut nodes: Query<(Entity, &mut UResponsive, &mut ULayout, &mut UNode)>,
+    containers: Query<&ComputedSize, With<UQueryContainer>>,
+    units: UnitContextParam,
+) {
+    for (entity, mut responsive, layout, node) in nodes.iter_mut() {
+        // تغيير القواعد من المستخدم يفرض إعادة التقييم
+        let rules_changed = responsive.is_changed();
+        let state = responsive.bypass_change_detection();
+        if rules_changed {
+            state.settled = None;
+            state.settling = false;
+        }
+
+        let root_size = units.root_size(units.owning_root(entity));
+        let container_size = units
+            .parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| containers.get(ancestor).ok())
+            .map(ComputedSize::size);
+        let Some(next) = state.step(root_size, container_size) else {
+            continue;
+        };
+
+        let rule = next.map(|index| &state.rules[index]);
+        let (rule_layout, rule_node) = (rule.and_then(|r| r.layout.clone()), rule.and_then(|r| r.node.clone()));
+        switch_component(layout, &mut state.base_layout, rule_layout);
+        switch_component(node, &mut state.base_node, rule_node);
+        state.active = next;
+    }
+}
+
+/// Writes `value` over `live`, keeping the value it replaced in `base`, or
+/// brings `base` back once no rule overrides the component.
+fn switch_component<T: Clone>(mut live: Mut<T>, base: &mut Option<T>, value: Option<T>) {
+    match value {
+        Some(value) => {
+            base.get_or_insert_with(|| live.clone());
+            *live = value;
+        }
+        None => {
+            if let Some(base) = base.take() {
+                *live = base;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::UnivisLayoutPlugin;
+
+    fn column() -> ULayout {
+        ULayout { flex_direction: UFlexDirection::Column, ..default() }
+    }
+
+    #[test]
+    fn query_bounds_are_inclusive() {
+        let query = UQuery::root().min_width(400.0).max_width(600.0);
+
+        assert!(query.matches(Vec2::new(400.0, 0.0)));
+        assert!(query.matches(Vec2::new(600.0, 0.0)));
+        assert!(!query.matches(Vec2::new(601.0, 0.0)));
+        assert!(UQuery::root().matches(Vec2::ZERO));
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let mut responsive = UResponsive::new()
+            .with_rule(UResponsiveRule::layout(UQuery::root().max_width(400.0), column()))
+            .with_rule(UResponsiveRule::layout(UQuery::root().max_width(600.0), ULayout::default()));
+
+        assert_eq!(responsive.step(Vec2::new(350.0, 800.0), None), Some(Some(0)));
+        responsive.active = Some(0);
+        responsive.step(Vec2::new(350.0, 800.0), None);
+        assert_eq!(responsive.step(Vec2::new(500.0, 800.0), None), Some(Some(1)));
+    }
+
+    #[test]
+    fn container_switch_does_not_undo_itself() {
+        let mut responsive = UResponsive::new()
+            .with_rule(UResponsiveRule::layout(UQuery::container().max_width(300.0), column()));
+        let root = Vec2::new(1280.0, 720.0);
+
+        assert_eq!(responsive.step(root, Some(Vec2::new(280.0, 40.0))), Some(Some(0)));
+        responsive.active = Some(0);
+
+        // The column is wider than the row was: it settles instead of switching back.
+        assert_eq!(responsive.step(root, Some(Vec2::new(320.0, 120.0))), None);
+        assert_eq!(responsive.step(root, Some(Vec2::new(320.0, 120.0))), None);
+
+        // An outside change re-evaluates the rules.
+        assert_eq!(responsive.step(root, Some(Vec2::new(500.0, 120.0))), Some(None));
+    }
+
+    #[test]
+    fn container_queries_wait_for_a_solved_container() {
+        let mut responsive = UResponsive::new()
+            .with_rule(UResponsiveRule::layout(UQuery::container().max_width(300.0), column()));
+        let root = Vec2::new(1280.0, 720.0);
+
+        // Before the first layout the container is still 0x0.
+        assert_eq!(responsive.step(root, Some(Vec2::ZERO)), None);
+        assert!(responsive.settled.is_none());
+        assert_eq!(responsive.step(root, Some(Vec2::new(500.0, 100.0))), None);
+        assert_eq!(responsive.step(root, Some(Vec2::new(280.0, 100.0))), Some(Some(0)));
+    }
+
+    #[test]
+    fn overrides_follow_size_changes_across_frames() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        let root = app
+            .world_mut()
+            .spawn((
+                UWorldRoot { size: Vec2::new(800.0, 600.0), ..default() },
+                UNode { width: UVal::Px(800.0), height: UVal::Px(600.0), ..default() },
+            ))
+            .id();
+        let panel = app
+            .world_mut()
+            .spawn((UQueryContainer, UNode { width: UVal::Px(500.0), height: UVal::Px(100.0), ..default() }, ChildOf(root)))
+            .id();
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        let node = app
+            .world_mut()
+            .spawn((
+                UNode::default(),
+                UResponsive::new()
+                    .with_rule(UResponsiveRule::layout(UQuery::root().max_width(600.0), column()))
+                    .with_rule(UResponsiveRule::node(
+                        UQuery::container().max_width(300.0),
+                        UNode { background_color: red, ..default() },
+                    )),
+                ChildOf(panel),
+            ))
+            .id();
+        let frames = |app: &mut App| (0..3).for_each(|_| app.update());
+        let active = |app: &App| app.world().get::<UResponsive>(node).unwrap().active_rule();
+        frames(&mut app);
+        assert_eq!(active(&app), None);
+
+        app.world_mut().get_mut::<UWorldRoot>(root).unwrap().size.x = 500.0;
+        frames(&mut app);
+        assert_eq!(active(&app), Some(0));
+        assert_eq!(app.world().get::<ULayout>(node).unwrap().flex_direction, UFlexDirection::Column);
+
+        // The rule doesn't override `UNode`, so an edit made meanwhile survives the switch back.
+        app.world_mut().get_mut::<UNode>(node).unwrap().padding = USides::all(4.0);
+        app.world_mut().get_mut::<UWorldRoot>(root).unwrap().size.x = 800.0;
+        frames(&mut app);
+        assert_eq!(active(&app), None);
+        assert_eq!(app.world().get::<ULayout>(node).unwrap().flex_direction, UFlexDirection::Row);
+        assert_eq!(app.world().get::<UNode>(node).unwrap().padding, USides::all(4.0));
+
+        // Container queries measure the nearest `UQueryContainer` ancestor, not the node.
+        app.world_mut().get_mut::<UNode>(panel).unwrap().width = UVal::Px(250.0);
+        frames(&mut app);
+        assert_eq!(active(&app), Some(1));
+        assert_eq!(app.world().get::<UNode>(node).unwrap().background_color, red);
+
+        app.world_mut().get_mut::<UNode>(panel).unwrap().width = UVal::Px(500.0);
+        frames(&mut app);
+        assert_eq!(active(&app), None);
+        let restored = app.world().get::<UNode>(node).unwrap();
+        assert_eq!(restored.padding, USides::all(4.0));
+        assert_ne!(restored.background_color, red);
+    }
+}
@@ -0,0 +1,274 @@
+use bevy::prelude::*;
+use crate::internal_prelude::*;
+
+/// Scale of `UScreenRoot` trees and optional pixel snapping.
+///
+/// A screen root is laid out at `window size / root_scale` and drawn with that
+/// scale, so its content grows with `user_scale` while still filling the window.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct UiScaleSettings {
+    /// User preference (settings menu, accessibility).
+    pub user_scale: f32,
+    /// One UI pixel is one logical pixel, so it follows the window scale factor.
+    /// When `false`, one UI pixel is one physical pixel.
+    pub dpi_aware: bool,
+    /// Round node edges to physical pixels after the solve.
+    pub pixel_snapping: bool,
+}
+
+impl Default for UiScaleSettings {
+    fn default() -> Self {
+        Self {
+            user_scale: 1.0,
+            dpi_aware: true,
+            pixel_snapping: false,
+        }
+    }
+}
+
+impl UiScaleSettings {
+    /// World units (logical pixels) per UI pixel.
+    pub fn root_scale(&self, window_scale_factor: f32) -> f32 {
+        let dpi = if self.dpi_aware { 1.0 } else { 1.0 / window_scale_factor };
+        (self.user_scale * dpi).max(f32::EPSILON)
+    }
+
+    /// Physical pixels per UI pixel.
+    pub fn physical_scale(&self, window_scale_factor: f32) -> f32 {
+        self.root_scale(window_scale_factor) * window_scale_factor
+    }
+}
+
+/// Edges of a node after pixel snapping.
+///
+/// `ComputedSize` (with its `local_pos`) stays exact; `size` is what gets drawn
+/// and picked, and `translation` is written into `Transform`.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct PixelSnap {
+    pub size: Vec2,
+    pub translation: Vec2,
+}
+
+/// Translation a node is drawn at before snapping: animated, else solved plus
+/// its sticky offset.
+pub fn unsnapped_translation(
+    size: &ComputedSize,
+    transition: Option<&LayoutTransitionState>,
+    sticky: Option<&StickyOffset>,
+) -> Vec2 {
+    transition.map_or_else(|| size.placed_translation(sticky), |state| state.translation)
+}
+
+/// Size a node is drawn and picked at: snapped, else animated, else solved.
+pub fn drawn_size(size: &ComputedSize, transition: Option<&LayoutTransitionState>, snap: Option<&PixelSnap>) -> Vec2 {
+    snap.map(|snap| snap.size)
+        .or(transition.map(|state| state.size))
+        .unwrap_or(size.size())
+}
+
+/// Rounds an edge span `[start, start + length]` to the pixel grid.
+///
+/// Each edge is rounded on its own from its exact position, so errors never
+/// add up along a row of nodes.
+fn snap_span(start: f32, length: f32, pixels_per_unit: f32) -> (f32, f32) {
+    let first = (start * pixels_per_unit).round() / pixels_per_unit;
+    let last = ((start + length) * pixels_per_unit).round() / pixels_per_unit;
+    (first, last - first)
+}
+
+/// Keeps the `Transform` scale of every `UScreenRoot` on `UiScaleSettings`.
+pub fn apply_ui_scale(
+    settings: Res<UiScaleSettings>,
+    units: UnitContextParam,
+    mut roots: Query<(Entity, &mut Transform), With<UScreenRoot>>,
+) {
+    for (root, mut transform) in roots.iter_mut() {
+        let scale = settings.root_scale(units.scale_factor(root));
+        let target = Vec3::new(scale, scale, transform.scale.z);
+        if transform.scale != target {
+            transform.scale = target;
+        }
+    }
+}
+
+/// Data `snap_to_physical_pixels` reads and writes for each node.
+type SnapNodeData = (
+    &'static ComputedSize,
+    Option<&'static LayoutTransitionState>,
+    Option<&'static StickyOffset>,
+    Option<&'static mut PixelSnap>,
+    &'static mut Transform,
+);
+
+/// Snaps the nodes of `UScreenRoot` trees to physical pixels.
+///
+/// Positions are accumulated from the unsnapped translations (see
+/// [`unsnapped_translation`]), and each node's translation is rebuilt relative
+/// to its parent's snapped center.
+pub fn snap_to_physical_pixels(
+    mut commands: Commands,
+    settings: Res<UiScaleSettings>,
+    units: UnitContextParam,
+    roots: Query<(Entity, &ComputedSize), With<UScreenRoot>>,
+    children_query: Query<&Children>,
+    mut nodes: Query<SnapNodeData>,
+) {
+    if !settings.pixel_snapping {
+        for (size, transition, sticky, snap, mut transform) in nodes.iter_mut() {
+            if snap.is_none() {
+                continue;
+            }
+            let exact = unsnapped_translation(size, transition, sticky);
+            transform.translation.x = exact.x;
+            transform.translation.y = exact.y;
+        }
+        return;
+    }
+
+    for (root, root_size) in roots.iter() {
+        let pixels_per_unit = settings.physical_scale(units.scale_factor(root));
+        // (عقدة، مركزها الدقيق، مركزها بعد التقريب) بالنسبة لمركز الجذر
+        let mut stack = vec![(root, Vec2::ZERO, Vec2::ZERO)];
+        let half_root = root_size.size() / 2.0;
+        while let Some((parent, parent_exact, parent_snapped)) = stack.pop() {
+            let Ok(children) = children_query.get(parent) else { continue };
+            for child in children.iter() {
+                let Ok((size, transition, sticky, snap, mut transform)) = nodes.get_mut(child) else {
+                    continue;
+                };
+                let current = transform.translation.truncate();
+                let exact_center = parent_exact + unsnapped_translation(size, transition, sticky);
+                let size = transition.map_or(size.size(), |state| state.size);
+
+                // الحواف من الزاوية العليا اليسرى للجذر (y للأسفل)
+                let (left, width) = snap_span(exact_center.x - size.x / 2.0 + half_root.x, size.x, pixels_per_unit);
+                let (top, height) = snap_span(half_root.y - exact_center.y - size.y / 2.0, size.y, pixels_per_unit);
+                let snapped_center = Vec2::new(
+                    left + width / 2.0 - half_root.x,
+                    half_root.y - top - height / 2.0,
+                );
+
+                let next = PixelSnap {
+                    size: Vec2::new(width, height),
+                    translation: snapped_center - parent_snapped,
+                };
+                if current != next.translation {
+                    transform.translation.x = next.translation.x;
+                    transform.translation.y = next.translation.y;
+                }
+                match snap {
+                    Some(mut snap) => {
+                        snap.set_if_neq(next);
+                    }
+                    None => {
+                        commands.entity(child).insert(next);
+                    }
+                }
+                stack.push((child, exact_center, snapped_center));
+            }
+        }
+    }
+}
+
+/// Removes `PixelSnap` once snapping is turned off (after restoring translations).
+fn clear_pixel_snaps(
+    mut commands: Commands,
+    settings: Res<UiScaleSettings>,
+    snapped: Query<Entity, With<PixelSnap>>,
+) {
+    if settings.pixel_snapping {
+        return;
+    }
+    for entity in snapped.iter() {
+        commands.entity(entity).remove::<PixelSnap>();
+    }
+}
+
+/// Applies [`UiScaleSettings`] to screen roots after the solve.
+pub struct UiScalePlugin;
+
+impl Plugin for UiScalePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UiScaleSettings>()
+            .add_systems(
+                PostUpdate,
+                (apply_ui_scale, snap_to_physical_pixels, clear_pixel_snaps)
+                    .chain()
+                    .in_set(UnivisPostUpdateSet::RenderSync)
+                    .after(animate_layout_transitions)
+                    .before(TransformSystems::Propagate),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::UnivisLayoutPlugin;
+
+    #[test]
+    fn root_scale_combines_user_scale_and_dpi() {
+        let settings = UiScaleSettings { user_scale: 1.5, ..default() };
+        assert_eq!(settings.root_scale(2.0), 1.5);
+        assert_eq!(settings.physical_scale(2.0), 3.0);
+
+        let physical = UiScaleSettings { dpi_aware: false, ..default() };
+        assert_eq!(physical.root_scale(2.0), 0.5);
+        assert_eq!(physical.physical_scale(2.0), 1.0);
+    }
+
+    #[test]
+    fn snapping_rounds_each_edge_without_drift() {
+        // عشرة عناصر بعرض 10.4: كل حافة تبقى ضمن نصف بكسل من موضعها الدقيق
+        let mut right = 0.0;
+        for i in 0..10 {
+            let start = i as f32 * 10.4;
+            let (left, width) = snap_span(start, 10.4, 1.0);
+            assert_eq!(left, left.round());
+            assert_eq!(width, width.round());
+            assert!((left - start).abs() <= 0.5);
+            if i > 0 {
+                assert_eq!(left, right);
+            }
+            right = left + width;
+        }
+        assert_eq!(right, 104.0);
+
+        // بمعامل 2: أنصاف البكسل المنطقي
+        assert_eq!(snap_span(0.3, 1.0, 2.0), (0.5, 1.0));
+    }
+
+    #[test]
+    fn snapping_follows_a_new_layout_result_equal_to_the_last_snapped_one() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin, UiScalePlugin));
+        app.insert_resource(UiScaleSettings { pixel_snapping: true, ..default() });
+        let world = app.world_mut();
+        let root = world
+            .spawn((
+                UScreenRoot,
+                UNode { width: UVal::Px(800.0), height: UVal::Px(600.0), ..default() },
+                ULayout { flex_direction: UFlexDirection::Column, ..default() },
+            ))
+            .id();
+        let first = world
+            .spawn((UNode { width: UVal::Px(100.0), height: UVal::Px(10.6), ..default() }, ChildOf(root)))
+            .id();
+        let second = world
+            .spawn((UNode { width: UVal::Px(100.0), height: UVal::Px(10.0), ..default() }, ChildOf(root)))
+            .id();
+
+        // Edges 10.6..20.6 of the 600px root snap to 11..21.
+        app.update();
+        assert_eq!(app.world().get::<Transform>(second).unwrap().translation.y, 284.0);
+
+        // The new exact center is 284 again, but the edges are 10.5..21.5 now.
+        app.world_mut().get_mut::<UNode>(first).unwrap().height = UVal::Px(10.5);
+        app.world_mut().get_mut::<UNode>(second).unwrap().height = UVal::Px(11.0);
+        app.update();
+        assert_eq!(app.world().get::<ComputedSize>(second).unwrap().local_pos.y, 284.0);
+        assert_eq!(app.world().get::<Transform>(second).unwrap().translation.y, 283.5);
+        assert_eq!(app.world().get::<PixelSnap>(second).unwrap().size.y, 11.0);
+    }
+}
@@ -0,0 +1,386 @@
+use std::fmt;
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::internal_prelude::*;
+
+/// Set this environment variable to rewrite golden files instead of comparing.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UNIVIS_UPDATE_SNAPSHOTS";
+
+/// One laid-out node of a [`LayoutSnapshot`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SnapshotNode {
+    /// The entity `Name`, or `#index` among its siblings when it has none.
+    pub name: String,
+    pub depth: usize,
+    /// `ULayout::display`.
+    pub display: String,
+    /// Top-left corner in the parent's layout space (y down). Roots sit at zero.
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<SnapshotNode>,
+}
+
+/// A laid-out UI tree in a stable form, for golden-file tests.
+///
+/// Values are rounded to 0.01 so the file doesn't change with float noise.
+/// Entity ids are left out; nodes are identified by `Name` and sibling order.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct LayoutSnapshot {
+    pub roots: Vec<SnapshotNode>,
+}
+
+/// A difference between a snapshot and its golden file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotMismatch {
+    /// Node names from the root, joined with `/`.
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl LayoutSnapshot {
+    /// Captures every `UScreenRoot` / `UWorldRoot` tree of `world`, after layout ran.
+    pub fn capture(world: &mut World) -> Self {
+        let mut roots_query = world.query_filtered::<(Entity, Option<&Name>), Or<(With<UWorldRoot>, With<UScreenRoot>)>>();
+        let mut roots: Vec<(String, Entity)> = roots_query
+            .iter(world)
+            .map(|(entity, name)| (name.map(|n| n.to_string()).unwrap_or_default(), entity))
+            .collect();
+        // ترتيب ثابت: الاسم ثم ترتيب الإنشاء
+        roots.sort_by_key(|(name, entity)| (name.clone(), entity.index()));
+
+        Self {
+            roots: roots
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, (_, entity))| capture_node(world, entity, index, None))
+                .collect(),
+        }
+    }
+
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("layout snapshots only contain serializable values")
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(text)
+    }
+
+    /// Differences with `golden`; sizes and positions may differ by `tolerance`.
+    pub fn diff(&self, golden: &LayoutSnapshot, tolerance: f32) -> Vec<SnapshotMismatch> {
+        let mut mismatches = Vec::new();
+        diff_nodes(&self.roots, &golden.roots, "", tolerance, &mut mismatches);
+        mismatches
+    }
+
+    /// Compares with the golden file at `path`, panicking with every mismatch.
+    ///
+    /// When [`UPDATE_SNAPSHOTS_ENV`] is set the file is written instead. A
+    /// missing file fails, so a golden that was never committed can't pass.
+    pub fn assert_matches_golden(&self, path: impl AsRef<Path>, tolerance: f32) {
+        let path = path.as_ref();
+        if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).expect("create the snapshot directory");
+            }
+            std::fs::write(path, self.to_ron()).expect("write the golden file");
+            return;
+        }
+
+        assert!(
+            path.exists(),
+            "missing golden file {}: rerun with {UPDATE_SNAPSHOTS_ENV}=1 to write it",
+            path.display(),
+        );
+        let text = std::fs::read_to_string(path).expect("read the golden file");
+        let golden = Self::from_ron(&text)
+            .unwrap_or_else(|error| panic!("invalid golden file {}: {error}", path.display()));
+        let mismatches = self.diff(&golden, tolerance);
+        if !mismatches.is_empty() {
+            let lines: Vec<String> = mismatches.iter().map(|m| format!("  {m}")).collect();
+            panic!(
+                "layout differs from {}:\n{}\nrerun with {UPDATE_SNAPSHOTS_ENV}=1 to accept the new layout",
+                path.display(),
+                lines.join("\n"),
+            );
+        }
+    }
+}
+
+fn capture_node(world: &World, entity: Entity, index: usize, parent_size: Option<Vec2>) -> Option<SnapshotNode> {
+    let computed = world.get::<ComputedSize>(entity)?;
+    let size = computed.size();
+    // موضع التخطيط الدقيق، لا Transform الذي قد يحمل قيمة مرسومة (تقريب، انتقال، sticky)
+    let translation = computed.local_pos;
+    let position = match parent_size {
+        Some(parent) => Vec2::new(
+            parent.x / 2.0 + translation.x - size.x / 2.0,
+            parent.y / 2.0 - translation.y - size.y / 2.0,
+        ),
+        None => Vec2::ZERO,
+    };
+    let display = world.get::<ULayout>(entity).map_or(UDisplay::Flex, |layout| layout.display);
+
+    let children = world
+        .get::<Children>(entity)
+        .map(|children| {
+            children
+                .iter()
+                .filter(|child| world.get::<UNode>(*child).is_some())
+                .enumerate()
+                .filter_map(|(index, child)| capture_node(world, child, index, Some(size)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(SnapshotNode {
+        name: world.get::<Name>(entity).map_or_else(|| format!("#{index}"), |name| name.to_string()),
+        depth: world.get::<LayoutDepth>(entity).map_or(0, |depth| depth.0),
+        display: format!("{display:?}"),
+        x: stable(position.x),
+        y: stable(position.y),
+        width: stable(size.x),
+        height: stable(size.y),
+        children,
+    })
+}
+
+/// Rounds to 0.01 and turns `-0.0` into `0.0`.
+fn stable(value: f32) -> f32 {
+    (value * 100.0).round() / 100.0 + 0.0
+}
+
+fn diff_nodes(
+    actual: &[SnapshotNode],
+    golden: &[SnapshotNode],
+    parent_path: &str,
+    tolerance: f32,
+    mismatches: &mut Vec<SnapshotMismatch>,
+) {
+    for (index, golden_node) in golden.iter().enumerate() {
+        let path = format!("{parent_path}/{}", golden_node.name);
+        let Some(node) = actual.get(index) else {
+            mismatches.push(SnapshotMismatch { path, message: "missing".into() });
+            continue;
+        };
+        if node.name != golden_node.name {
+            mismatches.push(SnapshotMismatch { path, message: format!("found `{}` instead", node.name) });
+            continue;
+        }
+
+        let mut report = |message: String| mismatches.push(SnapshotMismatch { path: path.clone(), message });
+        if node.depth != golden_node.depth {
+            report(format!("depth {} (golden {})", node.depth, golden_node.depth));
+        }
+        if node.display != golden_node.display {
+            report(format!("display {} (golden {})", node.display, golden_node.display));
+        }
+        for (field, value, expected) in [
+            ("x", node.x, golden_node.x),
+            ("y", node.y, golden_node.y),
+            ("width", node.width, golden_node.width),
+            ("height", node.height, golden_node.height),
+        ] {
+            if (value - expected).abs() > tolerance {
+                report(format!("{field} {value} (golden {expected})"));
+            }
+        }
+
+        diff_nodes(&node.children, &golden_node.children, &path, tolerance, mismatches);
+    }
+
+    for extra in actual.iter().skip(golden.len()) {
+        mismatches.push(SnapshotMismatch {
+            path: format!("{parent_path}/{}", extra.name),
+            message: "not in the golden file".into(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::UnivisLayoutPlugin;
+    use crate::layout::univis_node::UnivisNodePlugin;
+
+    fn leaf(name: &str, x: f32, width: f32) -> SnapshotNode {
+        SnapshotNode {
+            name: name.into(),
+            depth: 1,
+            display: "Flex".into(),
+            x,
+            y: 0.0,
+            width,
+            height: 20.0,
+            children: Vec::new(),
+        }
+    }
+
+    fn snapshot(children: Vec<SnapshotNode>) -> LayoutSnapshot {
+        LayoutSnapshot {
+            roots: vec![SnapshotNode { children, ..leaf("root", 0.0, 200.0) }],
+        }
+    }
+
+    #[test]
+    fn ron_round_trip_keeps_the_tree() {
+        let snapshot = snapshot(vec![leaf("a", 0.0, 50.0), leaf("#1", 50.0, 30.0)]);
+
+        assert_eq!(LayoutSnapshot::from_ron(&snapshot.to_ron()).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn diff_reports_changes_beyond_tolerance() {
+        let golden = snapshot(vec![leaf("a", 0.0, 50.0), leaf("b", 50.0, 30.0)]);
+        let actual = snapshot(vec![leaf("a", 0.0, 50.4), leaf("b", 52.0, 30.0), leaf("c", 82.0, 10.0)]);
+
+        let mismatches: Vec<String> = actual.diff(&golden, 0.5).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            mismatches,
+            vec![
+                "/root/b: x 52 (golden 50)".to_string(),
+                "/root/c: not in the golden file".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_golden_file_fails() {
+        if std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+            return;
+        }
+        let path = std::env::temp_dir().join("univis_ui_missing_golden.ron");
+        let _ = std::fs::remove_file(&path);
+
+        let result = std::panic::catch_unwind(|| LayoutSnapshot::default().assert_matches_golden(&path, 0.5));
+
+        assert!(result.is_err());
+        assert!(!path.exists());
+    }
+
+    /// A reduced `layout_solver_ultra_complex` scene: nested flex rows and
+    /// columns, wrapping, padding and a grid strip.
+    fn spawn_dashboard(world: &mut World) {
+        let column = |name: &'static str, width: UVal| {
+            (
+                Name::new(name),
+                UNode { width, height: UVal::Percent(1.0), padding: USides::all(8.0), ..default() },
+                ULayout { flex_direction: UFlexDirection::Column, gap: 6.0, ..default() },
+            )
+        };
+
+        world
+            .spawn((
+                Name::new("root"),
+                UWorldRoot { size: Vec2::new(1280.0, 720.0), ..default() },
+                UNode {
+                    width: UVal::Percent(1.0),
+                    height: UVal::Percent(1.0),
+                    padding: USides::all(16.0),
+                    ..default()
+                },
+                ULayout { flex_direction: UFlexDirection::Column, gap: 12.0, ..default() },
+            ))
+            .with_children(|root| {
+                root.spawn((
+                    Name::new("stage"),
+                    UNode { width: UVal::Percent(1.0), height: UVal::Flex(1.0), ..default() },
+                    ULayout { gap: 10.0, ..default() },
+                ))
+                .with_children(|stage| {
+                    stage.spawn(column("left", UVal::Px(260.0))).with_children(|left| {
+                        for height in [40.0, 64.0, 32.0] {
+                            left.spawn(UNode { width: UVal::Percent(1.0), height: UVal::Px(height), ..default() });
+                        }
+                    });
+                    stage.spawn(column("center", UVal::Flex(1.0))).with_children(|center| {
+                        center
+                            .spawn((
+                                Name::new("chips"),
+                                UNode { width: UVal::Percent(1.0), ..default() },
+                                ULayout {
+                                    gap: 4.0,
+                                    container_ext: ULayoutContainerExt {
+                                        flex: ULayoutFlexContainer { wrap: UFlexWrap::Wrap, ..default() },
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
+                            ))
+                            .with_children(|chips| {
+                                for width in [120.0, 90.0, 150.0, 70.0, 200.0, 110.0] {
+                                    chips.spawn(UNode { width: UVal::Px(width), height: UVal::Px(24.0), ..default() });
+                                }
+                            });
+                        center.spawn(UNode {
+                            width: UVal::Percent(1.0),
+                            height: UVal::Flex(1.0),
+                            margin: USides::all(4.0),
+                            ..default()
+                        });
+                    });
+                    stage.spawn(column("right", UVal::Percent(0.2)));
+                });
+
+                root.spawn((
+                    Name::new("grid"),
+                    UNode { width: UVal::Percent(1.0), height: UVal::Px(140.0), padding: USides::all(6.0), ..default() },
+                    ULayout {
+                        display: UDisplay::Grid,
+                        grid_columns: 4,
+                        gap: 8.0,
+                        ..default()
+                    },
+                ))
+                .with_children(|grid| {
+                    for _ in 0..6 {
+                        grid.spawn(UNode { height: UVal::Px(56.0), ..default() });
+                    }
+                });
+            });
+    }
+
+    #[test]
+    fn dashboard_layout_matches_golden() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        spawn_dashboard(app.world_mut());
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let snapshot = LayoutSnapshot::capture(app.world_mut());
+        snapshot.assert_matches_golden(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots/dashboard.ron"),
+            0.5,
+        );
+    }
+
+    #[test]
+    fn capture_reads_the_layout_result_not_the_drawn_transform() {
+        let mut app = App::new();
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        spawn_dashboard(app.world_mut());
+        app.update();
+        let solved = LayoutSnapshot::capture(app.world_mut());
+
+        // Drawn variants (snapping, transitions, sticky) only touch `Transform`.
+        let world = app.world_mut();
+        for mut transform in world.query_filtered::<&mut Transform, With<UNode>>().iter_mut(world) {
+            transform.translation += Vec3::new(3.7, -1.2, 0.0);
+        }
+
+        assert_eq!(LayoutSnapshot::capture(app.world_mut()), solved);
+    }
+}
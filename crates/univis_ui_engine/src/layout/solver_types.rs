@@ -2,7 +2,8 @@ use bevy::prelude::*;
 
 use crate::layout::geometry::UVal;
 use crate::layout::univis_node::{
-    UAlignItemsExt, UAlignSelf, UAlignSelfExt, UContentAlignExt, UOverflowPosition, UPositionType,
+    UAlignItemsExt, UAlignSelf, UAlignSelfExt, UContentAlignExt, UGridLines, ULayoutCustomItem, UOverflowPosition,
+    UPositionType,
 };
 
 /// Represents the resolved sizing mode for the solver.
@@ -17,7 +18,7 @@ pub enum SolverSizeMode {
 }
 
 /// A normalized specification of a node's layout properties for the solver.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct SolverSpec {
     pub width_mode: SolverSizeMode,
     pub width_val: f32,
@@ -25,6 +26,22 @@ pub struct SolverSpec {
     pub height_mode: SolverSizeMode,
     pub height_val: f32,
     pub height_flex: f32,
+    pub min_width: UVal,
+    pub max_width: UVal,
+    pub min_height: UVal,
+    pub max_height: UVal,
+    /// Intrinsic (max-content) size.
+    pub content_width: f32,
+    pub content_height: f32,
+    /// Min-content size (see `IntrinsicSize`), used for the automatic minimum
+    /// of flex items.
+    pub min_content_width: f32,
+    pub min_content_height: f32,
+    /// Baseline offsets from the top edge (see `UBaseline`).
+    pub first_baseline: Option<f32>,
+    pub last_baseline: Option<f32>,
+    /// Preferred `width / height` ratio (always finite and positive when set).
+    pub aspect_ratio: Option<f32>,
     pub position_type: UPositionType,
     pub left: UVal,
     pub right: UVal,
@@ -42,6 +59,13 @@ pub struct SolverSpec {
     pub grid_column_span: u32,
     pub grid_row_start: Option<u32>,
     pub grid_row_span: u32,
+    pub grid_area: Option<String>,
+    pub grid_column_lines: UGridLines,
+    pub grid_row_lines: UGridLines,
+    /// Fixed radial angle in degrees (see `ULayoutRadialItem`).
+    pub radial_angle: Option<f32>,
+    /// Values for custom placers.
+    pub custom: ULayoutCustomItem,
     pub order: i32,
 }
 
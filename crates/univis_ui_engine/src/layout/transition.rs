@@ -0,0 +1,228 @@
+use bevy::math::curve::{Curve, EaseFunction};
+use bevy::prelude::*;
+use crate::internal_prelude::*;
+
+/// Animates where a node is drawn when a relayout moves or resizes it.
+///
+/// Layout stays exact: `ComputedSize` and the children's placement hold the
+/// new result at once, while the drawn translation and size (see
+/// [`LayoutTransitionState`]) ease from the old rect to the new one.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ULayoutTransition {
+    /// Duration in seconds. Zero or less snaps.
+    pub duration: f32,
+    pub easing: EaseFunction,
+}
+
+impl Default for ULayoutTransition {
+    fn default() -> Self {
+        Self {
+            duration: 0.2,
+            easing: EaseFunction::CubicOut,
+        }
+    }
+}
+
+impl ULayoutTransition {
+    pub fn new(duration: f32, easing: EaseFunction) -> Self {
+        Self { duration, easing }
+    }
+}
+
+/// The rect a [`ULayoutTransition`] node is drawn and picked at this frame.
+///
+/// Inserted automatically. `translation` is also written into `Transform`
+/// (through `PixelSnap` when pixel snapping is on).
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct LayoutTransitionState {
+    pub translation: Vec2,
+    pub size: Vec2,
+    from_translation: Vec2,
+    from_size: Vec2,
+    target_translation: Vec2,
+    target_size: Vec2,
+    elapsed: f32,
+    running: bool,
+}
+
+impl LayoutTransitionState {
+    fn settled(translation: Vec2, size: Vec2) -> Self {
+        Self {
+            translation,
+            size,
+            from_translation: translation,
+            from_size: size,
+            target_translation: translation,
+            target_size: size,
+            elapsed: 0.0,
+            running: false,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Moves toward the latest layout result (`ComputedSize` and where it places the node).
+    fn advance(&mut self, target_translation: Vec2, layout_size: Vec2, transition: &ULayoutTransition, delta: f32) {
+        if target_translation != self.target_translation || layout_size != self.target_size {
+            // عقدة ظهرت للتو (بلا حجم سابق) تأخذ مكانها مباشرة
+            let appearing = self.target_size.cmple(Vec2::ZERO).any();
+            if appearing || transition.duration <= 0.0 {
+                *self = Self::settled(target_translation, layout_size);
+                return;
+            }
+            self.from_translation = self.translation;
+            self.from_size = self.size;
+            self.target_translation = target_translation;
+            self.target_size = layout_size;
+            self.elapsed = 0.0;
+            self.running = true;
+        }
+
+        if !self.running {
+            return;
+        }
+        self.elapsed += delta;
+        let t = (self.elapsed / transition.duration).min(1.0);
+        if t >= 1.0 {
+            *self = Self::settled(self.target_translation, self.target_size);
+            return;
+        }
+        let eased = transition.easing.sample_clamped(t);
+        self.translation = self.from_translation.lerp(self.target_translation, eased);
+        self.size = self.from_size.lerp(self.target_size, eased);
+    }
+}
+
+/// Starts tracking new `ULayoutTransition` nodes at their current rect.
+fn init_transition_state(
+    mut commands: Commands,
+    nodes: Query<(Entity, &ComputedSize, Option<&StickyOffset>), Added<ULayoutTransition>>,
+) {
+    for (entity, size, sticky) in nodes.iter() {
+        commands
+            .entity(entity)
+            .insert(LayoutTransitionState::settled(size.placed_translation(sticky), size.size()));
+    }
+}
+
+/// Data `animate_layout_transitions` reads and writes for each node.
+type TransitionNodeData = (
+    &'static ULayoutTransition,
+    &'static ComputedSize,
+    Option<&'static StickyOffset>,
+    &'static mut Transform,
+    &'static mut LayoutTransitionState,
+    Has<PixelSnap>,
+);
+
+/// Eases `ULayoutTransition` nodes toward their solved rect, sticky offset
+/// included (see [`ComputedSize::placed_translation`]).
+pub fn animate_layout_transitions(time: Res<Time>, mut nodes: Query<TransitionNodeData>) {
+    let delta = time.delta_secs();
+    for (transition, size, sticky, mut transform, mut state, snapped) in nodes.iter_mut() {
+        let mut next = *state;
+        next.advance(size.placed_translation(sticky), size.size(), transition, delta);
+        state.set_if_neq(next);
+        // التقريب يكتب Transform من الحالة المرسومة
+        if !snapped && transform.translation.truncate() != next.translation {
+            transform.translation.x = next.translation.x;
+            transform.translation.y = next.translation.y;
+        }
+    }
+}
+
+/// Drops the drawn rect once a node no longer transitions.
+fn remove_transition_state(
+    mut commands: Commands,
+    mut removed: RemovedComponents<ULayoutTransition>,
+) {
+    for entity in removed.read() {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<LayoutTransitionState>();
+        }
+    }
+}
+
+/// Runs [`ULayoutTransition`] animations after the solve.
+pub struct LayoutTransitionPlugin;
+
+impl Plugin for LayoutTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_type::<ULayoutTransition>()
+            .add_systems(
+                PostUpdate,
+                (remove_transition_state, init_transition_state, animate_layout_transitions)
+                    .chain()
+                    .in_set(UnivisPostUpdateSet::RenderSync)
+                    .before(TransformSystems::Propagate),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear(duration: f32) -> ULayoutTransition {
+        ULayoutTransition::new(duration, EaseFunction::Linear)
+    }
+
+    #[test]
+    fn new_layout_result_eases_from_the_drawn_rect() {
+        let transition = linear(1.0);
+        let mut state = LayoutTransitionState::settled(Vec2::ZERO, Vec2::splat(10.0));
+
+        state.advance(Vec2::new(100.0, 0.0), Vec2::splat(30.0), &transition, 0.5);
+        assert!(state.is_running());
+        assert_eq!(state.translation, Vec2::new(50.0, 0.0));
+        assert_eq!(state.size, Vec2::splat(20.0));
+
+        // نتيجة الحل لم تتغير
+        state.advance(Vec2::new(100.0, 0.0), Vec2::splat(30.0), &transition, 0.25);
+        assert_eq!(state.translation, Vec2::new(75.0, 0.0));
+
+        state.advance(Vec2::new(100.0, 0.0), Vec2::splat(30.0), &transition, 0.5);
+        assert!(!state.is_running());
+        assert_eq!(state.translation, Vec2::new(100.0, 0.0));
+        assert_eq!(state.size, Vec2::splat(30.0));
+    }
+
+    #[test]
+    fn retarget_starts_from_the_current_drawn_rect() {
+        let transition = linear(1.0);
+        let mut state = LayoutTransitionState::settled(Vec2::ZERO, Vec2::splat(10.0));
+        state.advance(Vec2::new(100.0, 0.0), Vec2::splat(10.0), &transition, 0.5);
+
+        state.advance(Vec2::new(0.0, 100.0), Vec2::splat(10.0), &transition, 0.5);
+        assert_eq!(state.translation, Vec2::new(25.0, 50.0));
+    }
+
+    #[test]
+    fn new_target_equal_to_the_drawn_translation_is_kept() {
+        let transition = linear(1.0);
+        let mut state = LayoutTransitionState::settled(Vec2::ZERO, Vec2::splat(10.0));
+        state.advance(Vec2::new(100.0, 0.0), Vec2::splat(10.0), &transition, 0.5);
+        assert_eq!(state.translation, Vec2::new(50.0, 0.0));
+
+        state.advance(Vec2::new(50.0, 0.0), Vec2::splat(10.0), &transition, 0.25);
+        assert_eq!(state.translation, Vec2::new(50.0, 0.0));
+        state.advance(Vec2::new(50.0, 0.0), Vec2::splat(10.0), &transition, 1.0);
+        assert_eq!(state, LayoutTransitionState::settled(Vec2::new(50.0, 0.0), Vec2::splat(10.0)));
+    }
+
+    #[test]
+    fn appearing_nodes_and_zero_durations_snap() {
+        let mut appearing = LayoutTransitionState::settled(Vec2::ZERO, Vec2::ZERO);
+        appearing.advance(Vec2::new(40.0, 0.0), Vec2::splat(20.0), &linear(1.0), 0.1);
+        assert_eq!(appearing, LayoutTransitionState::settled(Vec2::new(40.0, 0.0), Vec2::splat(20.0)));
+
+        let mut instant = LayoutTransitionState::settled(Vec2::ZERO, Vec2::splat(10.0));
+        instant.advance(Vec2::new(40.0, 0.0), Vec2::splat(20.0), &linear(0.0), 0.1);
+        assert!(!instant.is_running());
+        assert_eq!(instant.translation, Vec2::new(40.0, 0.0));
+    }
+}
@@ -7,6 +7,8 @@ impl Plugin for UnivisNodePlugin {
     fn build(&self, app: &mut App) {
         app
             .register_type::<UVal>()
+            .register_type::<UCalc>()
+            .register_type::<UCalcSum>()
             .register_type::<ULayout>()
             .register_type::<UNode>()
             .register_type::<ComputedSize>()
@@ -24,7 +26,26 @@ impl Plugin for UnivisNodePlugin {
             .register_type::<UOverflowPosition>()
             .register_type::<UFlexWrap>()
             .register_type::<UTrackSize>()
-            .register_type::<UGridAutoFlow>();
+            .register_type::<UTrackBreadth>()
+            .register_type::<UGridRepeat>()
+            .register_type::<UGridAutoFlow>()
+            .register_type::<ULayoutCustomItem>()
+            .register_type::<ULayoutMasonryContainer>()
+            .register_type::<UMasonryFlow>()
+            .register_type::<UMasonryOrder>()
+            .register_type::<URadialLayout>()
+            .register_type::<URadialDirection>()
+            .register_type::<ULayoutRadialItem>()
+            .register_type::<UGridLine>()
+            .register_type::<UGridLines>()
+            .register_type::<UGridLineName>()
+            .register_type::<UGridPlacement>()
+            .register_type::<UOverflow>()
+            .register_type::<UDirection>()
+            .register_type::<ComputedDirection>()
+            .register_type::<ScrollableOverflow>()
+            .register_type::<StickyOffset>()
+            .register_type::<ComputedGridTracks>();
     }
 }
 
@@ -38,12 +59,24 @@ pub enum UShapeMode {
 /// The core component for any UI node.
 /// Defines sizing, spacing, appearance (color/radius), and layout behavior.
 #[derive(Component, Clone, Reflect)]
-#[require(Transform, Visibility, ComputedSize, ULayout, IntrinsicSize)] 
+#[require(Transform, Visibility, ComputedSize, ScrollableOverflow, ComputedDirection, ULayout, IntrinsicSize, UBaseline)] 
 pub struct UNode {
     /// Preferred width of the node.
     pub width: UVal,
     /// Preferred height of the node.
     pub height: UVal,
+
+    /// Lower bound for the resolved width. `UVal::Auto` means no explicit minimum.
+    pub min_width: UVal,
+    /// Upper bound for the resolved width. `UVal::Auto` means no maximum.
+    pub max_width: UVal,
+    /// Lower bound for the resolved height. `UVal::Auto` means no explicit minimum.
+    pub min_height: UVal,
+    /// Upper bound for the resolved height. `UVal::Auto` means no maximum.
+    pub max_height: UVal,
+    /// Preferred `width / height` ratio. When one axis is resolved (fixed,
+    /// percent, flex or stretched) the other one is derived from it.
+    pub aspect_ratio: Option<f32>,
     
     /// Inner spacing (affects children placement).
     pub padding: USides,
@@ -63,6 +96,11 @@ impl Default for UNode {
         Self {
             width: UVal::Content,
             height: UVal::Content,
+            min_width: UVal::Auto,
+            max_width: UVal::Auto,
+            min_height: UVal::Auto,
+            max_height: UVal::Auto,
+            aspect_ratio: None,
             padding: USides::default(),
             margin: USides::default(),
             background_color: Color::NONE,
@@ -72,6 +110,51 @@ impl Default for UNode {
     }
 }
 
+impl UNode {
+    /// Returns a copy whose sizes and limits only use solver units
+    /// (see [`UVal::normalize`]). `base` is the parent content size.
+    pub fn resolve_units(&self, base: Vec2, ctx: &UUnitContext) -> UNode {
+        UNode {
+            width: self.width.normalize(base.x, ctx),
+            height: self.height.normalize(base.y, ctx),
+            min_width: self.min_width.normalize(base.x, ctx),
+            max_width: self.max_width.normalize(base.x, ctx),
+            min_height: self.min_height.normalize(base.y, ctx),
+            max_height: self.max_height.normalize(base.y, ctx),
+            ..self.clone()
+        }
+    }
+
+    /// `true` when both nodes have the same sizes, limits and spacing.
+    ///
+    /// Paint properties (`background_color`, `border_radius`, `shape_mode`) are
+    /// ignored: changing them only updates the material.
+    pub fn layout_eq(&self, other: &UNode) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.min_width == other.min_width
+            && self.max_width == other.max_width
+            && self.min_height == other.min_height
+            && self.max_height == other.max_height
+            && self.aspect_ratio == other.aspect_ratio
+            && self.padding == other.padding
+            && self.margin == other.margin
+    }
+
+    /// `true` when the measured size can't change with the children, so layout
+    /// changes inside stop here instead of dirtying the ancestors.
+    ///
+    /// Each axis must be `Px`, or be a scroll container that isn't content-sized
+    /// (a scroll viewport); such axes ignore the children when measured.
+    pub fn is_relayout_boundary(&self, layout: &ULayout) -> bool {
+        let independent = |size: UVal, scrolls: bool| {
+            matches!(size, UVal::Px(_)) || (scrolls && !matches!(size, UVal::Content | UVal::Auto))
+        };
+        independent(self.width, layout.overflow_x.is_scroll_container())
+            && independent(self.height, layout.overflow_y.is_scroll_container())
+    }
+}
+
 /// Defines a visual border around a node.
 #[derive(Component, Clone)]
 pub struct UBorder {
@@ -113,6 +196,11 @@ pub struct ULayout {
     /// Number of columns (used for Grid/Masonry layouts).
     pub grid_columns: u32,
 
+    /// What happens to children that overflow horizontally.
+    pub overflow_x: UOverflow,
+    /// What happens to children that overflow vertically.
+    pub overflow_y: UOverflow,
+
     /// Advanced container-only layout controls.
     pub container_ext: ULayoutContainerExt,
 }
@@ -126,11 +214,62 @@ impl Default for ULayout {
             align_items: UAlignItems::Start,
             gap: 0.0,
             grid_columns: 1, // Default is one column
+            overflow_x: UOverflow::Visible,
+            overflow_y: UOverflow::Visible,
             container_ext: ULayoutContainerExt::default(),
         }
     }
 }
 
+impl ULayout {
+    /// Direction the solver lays children out in. Masonry takes it from its flow.
+    pub fn solver_direction(&self) -> UFlexDirection {
+        match self.display {
+            UDisplay::Masonry => self.container_ext.masonry.flow.direction(),
+            _ => self.flex_direction,
+        }
+    }
+
+    /// `true` when either axis hides its overflow.
+    pub fn clips_overflow(&self) -> bool {
+        self.overflow_x.clips() || self.overflow_y.clips()
+    }
+}
+
+/// Overflow behavior of a container axis.
+///
+/// The extent of the content is reported in `ScrollableOverflow` for every mode.
+/// Each axis is clipped on its own, so `overflow_x: Clip` alone still lets
+/// children spill out above and below the box.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+pub enum UOverflow {
+    /// Children are drawn and picked outside the box.
+    #[default]
+    Visible,
+    /// Children are clipped to the box; the box still grows with its content
+    /// and is never scrolled.
+    Clip,
+    /// A scroll container: clipped, sized without its content, and scroll
+    /// widgets move the content on user input.
+    Scroll,
+    /// Like `Scroll`, but user input is ignored; the content can only be
+    /// moved from code.
+    Hidden,
+}
+
+impl UOverflow {
+    /// `true` when children are clipped on this axis.
+    pub fn clips(self) -> bool {
+        self != UOverflow::Visible
+    }
+
+    /// `true` for `Scroll` and `Hidden`: the axis does not take its size from
+    /// its content.
+    pub fn is_scroll_container(self) -> bool {
+        matches!(self, UOverflow::Scroll | UOverflow::Hidden)
+    }
+}
+
 /// Alignment options for layout (Standard CSS-like).
 #[derive(Clone, Copy, PartialEq, Debug, Default, Reflect)]
 pub enum LayoutAlign {
@@ -195,6 +334,8 @@ pub enum UDisplay {
     Radial,
     Masonry,
     None,
+    /// A placer registered with `register_layout_placer` under this id.
+    Custom(&'static str),
 }
 
 /// CSS-inspired extended alignment values for self alignment.
@@ -218,6 +359,17 @@ pub enum UAlignSelfExt {
     Right,
 }
 
+impl UAlignSelfExt {
+    /// Swaps `Left` and `Right`.
+    pub fn mirrored(self) -> Self {
+        match self {
+            UAlignSelfExt::Left => UAlignSelfExt::Right,
+            UAlignSelfExt::Right => UAlignSelfExt::Left,
+            other => other,
+        }
+    }
+}
+
 /// CSS-inspired extended alignment values for container item alignment.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
 pub enum UAlignItemsExt {
@@ -238,6 +390,17 @@ pub enum UAlignItemsExt {
     Right,
 }
 
+impl UAlignItemsExt {
+    /// Swaps `Left` and `Right`.
+    pub fn mirrored(self) -> Self {
+        match self {
+            UAlignItemsExt::Left => UAlignItemsExt::Right,
+            UAlignItemsExt::Right => UAlignItemsExt::Left,
+            other => other,
+        }
+    }
+}
+
 /// CSS-inspired extended alignment values for distributing lines/tracks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
 pub enum UContentAlignExt {
@@ -278,6 +441,58 @@ pub enum UTrackSize {
     Fr(f32),
     #[default]
     Auto,
+    /// Largest minimum contribution of the items in the track.
+    MinContent,
+    /// Largest content contribution of the items in the track.
+    MaxContent,
+    /// `minmax(min, max)`.
+    MinMax(UTrackBreadth, UTrackBreadth),
+    /// `fit-content(limit)`: content size, but no larger than `limit` pixels.
+    FitContent(f32),
+    /// `repeat(count, minmax(min, max))`. Build it with [`UTrackSize::repeat`].
+    Repeat(UGridRepeat, UTrackBreadth, UTrackBreadth),
+}
+
+impl UTrackSize {
+    /// `repeat(count, track)`. A nested `FitContent` repeats as `minmax(auto, max-content)`.
+    pub fn repeat(count: UGridRepeat, track: UTrackSize) -> Self {
+        let (min, max) = track.min_max();
+        UTrackSize::Repeat(count, min, max)
+    }
+
+    /// The track as a `minmax(min, max)` pair.
+    pub fn min_max(&self) -> (UTrackBreadth, UTrackBreadth) {
+        match *self {
+            UTrackSize::Px(v) => (UTrackBreadth::Px(v), UTrackBreadth::Px(v)),
+            UTrackSize::Fr(f) => (UTrackBreadth::Auto, UTrackBreadth::Fr(f)),
+            UTrackSize::Auto => (UTrackBreadth::Auto, UTrackBreadth::Auto),
+            UTrackSize::MinContent => (UTrackBreadth::MinContent, UTrackBreadth::MinContent),
+            UTrackSize::MaxContent => (UTrackBreadth::MaxContent, UTrackBreadth::MaxContent),
+            UTrackSize::FitContent(_) => (UTrackBreadth::Auto, UTrackBreadth::MaxContent),
+            UTrackSize::MinMax(min, max) | UTrackSize::Repeat(_, min, max) => (min, max),
+        }
+    }
+}
+
+/// One side of a `minmax()` track. `Fr` is only meaningful as the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Default)]
+pub enum UTrackBreadth {
+    Px(f32),
+    Fr(f32),
+    #[default]
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+/// Repetition count of [`UTrackSize::Repeat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum UGridRepeat {
+    Count(u32),
+    /// As many tracks as fit the container.
+    AutoFill,
+    /// Like `AutoFill`, but tracks without items collapse to zero.
+    AutoFit,
 }
 
 /// Grid auto-placement flow.
@@ -285,7 +500,64 @@ pub enum UTrackSize {
 pub enum UGridAutoFlow {
     #[default]
     Row,
+    /// Fills each column down to the explicit row count, then moves to the next one.
     Column,
+    /// Like `Row`, but each auto item searches from the first cell and backfills earlier holes.
+    RowDense,
+    /// Like `Column`, but each auto item searches from the first cell and backfills earlier holes.
+    ColumnDense,
+}
+
+/// A grid line reference used by [`UGridLines`].
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, Default)]
+pub enum UGridLine {
+    #[default]
+    Auto,
+    /// 1-based line number. Negative numbers count back from the last explicit line (`-1`).
+    Index(i32),
+    /// A line from `column_line_names`/`row_line_names`, or an area's `<name>-start`/`<name>-end`.
+    /// A bare area name resolves to the area's start or end line.
+    Named(String),
+    /// Spans this many tracks from the other edge.
+    Span(u32),
+    /// Spans up to the last explicit line (end edge only).
+    SpanToEnd,
+}
+
+/// Start/end placement on one grid axis, like CSS `grid-column` / `grid-row`.
+#[derive(Debug, Clone, PartialEq, Eq, Reflect, Default)]
+pub struct UGridLines {
+    pub start: UGridLine,
+    pub end: UGridLine,
+}
+
+impl UGridLine {
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::Named(name.into())
+    }
+}
+
+impl UGridLines {
+    pub fn new(start: UGridLine, end: UGridLine) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_auto(&self) -> bool {
+        self.start == UGridLine::Auto && self.end == UGridLine::Auto
+    }
+}
+
+/// A name attached to a grid line (1-based), like CSS `[name]` in a track list.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct UGridLineName {
+    pub name: String,
+    pub line: u32,
+}
+
+impl UGridLineName {
+    pub fn new(name: impl Into<String>, line: u32) -> Self {
+        Self { name: name.into(), line }
+    }
 }
 
 /// Advanced container-only controls nested under [`ULayout`].
@@ -294,6 +566,8 @@ pub struct ULayoutContainerExt {
     pub box_align: ULayoutBoxAlignContainer,
     pub flex: ULayoutFlexContainer,
     pub grid: ULayoutGridContainer,
+    pub masonry: ULayoutMasonryContainer,
+    pub radial: URadialLayout,
 }
 
 /// Extended container-level alignment options.
@@ -340,6 +614,11 @@ pub struct ULayoutGridContainer {
     pub auto_flow: UGridAutoFlow,
     pub auto_rows: UTrackSize,
     pub auto_columns: UTrackSize,
+    /// Named areas, one string per row, e.g. `["header header", "sidebar main"]`.
+    /// `.` marks an unnamed cell.
+    pub template_areas: Vec<String>,
+    pub column_line_names: Vec<UGridLineName>,
+    pub row_line_names: Vec<UGridLineName>,
 }
 
 impl Default for ULayoutGridContainer {
@@ -350,16 +629,113 @@ impl Default for ULayoutGridContainer {
             auto_flow: UGridAutoFlow::Row,
             auto_rows: UTrackSize::Auto,
             auto_columns: UTrackSize::Auto,
+            template_areas: Vec::new(),
+            column_line_names: Vec::new(),
+            row_line_names: Vec::new(),
         }
     }
 }
 
+/// Extended masonry container options.
+#[derive(Debug, Clone, Copy, Reflect, Default)]
+pub struct ULayoutMasonryContainer {
+    /// Minimum lane size (column width, or row height for `Horizontal`).
+    /// When set, the lane count follows the container size and `grid_columns` is ignored.
+    pub min_column_width: Option<f32>,
+    pub flow: UMasonryFlow,
+    pub order: UMasonryOrder,
+}
+
+/// Direction in which masonry lanes grow. Masonry ignores `flex_direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+pub enum UMasonryFlow {
+    /// Fixed-width columns of varying height.
+    #[default]
+    Vertical,
+    /// Fixed-height rows of varying width.
+    Horizontal,
+}
+
+impl UMasonryFlow {
+    /// Solver direction whose main axis runs across the lanes.
+    pub fn direction(self) -> UFlexDirection {
+        match self {
+            UMasonryFlow::Vertical => UFlexDirection::Row,
+            UMasonryFlow::Horizontal => UFlexDirection::Column,
+        }
+    }
+}
+
+/// How masonry items pick a lane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+pub enum UMasonryOrder {
+    /// Each item goes to the currently shortest lane.
+    #[default]
+    ShortestFirst,
+    /// Items fill lanes round-robin, keeping source order across each row.
+    SourceOrder,
+}
+
+/// Radial container options (see [`UDisplay::Radial`]).
+///
+/// Angles are in degrees, measured clockwise from the top of the container.
+#[derive(Debug, Clone, Copy, Reflect)]
+pub struct URadialLayout {
+    /// Angle of the first item.
+    pub start_angle: f32,
+    /// Arc covered by the items. A full circle (`360.0`) spaces them evenly;
+    /// a smaller arc puts the first and last item on its ends.
+    pub sweep_angle: f32,
+    /// Distance from the center to each item's center. `Percent` is relative to
+    /// half the smaller side of the content box; `Auto` fits the container.
+    pub radius: UVal,
+    pub direction: URadialDirection,
+    /// Rotates each child so its top edge faces away from the center.
+    pub rotate_children: bool,
+}
+
+impl Default for URadialLayout {
+    fn default() -> Self {
+        Self {
+            start_angle: 0.0,
+            sweep_angle: 360.0,
+            radius: UVal::Auto,
+            direction: URadialDirection::Clockwise,
+            rotate_children: false,
+        }
+    }
+}
+
+/// Direction in which radial items follow each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+pub enum URadialDirection {
+    #[default]
+    Clockwise,
+    CounterClockwise,
+}
+
 /// Advanced item-only controls nested under [`USelf`].
 #[derive(Debug, Clone, Copy, Reflect, Default)]
 pub struct ULayoutItemExt {
     pub box_align: ULayoutBoxAlignSelf,
     pub flex: ULayoutFlexItem,
     pub grid: ULayoutGridItem,
+    pub radial: ULayoutRadialItem,
+    pub custom: ULayoutCustomItem,
+}
+
+/// Extended radial item options.
+#[derive(Debug, Clone, Copy, Reflect, Default)]
+pub struct ULayoutRadialItem {
+    /// Fixed angle for this item (degrees, clockwise from the top). Items
+    /// without one share the container's arc.
+    pub angle: Option<f32>,
+}
+
+/// Free-form per-item values for custom placers (see [`UDisplay::Custom`]).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Default)]
+pub struct ULayoutCustomItem {
+    pub params: Vec4,
 }
 
 /// Extended child-level alignment options.
@@ -420,6 +796,28 @@ impl Default for ULayoutGridItem {
     }
 }
 
+/// Named grid placement for a child node.
+///
+/// Kept apart from [`USelf`] so the item options stay `Copy`. When present it
+/// overrides `ULayoutGridItem` on the axes it sets.
+#[derive(Component, Debug, Clone, PartialEq, Reflect, Default)]
+#[reflect(Component)]
+pub struct UGridPlacement {
+    /// Places the item in a named area of `template_areas`.
+    pub area: Option<String>,
+    /// Line-based column placement; overrides `column_start`/`column_span` when set.
+    pub column: UGridLines,
+    /// Line-based row placement; overrides `row_start`/`row_span` when set.
+    pub row: UGridLines,
+}
+
+impl UGridPlacement {
+    /// Placement in the named area of the parent's `template_areas`.
+    pub fn area(name: impl Into<String>) -> Self {
+        Self { area: Some(name.into()), ..Self::default() }
+    }
+}
+
 /// Self-control component for a child node.
 /// Overrides parent settings (Alignment) or Layout flow (Positioning).
 #[derive(Component, Debug, Clone, Copy, Reflect)]
@@ -428,12 +826,12 @@ pub struct USelf {
     /// Self alignment overriding parent's `align_items`.
     pub align_self: UAlignSelf,
     
-    /// Positioning offsets (Works for Relative and Absolute).
+    /// Positioning offsets (Works for Relative, Absolute and Fixed).
     pub left: UVal,    
     pub top: UVal,    
     pub bottom: UVal,    
     pub right: UVal,    
-    /// Layout order (affects Z-index too).
+    /// Layout order. Draw order is controlled by `UZIndex`.
     pub order: i32,
     pub position_type: UPositionType,
     /// Advanced item-only layout controls.
@@ -455,6 +853,18 @@ impl Default for USelf {
 }
 
 impl USelf {
+    /// Returns a copy whose offsets and flex basis only use solver units.
+    /// `base` is the parent size and `main_base` the parent's main-axis content size.
+    pub fn resolve_units(&self, base: Vec2, main_base: f32, ctx: &UUnitContext) -> USelf {
+        let mut resolved = *self;
+        resolved.left = self.left.normalize(base.x, ctx);
+        resolved.right = self.right.normalize(base.x, ctx);
+        resolved.top = self.top.normalize(base.y, ctx);
+        resolved.bottom = self.bottom.normalize(base.y, ctx);
+        resolved.item_ext.flex.flex_basis = self.item_ext.flex.flex_basis.map(|b| b.normalize(main_base, ctx));
+        resolved
+    }
+
     pub fn get_val(&self) -> f32 {
         match &self.left {
             UVal::Px(p) => *p,
@@ -485,8 +895,55 @@ pub struct UPosition {
 pub enum UPositionType {
     Relative, // In-flow
     Absolute, // Out-of-flow
+    /// In-flow like `Relative`, but `top`/`left` are thresholds: inside a scroll
+    /// container the node sticks to the viewport edge until its parent scrolls out.
+    Sticky,
+    /// Out-of-flow like `Absolute`, but sized and offset against the layout root
+    /// (`UScreenRoot` / `UWorldRoot`) whatever its ancestors are.
+    /// Ancestor rotation and scale are not compensated.
+    Fixed,
+}
+
+impl UPositionType {
+    /// `true` for items that don't take space in their parent's flow.
+    pub fn is_out_of_flow(self) -> bool {
+        matches!(self, UPositionType::Absolute | UPositionType::Fixed)
+    }
+}
+
+/// Inline direction of a subtree. Nodes without it inherit their parent's.
+///
+/// In `Rtl`, in-flow children are mirrored inside the content box: `Row`
+/// starts on the right, `Start`/`End` swap sides, grid columns run right to
+/// left, and `USides.left`/`right` of padding and margins act as start/end.
+/// `Left`/`Right` alignments, position offsets and radial angles stay physical.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum UDirection {
+    #[default]
+    Ltr,
+    Rtl,
 }
 
+/// Resolved `UDirection` of a node, maintained by `propagate_direction`.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct ComputedDirection(pub UDirection);
+
+impl ComputedDirection {
+    pub fn is_rtl(&self) -> bool {
+        self.0 == UDirection::Rtl
+    }
+}
+
+/// Stacking order of a node and its subtree, independent of tree and layout order.
+///
+/// Each step lifts the subtree above every node of the tree without a higher index,
+/// for both drawing and picking. Indices add up along the ancestors; roots ignore it.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Reflect)]
+#[reflect(Component)]
+pub struct UZIndex(pub i32);
+
 /// مكون يفرض القص (Masking) على جميع أبنائه.
 /// يتم استخدام حدود هذا العنصر (Size + Position + Radius) كقناع.
 #[derive(Component, Default, Reflect)]
@@ -496,8 +953,40 @@ pub struct UClip {
     /// هل القص مفعل؟
     pub enabled: bool,
 }
+
 impl UClip {
+    /// Extent used for an axis that is not clipped, large enough to never cut.
+    pub const UNCLIPPED_EXTENT: f32 = 1.0e7;
+
     pub fn enabled(enable: bool) -> Self {
         UClip { enabled: enable }
     }
+
+    /// An enabled clip limited to the given axes.
+    pub fn axes(x: bool, y: bool) -> (UClip, UClipAxes) {
+        (UClip { enabled: true }, UClipAxes(BVec2::new(x, y)))
+    }
+}
+
+/// Axes cut by the node's [`UClip`]. Without it both axes are clipped.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct UClipAxes(pub BVec2);
+
+impl Default for UClipAxes {
+    fn default() -> Self {
+        UClipAxes(BVec2::TRUE)
+    }
+}
+
+impl UClipAxes {
+    /// Mask size for a clipper of `size`; unclipped axes get `UClip::UNCLIPPED_EXTENT`.
+    pub fn mask_size(self, size: Vec2) -> Vec2 {
+        Vec2::select(self.0, size, Vec2::splat(UClip::UNCLIPPED_EXTENT))
+    }
+
+    /// Corner radii of the mask; only a box clipped on both axes is rounded.
+    pub fn mask_radius(self, radius: Vec4) -> Vec4 {
+        if self.0.all() { radius } else { Vec4::ZERO }
+    }
 }
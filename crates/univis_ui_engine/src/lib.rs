@@ -5,8 +5,8 @@ pub mod schedule;
 
 #[doc(hidden)]
 pub mod internal {
-    pub use crate::layout::components::{IntrinsicSize, LayoutDepth, LayoutTreeDepth, UI3d};
-    pub use crate::layout::geometry::ComputedSize;
+    pub use crate::layout::components::{IntrinsicSize, LayoutDepth, LayoutTreeDepth, UBaseline, UI3d};
+    pub use crate::layout::geometry::{ComputedGridTracks, ComputedSize, ComputedTrack, ScrollableOverflow, StickyOffset};
     pub use crate::layout::render::system::{MaterialHandles, MaterialPool};
 }
 
@@ -19,20 +19,26 @@ pub(crate) mod internal_prelude {
     pub use crate::layout::geometry::*;
     pub use crate::layout::image::*;
     pub use crate::layout::layout_system::*;
+    pub use crate::layout::measure::*;
     pub use crate::layout::pbr::*;
     pub use crate::layout::pipeline::prelude::*;
     pub use crate::layout::profiling::*;
     pub use crate::layout::render::prelude::*;
+    pub use crate::layout::responsive::*;
+    pub use crate::layout::scale::*;
     pub use crate::layout::solver_types::*;
+    pub use crate::layout::transition::*;
     pub use crate::layout::univis_node::*;
     pub use crate::schedule::*;
     pub use univis_ui_style::prelude::*;
 }
 
 pub mod prelude {
-    pub use crate::layout::geometry::{UCornerRadius, USides, UVal};
+    pub use crate::layout::components::UBaseline;
+    pub use crate::layout::geometry::{UCalc, UCalcSum, UCornerRadius, USides, UVal};
     pub use crate::layout::image::UImage;
-    pub use crate::layout::layout_system::{UScreenRoot, UWorldRoot};
+    pub use crate::layout::layout_system::{UScreenRoot, UTargetCamera, UWorldRoot};
+    pub use crate::layout::measure::{UMeasure, UMeasureFunc};
     pub use crate::layout::pbr::UPbr;
     pub use crate::layout::univis_node::*;
     pub use crate::{layout::prelude::*, UnivisEnginePlugin};
@@ -46,6 +52,8 @@ impl Plugin for UnivisEnginePlugin {
             layout::univis_node::UnivisNodePlugin,
             layout::UnivisLayoutPlugin,
             layout::render::UnivisRenderPlugin,
+            layout::transition::LayoutTransitionPlugin,
+            layout::scale::UiScalePlugin,
         ));
     }
 }
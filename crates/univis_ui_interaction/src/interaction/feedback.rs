@@ -29,6 +29,14 @@ impl Default for UInteractionColors{
         UInteractionColors { normal: Color::NONE, hovered: Color::srgb(0.1, 0.1, 0.1), pressed: Color::BLACK }
     }
 }
+/// Writes the hover/press color only when it differs, so `Changed<UNode>`
+/// isn't raised for nothing. Colors are paint-only and never dirty layout.
+fn set_background(node: &mut Mut<UNode>, color: Color) {
+    if node.background_color != color {
+        node.background_color = color;
+    }
+}
+
 /// Event Handler: Pointer Over (Hover Enter)
 pub fn on_pointer_over(
     trigger: On<Pointer<Over>>,
@@ -39,7 +47,7 @@ pub fn on_pointer_over(
     if let Ok((mut interaction, mut node, colors)) = query.get_mut(entity) {
         *interaction = UInteraction::Hovered;
         if let Some(color) = colors {
-            node.background_color = color.hovered;
+            set_background(&mut node, color.hovered);
         }
     }
 }
@@ -54,7 +62,7 @@ pub fn on_pointer_click(
     if let Ok((mut interaction, mut node, colors)) = query.get_mut(entity) {
         *interaction = UInteraction::Clicked;
         if let Some(color) = colors {
-            node.background_color = color.pressed;
+            set_background(&mut node, color.pressed);
         }
     }
 }
@@ -69,7 +77,7 @@ pub fn on_pointer_out(
     if let Ok((mut interaction, mut node, colors)) = query.get_mut(entity) {
         *interaction = UInteraction::Normal;
         if let Some(color) = colors {
-            node.background_color = color.normal;
+            set_background(&mut node, color.normal);
         }
     }
 }
@@ -84,7 +92,7 @@ pub fn on_pointer_press(
     if let Ok((mut interaction, mut node, colors)) = query.get_mut(entity) {
         *interaction = UInteraction::Pressed;
         if let Some(color) = colors {
-            node.background_color = color.pressed;
+            set_background(&mut node, color.pressed);
         }
     }
 }
@@ -99,7 +107,7 @@ pub fn on_pointer_release(
     if let Ok((mut interaction, mut node, colors)) = query.get_mut(entity) {
         *interaction = UInteraction::Released;
         if let Some(color) = colors {
-            node.background_color = color.hovered;
+            set_background(&mut node, color.hovered);
         }
     }
 }
\ No newline at end of file
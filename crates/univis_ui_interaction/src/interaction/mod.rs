@@ -1,3 +1,4 @@
+use bevy::picking::PickingSystems;
 use bevy::prelude::*;
 
 use crate::internal_prelude::*;
@@ -21,7 +22,7 @@ impl Plugin for UnivisInteractionPlugin {
 
         // app.add_plugins(UnivisInputFieldPlugin);
         // 1. إضافة Backend الالتقاط (حساب من أين يمر الماوس)
-        app.add_systems(PreUpdate, univis_picking_backend);
+        app.add_systems(PreUpdate, univis_picking_backend.in_set(PickingSystems::Backend));
         
         // 2. تسجيل المراقبين (Observers) - الطريقة الجديدة للتفاعل
         // هذه المراقبون سيعملون تلقائياً لأي كيان يرسل له Backend حدثاً
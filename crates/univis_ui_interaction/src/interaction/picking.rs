@@ -1,3 +1,4 @@
+use bevy::camera::visibility::RenderLayers;
 use bevy::ecs::relationship::Relationship;
 use bevy::prelude::*;
 use bevy::picking::backend::prelude::*;
@@ -9,7 +10,7 @@ fn is_clipped_by_ancestors(
     start_entity: Entity,
     cursor_world_pos: Vec2,
     parents_query: &Query<&ChildOf>,
-    clipper_query: &Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip)>,
+    clipper_query: &Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip, Option<&UClipAxes>)>,
 ) -> bool {
     let mut current_entity = start_entity;
 
@@ -17,8 +18,9 @@ fn is_clipped_by_ancestors(
     while let Ok(parent) = parents_query.get(current_entity) {
         current_entity = parent.get();
 
-        if let Ok((transform, size, node, clip)) = clipper_query.get(current_entity) {
+        if let Ok((transform, size, node, clip, axes)) = clipper_query.get(current_entity) {
             if clip.enabled {
+                let axes = axes.copied().unwrap_or_default();
                 // 1. التحويل من العالم (World) إلى المحلي (Local) الخاص بالأب القاطع
                 let transform_matrix = transform.to_matrix();
                 let inverse_matrix = transform_matrix.inverse();
@@ -29,13 +31,13 @@ fn is_clipped_by_ancestors(
                     .truncate();
 
                 // 2. حساب حدود القناع
-                let half_size = Vec2::new(size.width, size.height) * 0.5;
-                let radius = Vec4::new(
+                let half_size = axes.mask_size(Vec2::new(size.width, size.height)) * 0.5;
+                let radius = axes.mask_radius(Vec4::new(
                     node.border_radius.top_right,
                     node.border_radius.bottom_right,
                     node.border_radius.top_left,
                     node.border_radius.bottom_left,
-                );
+                ));
 
                 // 3. اختبار SDF
                 let dist = sd_rounded_box(cursor_in_clipper_space, half_size, radius);
@@ -54,13 +56,13 @@ fn is_clipped_by_ancestors(
 fn is_ancestor_of(
     potential_ancestor: Entity,
     potential_descendant: Entity,
-    parents_query: &Query<&ChildOf>,
+    parent_of: impl Fn(Entity) -> Option<Entity>,
 ) -> bool {
     let mut current = potential_descendant;
     
     // نصعد في الشجرة حتى نجد الأب أو نصل للجذر
-    while let Ok(parent) = parents_query.get(current) {
-        current = parent.get();
+    while let Some(parent) = parent_of(current) {
+        current = parent;
         if current == potential_ancestor {
             return true;
         }
@@ -69,42 +71,108 @@ fn is_ancestor_of(
     false
 }
 
+/// مجموع قيم UZIndex للعنصر وأسلافه (طبقة التراكب)
+///
+/// الجذر (عقدة بلا أب) لا يدخل في المجموع، كما في مسار الرسم.
+fn stacking_layer(
+    entity: Entity,
+    parent_of: impl Fn(Entity) -> Option<Entity>,
+    z_index_of: impl Fn(Entity) -> i32,
+) -> i32 {
+    let mut layer = 0;
+    let mut current = entity;
+
+    while let Some(parent) = parent_of(current) {
+        layer += z_index_of(current);
+        current = parent;
+    }
+
+    layer
+}
+
+/// ترتيب التراكب للالتقاط: طبقة UZIndex، ثم عمق الشجرة، ثم z العالمي.
+///
+/// يُقارن كـ tuple؛ دمج القيم الثلاث في f32 واحد يفقد دقة z عند الطبقات الكبيرة.
+type StackingOrder = (i32, usize, f32);
+
+fn compare_stacking(a: &StackingOrder, b: &StackingOrder) -> std::cmp::Ordering {
+    a.0.cmp(&b.0).then(a.1.cmp(&b.1)).then(a.2.total_cmp(&b.2))
+}
+
+/// يرتب الإصابات من الأعلى إلى الأسفل ويعيد `HitData.depth` لكل منها.
+///
+/// الأب يسقط إذا أصيب ابن له فوقه، والعمق هو الترتيب (0 للأعلى) لأن Bevy
+/// يعتبر العمق الأصغر هو الأقرب للكاميرا.
+fn rank_hits(
+    hits: &[(Entity, StackingOrder)],
+    parent_of: impl Fn(Entity) -> Option<Entity> + Copy,
+) -> Vec<(Entity, f32)> {
+    // نريد فقط إبقاء العنصر الأعمق من كل عائلة
+    let mut filtered: Vec<(Entity, StackingOrder)> = hits
+        .iter()
+        .filter(|(entity, order)| {
+            // فحص: هل هناك ابن لهذا الكيان تم التقاطه أيضاً وهو أقرب للكاميرا؟
+            !hits.iter().any(|(other, other_order)| {
+                other != entity
+                    && is_ancestor_of(*entity, *other, parent_of)
+                    && compare_stacking(other_order, order).is_gt()
+            })
+        })
+        .copied()
+        .collect();
+
+    filtered.sort_by(|(_, a), (_, b)| compare_stacking(b, a));
+    filtered
+        .iter()
+        .enumerate()
+        .map(|(rank, (entity, _))| (*entity, rank as f32))
+        .collect()
+}
+
+/// يلتقط لكل كاميرا 2D على حدة: شعاع لكل (كاميرا، مؤشر) من `RayMap`.
+///
+/// العقدة لا تُلتقط عبر كاميرا إلا إذا تقاطعت `RenderLayers` الخاصة بها مع
+/// طبقات الكاميرا، أي إذا كانت الكاميرا ترسمها.
 pub fn univis_picking_backend(
-    pointers: Query<(&PointerId, &PointerLocation)>,
-    cameras: Query<(Entity, &Camera, &GlobalTransform), With<Camera2d>>,
+    ray_map: Res<RayMap>,
+    cameras: Query<(&Camera, Option<&RenderLayers>), With<Camera2d>>,
     
     nodes_query: Query<(
         Entity, 
         &UNode, 
         &GlobalTransform, 
         &ComputedSize, 
+        Option<&LayoutTransitionState>,
+        Option<&PixelSnap>,
         Option<&LayoutDepth>,
+        Option<&RenderLayers>,
     ), With<UInteraction>>,
     
     parents_query: Query<&ChildOf>,
-    clipper_query: Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip)>,
+    clipper_query: Query<(&GlobalTransform, &ComputedSize, &UNode, &UClip, Option<&UClipAxes>)>,
+    z_index_query: Query<&UZIndex>,
 
     mut output: MessageWriter<PointerHits>,
 ) {
-    let Ok((cam_entity, camera, cam_transform)) = cameras.single() else { 
-        return; 
-    };
+    let parent_of = |entity: Entity| parents_query.get(entity).ok().map(ChildOf::parent);
 
-    for (pointer_id, pointer_loc) in pointers.iter() {
-        let Some(location) = pointer_loc.location() else { 
-            continue; 
-        };
-        
-        let Ok(ray) = camera.viewport_to_world(cam_transform, location.position) else { 
-            continue; 
+    // RayMap يحتوي فقط المؤشرات الواقعة داخل viewport الكاميرا وعلى نافذتها
+    for (ray_id, ray) in ray_map.iter() {
+        let Ok((camera, cam_layers)) = cameras.get(ray_id.camera) else {
+            continue;
         };
+        if !camera.is_active {
+            continue;
+        }
+        let cam_entity = ray_id.camera;
+        let cam_layers = cam_layers.cloned().unwrap_or_default();
         
         let cursor_pos_world = ray.origin.truncate(); 
 
         // المرحلة 1: جمع كل الـ hits المحتملة
-        let mut all_hits: Vec<(Entity, HitData, f32)> = Vec::new();
+        let mut all_hits: Vec<(Entity, StackingOrder)> = Vec::new();
 
-        for (entity, node, global_transform, size, depth_comp) in nodes_query.iter() {
+        for (entity, node, global_transform, size, transition, snap, depth_comp, layers) in nodes_query.iter() {
             // التحويل لـ Local Space
             let transform_matrix = global_transform.to_matrix();
             let inverse_matrix = transform_matrix.inverse();
@@ -112,7 +180,8 @@ pub fn univis_picking_backend(
                 .transform_point3(cursor_pos_world.extend(0.0))
                 .truncate(); 
 
-            let half_size = Vec2::new(size.width, size.height) * 0.5;
+            // المستطيل المرسوم (ULayoutTransition / PixelSnap)
+            let half_size = drawn_size(size, transition, snap) * 0.5;
             let radius_vec = Vec4::new(
                 node.border_radius.top_right,
                 node.border_radius.bottom_right,
@@ -123,6 +192,11 @@ pub fn univis_picking_backend(
             let dist = sd_rounded_box(cursor_pos_local, half_size, radius_vec);
 
             if dist <= 0.0 {
+                // طبقات لا تراها هذه الكاميرا
+                if !cam_layers.intersects(layers.unwrap_or(&RenderLayers::default())) {
+                    continue;
+                }
+
                 // التحقق من القص
                 if is_clipped_by_ancestors(
                     entity, 
@@ -133,56 +207,172 @@ pub fn univis_picking_backend(
                     continue; 
                 }
 
-                // حساب العمق
-                let tree_depth = depth_comp.map(|d| d.0).unwrap_or(0) as f32;
+                // حساب العمق: طبقة UZIndex أولاً ثم عمق الشجرة
+                let layer = stacking_layer(entity, parent_of, |entity| {
+                    z_index_query.get(entity).map_or(0, |z| z.0)
+                });
+                let tree_depth = depth_comp.map(|d| d.0).unwrap_or(0);
                 let z_depth = global_transform.translation().z;
-                let final_depth = tree_depth * 1000.0 + z_depth;
 
-                all_hits.push((
-                    entity, 
-                    HitData {
-                        camera: cam_entity, 
-                        depth: final_depth,
-                        position: Some(cursor_pos_world.extend(0.0)),
-                        normal: Some(Vec3::Z),
-                    },
-                    final_depth, // نحتفظ بالعمق للمقارنة
-                ));
+                all_hits.push((entity, (layer, tree_depth, z_depth)));
             }
         }
 
-        // ✅ المرحلة 2: تصفية الآباء إذا كان هناك أبناء
-        // نريد فقط إبقاء العنصر الأعمق من كل عائلة
-        let mut filtered_hits: Vec<(Entity, HitData)> = Vec::new();
+        // ✅ المرحلة 2: تصفية الآباء إذا كان هناك أبناء، ثم الترتيب من الأعلى
+        let picks: Vec<(Entity, HitData)> = rank_hits(&all_hits, parent_of)
+            .into_iter()
+            .map(|(entity, depth)| {
+                (entity, HitData {
+                    camera: cam_entity,
+                    depth,
+                    position: Some(cursor_pos_world.extend(0.0)),
+                    normal: Some(Vec3::Z),
+                })
+            })
+            .collect();
 
-        for (entity, hit_data, depth) in all_hits.iter() {
-            let mut should_include = true;
-
-            // فحص: هل هناك ابن لهذا الكيان تم التقاطه أيضاً؟
-            for (other_entity, _, other_depth) in all_hits.iter() {
-                if entity == other_entity {
-                    continue;
-                }
+        // إرسال النتائج المفلترة
+        if !picks.is_empty() {
+            output.write(PointerHits {
+                pointer: ray_id.pointer,
+                picks,
+                order: camera.order as f32,
+            });
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::picking::backend::ray::RayId;
+    use bevy::platform::collections::HashMap;
 
-                // إذا كان other_entity ابن لـ entity وعمقه أكبر (أقرب للكاميرا)
-                if is_ancestor_of(*entity, *other_entity, &parents_query) && other_depth > depth {
-                    should_include = false;
-                    break;
-                }
-            }
+    /// root > (a > a1 > a2, b): `b` is a later sibling of `a`.
+    struct Tree {
+        parents: HashMap<Entity, Entity>,
+        z_index: HashMap<Entity, i32>,
+    }
 
-            if should_include {
-                filtered_hits.push((*entity, hit_data.clone()));
-            }
+    impl Tree {
+        fn parent_of(&self) -> impl Fn(Entity) -> Option<Entity> + Copy + '_ {
+            |entity| self.parents.get(&entity).copied()
         }
 
-        // إرسال النتائج المفلترة
-        if !filtered_hits.is_empty() {
-            output.write(PointerHits {
-                pointer: *pointer_id,
-                picks: filtered_hits,
-                order: 0.0, 
-            });
+        fn layer(&self, entity: Entity) -> i32 {
+            stacking_layer(entity, self.parent_of(), |entity| {
+                self.z_index.get(&entity).copied().unwrap_or(0)
+            })
         }
     }
-}
\ No newline at end of file
+
+    fn spawn_tree(world: &mut World) -> (Tree, [Entity; 5]) {
+        let [root, a, a1, a2, b] = std::array::from_fn(|_| world.spawn_empty().id());
+        let parents = HashMap::from_iter([(a, root), (a1, a), (a2, a1), (b, root)]);
+        (Tree { parents, z_index: HashMap::new() }, [root, a, a1, a2, b])
+    }
+
+    #[test]
+    fn stacking_layer_sums_ancestors_without_the_root() {
+        let mut world = World::new();
+        let (mut tree, [root, a, a1, a2, _]) = spawn_tree(&mut world);
+        tree.z_index.extend([(root, 5), (a, 3), (a1, 2)]);
+
+        assert_eq!(tree.layer(root), 0);
+        assert_eq!(tree.layer(a2), 5);
+    }
+
+    #[test]
+    fn stacking_compares_layer_then_depth_then_z() {
+        use std::cmp::Ordering;
+
+        assert_eq!(compare_stacking(&(1, 0, -10.0), &(0, 5, 0.0)), Ordering::Greater);
+        assert_eq!(compare_stacking(&(0, 2, -10.0), &(0, 1, 0.0)), Ordering::Greater);
+        assert_eq!(compare_stacking(&(0, 1, 0.002), &(0, 1, 0.001)), Ordering::Greater);
+        // طبقة كبيرة لا تطمس فرق z صغير
+        assert_eq!(compare_stacking(&(100_000, 1, 0.001), &(100_000, 1, 0.0)), Ordering::Greater);
+    }
+
+    #[test]
+    fn higher_z_index_later_sibling_wins_over_a_deeper_child() {
+        let mut world = World::new();
+        let (mut tree, [root, a, a1, a2, b]) = spawn_tree(&mut world);
+        tree.z_index.insert(b, 1);
+        let hits: Vec<(Entity, StackingOrder)> = [(root, 0), (a, 1), (a1, 2), (a2, 3), (b, 1)]
+            .into_iter()
+            .map(|(entity, depth)| (entity, (tree.layer(entity), depth, depth as f32 * 0.001)))
+            .collect();
+
+        // الآباء تسقط تحت أعمق أبنائها، و b يعلو a2 رغم أنه أقل عمقًا
+        assert_eq!(rank_hits(&hits, tree.parent_of()), vec![(b, 0.0), (a2, 1.0)]);
+
+        // بدون UZIndex يفوز الأعمق
+        tree.z_index.clear();
+        let flat: Vec<(Entity, StackingOrder)> = hits
+            .iter()
+            .map(|&(entity, (_, depth, z))| (entity, (tree.layer(entity), depth, z)))
+            .collect();
+        assert_eq!(rank_hits(&flat, tree.parent_of()), vec![(a2, 0.0), (b, 1.0)]);
+    }
+
+    #[test]
+    fn parent_above_its_child_keeps_both_hits() {
+        let mut world = World::new();
+        let (tree, [_, a, a1, _, _]) = spawn_tree(&mut world);
+        // الابن بطبقة أدنى من أبيه (UZIndex سالب) لا يخفي الأب
+        let hits = [(a, (0, 1, 0.0)), (a1, (-1, 2, 0.0))];
+
+        assert_eq!(rank_hits(&hits, tree.parent_of()), vec![(a, 0.0), (a1, 1.0)]);
+    }
+
+    #[test]
+    fn node_is_hit_only_through_the_camera_that_draws_it() {
+        let mut app = App::new();
+        app.add_message::<PointerHits>();
+        app.init_resource::<RayMap>();
+        app.add_systems(Update, univis_picking_backend);
+
+        let world = app.world_mut();
+        let main_camera = world.spawn((Camera2d, Camera { order: 0, ..default() })).id();
+        let overlay_camera = world
+            .spawn((Camera2d, Camera { order: 1, ..default() }, RenderLayers::layer(1)))
+            .id();
+        // عقدتان في المكان نفسه تحت المؤشر، كل واحدة على طبقة
+        let node = |layers: RenderLayers| {
+            (
+                UNode::default(),
+                ComputedSize { width: 100.0, height: 100.0, ..default() },
+                GlobalTransform::IDENTITY,
+                UInteraction::default(),
+                layers,
+            )
+        };
+        let main_node = world.spawn(node(RenderLayers::layer(0))).id();
+        let overlay_node = world.spawn(node(RenderLayers::layer(1))).id();
+
+        let ray = Ray3d::new(Vec3::new(10.0, 10.0, 100.0), Dir3::NEG_Z);
+        world.resource_mut::<RayMap>().map.extend([
+            (RayId::new(main_camera, PointerId::Mouse), ray),
+            (RayId::new(overlay_camera, PointerId::Mouse), ray),
+        ]);
+        app.update();
+
+        let messages = app.world().resource::<Messages<PointerHits>>();
+        let mut hits: Vec<(Entity, f32, Vec<Entity>)> = messages
+            .iter_current_update_messages()
+            .map(|hits| {
+                let camera = hits.picks[0].1.camera;
+                assert!(hits.picks.iter().all(|(_, data)| data.camera == camera && data.depth == 0.0));
+                (camera, hits.order, hits.picks.iter().map(|(entity, _)| *entity).collect())
+            })
+            .collect();
+        hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        assert_eq!(
+            hits,
+            vec![
+                (main_camera, 0.0, vec![main_node]),
+                (overlay_camera, 1.0, vec![overlay_node]),
+            ]
+        );
+    }
+}
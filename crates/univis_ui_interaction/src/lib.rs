@@ -5,6 +5,7 @@ pub(crate) mod internal_prelude {
     pub use crate::interaction::prelude::*;
     pub use univis_ui_engine::internal::{ComputedSize, LayoutDepth};
     pub use univis_ui_engine::layout::geometry::{UCornerRadius, USides, UVal};
+    pub use univis_ui_engine::layout::scale::drawn_size;
     pub use univis_ui_engine::prelude::*;
     pub use univis_ui_engine::schedule::UnivisPostUpdateSet;
 }
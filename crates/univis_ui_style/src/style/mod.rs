@@ -26,7 +26,9 @@ pub struct Theme {
 }
 
 pub struct TextStyles {
-    pub font: Fonts
+    pub font: Fonts,
+    /// Base font size in pixels; `Em`/`Rem` lengths resolve against it.
+    pub base_font_size: f32,
 }
 
 pub struct IconStyles {
@@ -53,7 +55,8 @@ impl FromWorld for Theme {
                     fira_sans_regular: asset_server.load(
                         "embedded://univis_ui_style/style/assets/fonts/FiraSans-Regular.ttf"
                     )
-                }
+                },
+                base_font_size: 16.0,
             },
             icon: IconStyles {
                 font: asset_server.load(
@@ -1,13 +1,33 @@
 use bevy::prelude::*;
 use crate::internal_prelude::*;
 
+/// Marks a `UNode.aspect_ratio` that was set from the texture by `sync_image_geometry`.
+#[derive(Component)]
+pub struct UImageAspectRatio;
+
 pub fn sync_image_geometry(
-    // نراقب تغيرات UImage أو UNode
-    mut query: Query<(&UImage, &mut UNode), Or<(Changed<UImage>, Changed<UNode>)>>,
+    mut commands: Commands,
+    // نراقب تغيرات UImage أو UNode، وكذلك تحميل/تعديل الصور (إطارات الفيديو مثلاً)
+    mut query: Query<(Entity, Ref<UImage>, &mut UNode, Has<UImageAspectRatio>)>,
+    mut image_events: MessageReader<AssetEvent<Image>>,
     // نحتاج الوصول للأصول لمعرفة حجم الصورة الحقيقي
     images: Res<Assets<Image>>, 
 ) {
-    for (ui_image, mut node) in query.iter_mut() {
+    let updated_images: Vec<AssetId<Image>> = image_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, ui_image, mut node, owns_ratio) in query.iter_mut() {
+        let texture_updated = updated_images.contains(&ui_image.texture.id());
+        if !ui_image.is_changed() && !node.is_changed() && !texture_updated {
+            continue;
+        }
         
         // 1. مزامنة نصف القطر (إذا وجد)
         if let Some(r) = ui_image.radius {
@@ -28,9 +48,38 @@ pub fn sync_image_geometry(
             }
         }
 
+        // محور واحد فقط Auto: نشتقه من نسبة الصورة الأصلية بدلاً من حجمها
+        let natural_ratio = (native_size.x > 0.0 && native_size.y > 0.0).then(|| native_size.x / native_size.y);
+        let derive_from_ratio = ui_image.keep_aspect_ratio
+            && natural_ratio.is_some()
+            && (ui_image.width == UVal::Auto) != (ui_image.height == UVal::Auto);
+
+        // نسبة وضعها المستخدم على UNode تبقى كما هي
+        if derive_from_ratio && (owns_ratio || node.aspect_ratio.is_none()) {
+            if node.aspect_ratio != natural_ratio {
+                node.aspect_ratio = natural_ratio;
+            }
+            if !owns_ratio {
+                commands.entity(entity).insert(UImageAspectRatio);
+            }
+        } else if !derive_from_ratio && owns_ratio {
+            node.aspect_ratio = None;
+            commands.entity(entity).remove::<UImageAspectRatio>();
+        }
+
+        let auto_axis = |native: f32| {
+            if derive_from_ratio {
+                UVal::Content
+            } else if native > 0.0 {
+                UVal::Px(native)
+            } else {
+                UVal::Auto
+            }
+        };
+
         // 3. تطبيق العرض (Width)
         let target_width = match ui_image.width {
-            UVal::Auto => if native_size.x > 0.0 { UVal::Px(native_size.x) } else { UVal::Auto },
+            UVal::Auto => auto_axis(native_size.x),
             other => other,
         };
 
@@ -40,7 +89,7 @@ pub fn sync_image_geometry(
 
         // 4. تطبيق الارتفاع (Height)
         let target_height = match ui_image.height {
-            UVal::Auto => if native_size.y > 0.0 { UVal::Px(native_size.y) } else { UVal::Auto },
+            UVal::Auto => auto_axis(native_size.y),
             other => other,
         };
 
@@ -49,3 +98,59 @@ pub fn sync_image_geometry(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image_app() -> (App, Handle<Image>) {
+        let mut app = App::new();
+        app.init_resource::<Assets<Image>>()
+            .add_message::<AssetEvent<Image>>()
+            .add_systems(Update, sync_image_geometry);
+        // 1x1: النسبة الطبيعية 1
+        let texture = app.world_mut().resource_mut::<Assets<Image>>().add(Image::default());
+        (app, texture)
+    }
+
+    #[test]
+    fn texture_ratio_is_cleared_once_it_no_longer_applies() {
+        let (mut app, texture) = image_app();
+        let entity = app
+            .world_mut()
+            .spawn(UImage::new(texture).with_size(UVal::Px(100.0), UVal::Auto))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<UNode>(entity).unwrap().aspect_ratio, Some(1.0));
+
+        app.world_mut().get_mut::<UImage>(entity).unwrap().height = UVal::Px(40.0);
+        app.update();
+        assert_eq!(app.world().get::<UNode>(entity).unwrap().aspect_ratio, None);
+
+        app.world_mut().get_mut::<UImage>(entity).unwrap().height = UVal::Auto;
+        app.update();
+        assert_eq!(app.world().get::<UNode>(entity).unwrap().aspect_ratio, Some(1.0));
+
+        app.world_mut().get_mut::<UImage>(entity).unwrap().keep_aspect_ratio = false;
+        app.update();
+        assert_eq!(app.world().get::<UNode>(entity).unwrap().aspect_ratio, None);
+    }
+
+    #[test]
+    fn user_aspect_ratio_is_kept() {
+        let (mut app, texture) = image_app();
+        let entity = app
+            .world_mut()
+            .spawn((
+                UNode { aspect_ratio: Some(2.0), ..default() },
+                UImage::new(texture).with_size(UVal::Px(100.0), UVal::Auto),
+            ))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<UNode>(entity).unwrap().aspect_ratio, Some(2.0));
+
+        app.world_mut().get_mut::<UImage>(entity).unwrap().keep_aspect_ratio = false;
+        app.update();
+        assert_eq!(app.world().get::<UNode>(entity).unwrap().aspect_ratio, Some(2.0));
+    }
+}
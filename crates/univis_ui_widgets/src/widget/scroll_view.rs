@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use crate::internal_prelude::*;
 use bevy::input::mouse::MouseWheel;
+use bevy::platform::collections::HashMap;
 
 pub struct UnivisScrollViewPlugin;
 
@@ -8,7 +9,13 @@ impl Plugin for UnivisScrollViewPlugin {
     fn build(&self, app: &mut App) {
         app
             .register_type::<UScrollContainer>()
-            .add_systems(Update, scroll_interaction_system);
+            .add_systems(Update, scroll_interaction_system)
+            .add_systems(
+                PostUpdate,
+                sticky_position_system
+                    .after(UnivisPostUpdateSet::LayoutSolve)
+                    .before(UnivisPostUpdateSet::RenderSync),
+            );
     }
 }
 
@@ -43,11 +50,13 @@ pub fn scroll_interaction_system(
         &UInteraction,      // نستخدم هذا بدلاً من حسابات الماوس اليدوية
         &mut UScrollContainer, 
         &ComputedSize, 
-        &Children
+        &ScrollableOverflow,
+        &Children,
+        Option<&ULayout>,
     )>,
     
     // 3. الاستعلام عن المحتوى (لتحديث موقعه)
-    mut content_query: Query<(&mut USelf, &ComputedSize)>,
+    mut content_query: Query<&mut USelf>,
 ) {
     // جمع حركة العجلة
     let mut scroll_delta = Vec2::ZERO;
@@ -61,7 +70,7 @@ pub fn scroll_interaction_system(
         return;
     }
 
-    for (interaction, mut container, size, children) in containers.iter_mut() {
+    for (interaction, mut container, size, content_overflow, children, layout) in containers.iter_mut() {
         
         // === هنا السحر: استخدام UInteraction ===
         // بدلاً من الحسابات المعقدة، نسأل فقط: هل الماوس فوق الحاوية؟
@@ -74,15 +83,19 @@ pub fn scroll_interaction_system(
             continue;
         };
 
-        let Ok((mut uself, content_size)) = content_query.get_mut(content_entity) else {
+        let Ok(mut uself) = content_query.get_mut(content_entity) else {
             continue;
         };
 
+        // مدى المحتوى الفعلي من المحرك (كل الأبناء وليس الأول فقط)
         let speed = container.scroll_speed.max(0.0);
-        let overflow_y = (content_size.height - size.height).max(0.0);
-        let overflow_x = (content_size.width - size.width).max(0.0);
+        let overflow = content_overflow.overflow(size.size());
+        let (overflow_x, overflow_y) = (overflow.x, overflow.y);
+
+        // `Clip` و`Hidden` لا يتحركان بالعجلة
+        let (wheel_x, wheel_y) = layout.map_or((true, true), |l| (wheel_scrolls(l.overflow_x), wheel_scrolls(l.overflow_y)));
 
-        if container.vertical {
+        if container.vertical && wheel_y {
             container.offset.y = clamp_scroll_offset(
                 container.offset.y,
                 scroll_delta.y * speed,
@@ -91,7 +104,7 @@ pub fn scroll_interaction_system(
             uself.top = UVal::Px(container.offset.y);
         }
 
-        if container.horizontal {
+        if container.horizontal && wheel_x {
             container.offset.x = clamp_scroll_offset(
                 container.offset.x,
                 scroll_delta.x * speed,
@@ -103,6 +116,121 @@ pub fn scroll_interaction_system(
     }
 }
 
+/// Moves `UPositionType::Sticky` nodes so they stay inside the viewport of
+/// their nearest `UScrollContainer`.
+///
+/// Works from the solved positions (`local_pos`) and stores the result in
+/// `StickyOffset`, which layout transitions and pixel snapping add back when
+/// they rebuild `Transform` later in the frame.
+pub fn sticky_position_system(
+    mut commands: Commands,
+    units: UnitContextParam,
+    containers: Query<(), With<UScrollContainer>>,
+    mut nodes: Query<(Entity, &UNode, &ComputedSize, &mut Transform, Option<&ChildOf>, Option<&USelf>)>,
+    mut offsets: Query<(Entity, &mut StickyOffset)>,
+) {
+    let mut moves: HashMap<Entity, Vec2> = HashMap::new();
+
+    for (entity, _, size, _, child_of, uself) in nodes.iter() {
+        let (Some(uself), Some(child_of)) = (uself, child_of) else {
+            continue;
+        };
+        if uself.position_type != UPositionType::Sticky {
+            continue;
+        }
+
+        // مركز العقدة وأبيها في فضاء حاوية التمرير
+        let parent = child_of.parent();
+        let mut center = size.local_pos;
+        let mut parent_center = Vec2::ZERO;
+        let mut current = parent;
+        let mut viewport_size = None;
+        while let Ok((_, _, current_size, _, current_parent, _)) = nodes.get(current) {
+            if containers.contains(current) {
+                viewport_size = Some(current_size.size());
+                break;
+            }
+            center += current_size.local_pos;
+            parent_center += current_size.local_pos;
+            let Some(next) = current_parent else {
+                break;
+            };
+            current = next.parent();
+        }
+        let Some(viewport_size) = viewport_size else {
+            continue;
+        };
+        let Ok((_, parent_node, parent_size, _, _, _)) = nodes.get(parent) else {
+            continue;
+        };
+
+        let unit_ctx = units.context_for(parent);
+        let sticky = Rect::from_center_size(center, size.size());
+        let parent_rect = Rect::from_center_size(parent_center, parent_size.size());
+        let parent_content = Rect {
+            min: parent_rect.min + Vec2::new(parent_node.padding.left, parent_node.padding.bottom),
+            max: parent_rect.max - Vec2::new(parent_node.padding.right, parent_node.padding.top),
+        };
+        let delta = sticky_delta(
+            sticky,
+            parent_content,
+            Rect::from_center_size(Vec2::ZERO, viewport_size),
+            uself.top.resolve_in(viewport_size.y, &unit_ctx),
+            uself.left.resolve_in(viewport_size.x, &unit_ctx),
+        );
+        moves.insert(entity, delta);
+    }
+
+    for (entity, mut offset) in offsets.iter_mut() {
+        match moves.get(&entity) {
+            Some(&delta) => {
+                offset.set_if_neq(StickyOffset(delta));
+            }
+            None => {
+                commands.entity(entity).remove::<StickyOffset>();
+            }
+        }
+    }
+    for (&entity, &delta) in moves.iter() {
+        let Ok((_, _, size, mut transform, _, _)) = nodes.get_mut(entity) else {
+            continue;
+        };
+        if !offsets.contains(entity) {
+            commands.entity(entity).insert(StickyOffset(delta));
+        }
+        let target = size.placed_translation(Some(&StickyOffset(delta)));
+        if transform.translation.truncate() != target {
+            transform.translation.x = target.x;
+            transform.translation.y = target.y;
+        }
+    }
+}
+
+/// Offset that keeps `sticky` within `top`/`left` of the viewport edges without
+/// leaving `parent_content`. All rects use y-up coordinates.
+fn sticky_delta(sticky: Rect, parent_content: Rect, viewport: Rect, top: Option<f32>, left: Option<f32>) -> Vec2 {
+    let mut delta = Vec2::ZERO;
+
+    if let Some(top) = top {
+        let push = sticky.max.y - (viewport.max.y - top);
+        let room = sticky.min.y - parent_content.min.y;
+        delta.y = -push.min(room).max(0.0);
+    }
+    if let Some(left) = left {
+        let push = (viewport.min.x + left) - sticky.min.x;
+        let room = parent_content.max.x - sticky.max.x;
+        delta.x = push.min(room).max(0.0);
+    }
+
+    delta
+}
+
+/// `true` when user input may scroll an axis with this overflow. `Visible`
+/// leaves the choice to the `UScrollContainer` flags.
+fn wheel_scrolls(overflow: UOverflow) -> bool {
+    matches!(overflow, UOverflow::Visible | UOverflow::Scroll)
+}
+
 fn clamp_scroll_offset(current: f32, delta: f32, overflow: f32) -> f32 {
     if overflow <= 0.0 {
         0.0
@@ -127,4 +255,136 @@ mod tests {
         assert_eq!(clamp_scroll_offset(-50.0, -20.0, 0.0), 0.0);
         assert_eq!(clamp_scroll_offset(10.0, 15.0, -5.0), 0.0);
     }
+
+    #[test]
+    fn wheel_only_scrolls_scroll_axes() {
+        assert!(wheel_scrolls(UOverflow::Scroll));
+        assert!(wheel_scrolls(UOverflow::Visible));
+        assert!(!wheel_scrolls(UOverflow::Hidden));
+        assert!(!wheel_scrolls(UOverflow::Clip));
+    }
+
+    #[test]
+    fn sticky_delta_pins_to_top_until_parent_scrolls_out() {
+        let viewport = Rect::from_center_size(Vec2::ZERO, Vec2::new(300.0, 400.0));
+        let parent = Rect::new(-150.0, -100.0, 150.0, 260.0);
+
+        // Header scrolled 40px past the top edge with a 10px threshold.
+        let header = Rect::new(-150.0, 210.0, 150.0, 240.0);
+        assert_eq!(sticky_delta(header, parent, viewport, Some(10.0), None), Vec2::new(0.0, -50.0));
+
+        // The parent's bottom edge limits how far the header follows.
+        let leaving = Rect::new(-150.0, 200.0, 150.0, 260.0);
+        assert_eq!(sticky_delta(header, leaving, viewport, Some(10.0), None), Vec2::new(0.0, -10.0));
+
+        // Still below the threshold: stays in flow.
+        let in_flow = Rect::new(-150.0, 150.0, 150.0, 180.0);
+        assert_eq!(sticky_delta(in_flow, parent, viewport, Some(0.0), None), Vec2::ZERO);
+    }
+
+    #[test]
+    fn sticky_delta_pins_to_left_edge() {
+        let viewport = Rect::from_center_size(Vec2::ZERO, Vec2::new(300.0, 400.0));
+        let parent = Rect::new(-400.0, -20.0, 400.0, 20.0);
+        let column = Rect::new(-220.0, -20.0, -180.0, 20.0);
+
+        assert_eq!(sticky_delta(column, parent, viewport, None, Some(0.0)), Vec2::new(70.0, 0.0));
+        assert_eq!(sticky_delta(column, parent, viewport, None, None), Vec2::ZERO);
+    }
+
+    /// Root > 300x200 scroll container > content scrolled by 50.25 > section >
+    /// sticky header pinned to the top. Returns `[content, section, header]`.
+    fn spawn_scrolled_sticky_header(app: &mut App) -> [Entity; 3] {
+        app.add_plugins((UnivisNodePlugin, UnivisLayoutPlugin));
+        app.add_systems(
+            PostUpdate,
+            sticky_position_system
+                .after(UnivisPostUpdateSet::LayoutSolve)
+                .before(UnivisPostUpdateSet::RenderSync),
+        );
+        let column = || ULayout { flex_direction: UFlexDirection::Column, ..default() };
+        let world = app.world_mut();
+        let root = world
+            .spawn((
+                UScreenRoot,
+                UNode { width: UVal::Px(800.0), height: UVal::Px(600.0), ..default() },
+                column(),
+            ))
+            .id();
+        let container = world
+            .spawn((
+                UScrollContainer::new(),
+                UNode { width: UVal::Px(300.0), height: UVal::Px(200.0), ..default() },
+                column(),
+                ChildOf(root),
+            ))
+            .id();
+        // Scrolled by a fraction of a pixel so snapping has to round the offset too.
+        let content = world
+            .spawn((
+                UNode { width: UVal::Px(300.0), height: UVal::Px(1000.0), ..default() },
+                column(),
+                USelf { top: UVal::Px(-50.25), ..default() },
+                ChildOf(container),
+            ))
+            .id();
+        let section = world
+            .spawn((
+                UNode { width: UVal::Px(300.0), height: UVal::Px(400.0), ..default() },
+                column(),
+                ChildOf(content),
+            ))
+            .id();
+        let header = world
+            .spawn((
+                UNode { width: UVal::Px(300.0), height: UVal::Px(20.0), ..default() },
+                USelf { position_type: UPositionType::Sticky, top: UVal::Px(0.0), ..default() },
+                ChildOf(section),
+            ))
+            .id();
+        [content, section, header]
+    }
+
+    /// Drawn y of the header's center relative to the container's center.
+    fn drawn_center_in_container(app: &App, path: [Entity; 3]) -> f32 {
+        path.iter()
+            .map(|&entity| app.world().get::<Transform>(entity).unwrap().translation.y)
+            .sum()
+    }
+
+    #[test]
+    fn sticky_header_keeps_its_offset_with_pixel_snapping() {
+        let mut app = App::new();
+        app.add_plugins(UiScalePlugin);
+        app.insert_resource(UiScaleSettings { pixel_snapping: true, ..default() });
+        let path = spawn_scrolled_sticky_header(&mut app);
+        let header = path[2];
+
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().get::<StickyOffset>(header).unwrap().0, Vec2::new(0.0, -50.25));
+        assert!(app.world().get::<PixelSnap>(header).is_some());
+        // Pinned to the viewport top: 10px below the container's top edge at +100.
+        assert_eq!(drawn_center_in_container(&app, path), 90.0);
+    }
+
+    #[test]
+    fn sticky_header_keeps_its_offset_with_a_layout_transition() {
+        let mut app = App::new();
+        app.add_plugins(LayoutTransitionPlugin);
+        app.init_resource::<Time>();
+        let path = spawn_scrolled_sticky_header(&mut app);
+        let header = path[2];
+        app.world_mut()
+            .entity_mut(header)
+            .insert(ULayoutTransition::new(1.0, EaseFunction::Linear));
+
+        app.update();
+        app.update();
+
+        let state = app.world().get::<LayoutTransitionState>(header).unwrap();
+        assert!(!state.is_running());
+        assert_eq!(drawn_center_in_container(&app, path), 90.0);
+    }
 }
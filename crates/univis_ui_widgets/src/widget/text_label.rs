@@ -1,8 +1,12 @@
 use bevy::camera::primitives::Aabb;
 use bevy::ecs::relationship::Relationship;
 use bevy::prelude::*;
-use bevy::text::TextLayoutInfo;
+use bevy::text::{
+    ComputedTextBlock, CosmicFontSystem, FontHinting, LineHeight, TextBounds, TextLayoutInfo, TextPipeline,
+    Text2dUpdateSystems,
+};
 use crate::internal_prelude::*;
+use univis_ui_engine::layout::core::hierarchy::propagate_direction;
 
 #[derive(Component, Reflect)]
 #[reflect(Component)]
@@ -84,6 +88,8 @@ pub fn fit_node_to_text_size(
     for (label, mut node, children) in parent_query.iter_mut() {
         // ننفذ فقط إذا كانت خاصية التحجيم التلقائي مفعلة
         if !label.autosize { continue; }
+        // النص الملتف يقاس عبر UMeasure (ارتفاع حسب العرض).
+        if label.linebreak != LineBreak::NoWrap { continue; }
 
         for &child in children {
             if let Ok(info) = child_query.get(child) {
@@ -117,6 +123,129 @@ pub fn fit_node_to_text_size(
     }
 }
 
+/// Shapes a label once and returns a `UMeasure` that wraps the shaped lines
+/// with cosmic-text's own line layout at any width, without the font system.
+/// Returns `None` while the font is not loaded.
+fn text_measure(
+    entity: Entity,
+    label: &UTextLabel,
+    fonts: &Assets<Font>,
+    pipeline: &mut TextPipeline,
+    font_system: &mut CosmicFontSystem,
+) -> Option<UMeasure> {
+    let font = TextFont {
+        font: label.font.clone(),
+        font_size: label.font_size,
+        ..default()
+    };
+    let layout = TextLayout {
+        justify: Justify::Left,
+        linebreak: label.linebreak,
+    };
+    let spans = std::iter::once((entity, 0, label.text.as_str(), &font, Color::WHITE, LineHeight::default()));
+    let mut block = ComputedTextBlock::default();
+    let mut info = pipeline
+        .create_text_measure(entity, fonts, spans, 1.0, &layout, &mut block, font_system, FontHinting::Disabled)
+        .ok()?;
+    info.compute_size(TextBounds::UNBOUNDED, &mut block, font_system);
+
+    let buffer = &block.buffer().0;
+    let metrics = buffer.metrics();
+    let (wrap, hinting, mono_width) = (buffer.wrap(), buffer.hinting(), buffer.monospace_width());
+    let paragraphs: Vec<_> = buffer.lines.iter().filter_map(|line| line.shape_opt().cloned()).collect();
+
+    Some(UMeasure::new(move |available_width: Option<f32>| {
+        let mut size = Vec2::ZERO;
+        for paragraph in &paragraphs {
+            for line in paragraph.layout(metrics.font_size, available_width, wrap, None, mono_width, hinting) {
+                size.x = size.x.max(line.w);
+                size.y += line.line_height_opt.unwrap_or(metrics.line_height);
+            }
+        }
+        size
+    }))
+}
+
+/// Gives autosized, wrapping labels a `UMeasure` so their height follows
+/// the width the layout gives them.
+pub fn update_text_label_measure(
+    mut commands: Commands,
+    labels: Query<(Entity, Ref<UTextLabel>, Has<UMeasure>)>,
+    fonts: Res<Assets<Font>>,
+    mut pipeline: ResMut<TextPipeline>,
+    mut font_system: ResMut<CosmicFontSystem>,
+) {
+    for (entity, label, has_measure) in labels.iter() {
+        if has_measure && !label.is_changed() {
+            continue;
+        }
+        if !label.autosize || label.linebreak == LineBreak::NoWrap {
+            if has_measure {
+                commands.entity(entity).remove::<UMeasure>();
+            }
+            continue;
+        }
+
+        // قد لا يكون الخط محملاً بعد؛ نعيد المحاولة في الإطار التالي.
+        let Some(measure) = text_measure(entity, &label, &fonts, &mut pipeline, &mut font_system) else {
+            continue;
+        };
+        commands.entity(entity).insert(measure);
+    }
+}
+
+/// Wraps the rendered text of measured labels at the node's content width.
+pub fn sync_text_label_bounds(
+    labels: Query<(&UNode, &ComputedSize, &Children, Has<UMeasure>), With<UTextLabel>>,
+    mut text_query: Query<&mut TextBounds, With<TextChildMarker>>,
+) {
+    for (node, computed, children, has_measure) in labels.iter() {
+        let width = has_measure.then(|| (computed.width - node.padding.width_sum()).max(0.0));
+        for &child in children {
+            if let Ok(mut bounds) = text_query.get_mut(child)
+                && bounds.width != width
+            {
+                bounds.width = width;
+            }
+        }
+    }
+}
+
+/// Publishes the first/last line baselines of each label to its `UBaseline`,
+/// so rows can align labels of different font sizes.
+pub fn sync_text_label_baseline(
+    mut label_query: Query<(&UNode, &ComputedSize, &Children, &mut UBaseline), With<UTextLabel>>,
+    text_query: Query<(&TextLayoutInfo, &ComputedTextBlock), With<TextChildMarker>>,
+) {
+    for (node, computed, children, mut baseline) in label_query.iter_mut() {
+        for &child in children {
+            let Ok((info, block)) = text_query.get(child) else { continue };
+            if info.size.y <= 0.0 { continue; }
+
+            // خطوط التخطيط بالبكسل الفعلي، بينما info.size منطقي.
+            let scale = info.scale_factor.max(f32::EPSILON);
+            let mut runs = block.buffer().0.layout_runs();
+            let Some(first_line) = runs.next().map(|run| run.line_y / scale) else { continue };
+            let last_line = runs.last().map_or(first_line, |run| run.line_y / scale);
+
+            // النص متمركز داخل العقدة.
+            let node_height = match node.height {
+                UVal::Px(v) => v,
+                _ => computed.height,
+            };
+            let text_top = (node_height - info.size.y) * 0.5;
+
+            let new_baseline = UBaseline {
+                first: Some(text_top + first_line),
+                last: Some(text_top + last_line),
+            };
+            if *baseline != new_baseline {
+                *baseline = new_baseline;
+            }
+        }
+    }
+}
+
 pub fn sync_text_label_props(
     label_query: Query<(&UTextLabel, &Children), Changed<UTextLabel>>,
     mut text_query: Query<(&mut Text2d, &mut TextFont, &mut TextColor, &mut TextLayout), With<TextChildMarker>>,
@@ -133,6 +262,30 @@ pub fn sync_text_label_props(
     }
 }
 
+/// Applies `UTextLabel::justify` to the text child; `Left`/`Right` swap in right-to-left nodes.
+pub fn sync_text_label_justify(
+    label_query: Query<
+        (&UTextLabel, &ComputedDirection, &Children),
+        Or<(Changed<UTextLabel>, Changed<ComputedDirection>)>,
+    >,
+    mut text_query: Query<&mut TextLayout, With<TextChildMarker>>,
+) {
+    for (label, direction, children) in label_query.iter() {
+        let justify = match label.justify {
+            Justify::Left if direction.is_rtl() => Justify::Right,
+            Justify::Right if direction.is_rtl() => Justify::Left,
+            other => other,
+        };
+        for &child in children {
+            if let Ok(mut layout) = text_query.get_mut(child)
+                && layout.justify != justify
+            {
+                layout.justify = justify;
+            }
+        }
+    }
+}
+
 pub fn sync_text_clip_visibility(
     mut text_query: Query<
         (
@@ -145,7 +298,7 @@ pub fn sync_text_clip_visibility(
         With<TextChildMarker>,
     >,
     parents_query: Query<&ChildOf>,
-    clipper_query: Query<(&GlobalTransform, &ComputedSize, &UClip)>,
+    clipper_query: Query<(&GlobalTransform, &ComputedSize, &UClip, Option<&UClipAxes>)>,
 ) {
     for (entity, global_transform, aabb, layout_info, mut visibility) in text_query.iter_mut() {
         let Some(world_quad) = text_world_quad(global_transform, aabb, layout_info) else {
@@ -233,14 +386,14 @@ fn is_quad_fully_inside_active_clippers(
     entity: Entity,
     world_quad: &[Vec2; 4],
     parents_query: &Query<&ChildOf>,
-    clipper_query: &Query<(&GlobalTransform, &ComputedSize, &UClip)>,
+    clipper_query: &Query<(&GlobalTransform, &ComputedSize, &UClip, Option<&UClipAxes>)>,
 ) -> bool {
     let mut current = entity;
 
     while let Ok(parent) = parents_query.get(current) {
         current = parent.get();
 
-        let Ok((clip_transform, clip_size, clip)) = clipper_query.get(current) else {
+        let Ok((clip_transform, clip_size, clip, axes)) = clipper_query.get(current) else {
             continue;
         };
 
@@ -248,7 +401,7 @@ fn is_quad_fully_inside_active_clippers(
             continue;
         }
 
-        let half_size = Vec2::new(clip_size.width, clip_size.height) * 0.5;
+        let half_size = axes.copied().unwrap_or_default().mask_size(Vec2::new(clip_size.width, clip_size.height)) * 0.5;
         if !is_quad_inside_oriented_rect(world_quad, clip_transform, half_size) {
             return false;
         }
@@ -286,9 +439,22 @@ impl Plugin for UnivisTextPlugin {
             ))
             .add_systems(
                 PostUpdate,
-                fit_node_to_text_size
+                (update_text_label_measure, fit_node_to_text_size, sync_text_label_baseline)
+                    .chain()
                     .in_set(UnivisPostUpdateSet::WidgetSync)
                     .before(UnivisPostUpdateSet::LayoutMeasure),
+            )
+            .add_systems(
+                PostUpdate,
+                sync_text_label_justify
+                    .in_set(UnivisPostUpdateSet::WidgetSync)
+                    .after(propagate_direction),
+            )
+            .add_systems(
+                PostUpdate,
+                sync_text_label_bounds
+                    .after(UnivisPostUpdateSet::LayoutSolve)
+                    .before(Text2dUpdateSystems),
             );
     }
 }
@@ -297,6 +463,60 @@ impl Plugin for UnivisTextPlugin {
 mod tests {
     use super::*;
 
+    #[test]
+    fn text_measure_wraps_with_cosmic_text_layout() {
+        let mut fonts = Assets::<Font>::default();
+        let font = fonts.add(Font::try_from_bytes(bevy::text::DEFAULT_FONT_DATA.to_vec()).unwrap());
+        let label = UTextLabel {
+            font,
+            font_size: 20.0,
+            linebreak: LineBreak::WordBoundary,
+            ..UTextLabel::new("one two three")
+        };
+        let measure = text_measure(
+            Entity::PLACEHOLDER,
+            &label,
+            &fonts,
+            &mut TextPipeline::default(),
+            &mut CosmicFontSystem::default(),
+        )
+        .unwrap();
+
+        let line = measure.measure(None);
+        let narrow = measure.measure(Some(line.x * 0.6));
+        let min = measure.measure(Some(0.0));
+        assert!(narrow.x <= line.x * 0.6 && narrow.y > line.y);
+        // At zero width every word takes its own line: the widest word is the min-content width.
+        assert!((min.y - line.y * 3.0).abs() < 0.01);
+        assert!(min.x > 0.0 && min.x < narrow.x);
+    }
+
+    #[test]
+    fn rtl_label_swaps_left_and_right_justification() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_text_label_justify);
+
+        let label = app
+            .world_mut()
+            .spawn((
+                UTextLabel { justify: Justify::Left, ..UTextLabel::new("مرحبا") },
+                ComputedDirection(UDirection::Rtl),
+            ))
+            .id();
+        let text = app.world_mut().spawn((TextChildMarker, TextLayout::default(), ChildOf(label))).id();
+        app.update();
+        assert_eq!(app.world().get::<TextLayout>(text).unwrap().justify, Justify::Right);
+
+        app.world_mut().get_mut::<ComputedDirection>(label).unwrap().0 = UDirection::Ltr;
+        app.update();
+        assert_eq!(app.world().get::<TextLayout>(text).unwrap().justify, Justify::Left);
+
+        app.world_mut().get_mut::<UTextLabel>(label).unwrap().justify = Justify::Center;
+        app.world_mut().get_mut::<ComputedDirection>(label).unwrap().0 = UDirection::Rtl;
+        app.update();
+        assert_eq!(app.world().get::<TextLayout>(text).unwrap().justify, Justify::Center);
+    }
+
     #[test]
     fn quad_inside_oriented_rect_returns_true() {
         let rect_transform = GlobalTransform::from(Transform::default());
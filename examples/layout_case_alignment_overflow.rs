@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use univis_ui::prelude::*;
+use univis_ui::{layout::debug::LayoutDebugPlugin, prelude::*};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(UnivisUiPlugin)
+        // F8: صناديق التخطيط، F7: معلومات العقدة تحت المؤشر
+        .add_plugins((UnivisUiPlugin, LayoutDebugPlugin))
         .add_systems(Startup, setup)
         .run();
 }
@@ -68,6 +68,7 @@ fn setup(mut commands: Commands) {
                                 auto_flow: UGridAutoFlow::Column,
                                 auto_rows: UTrackSize::Px(46.0),
                                 auto_columns: UTrackSize::Fr(1.0),
+                                ..default()
                             },
                             ..default()
                         },
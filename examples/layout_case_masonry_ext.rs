@@ -56,6 +56,11 @@ fn setup(mut commands: Commands) {
                                 column_gap: Some(10.0),
                                 ..default()
                             },
+                            // عدد الأعمدة يتبع عرض النافذة
+                            masonry: ULayoutMasonryContainer {
+                                min_column_width: Some(140.0),
+                                ..default()
+                            },
                             ..default()
                         },
                         ..default()
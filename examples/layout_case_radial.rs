@@ -63,6 +63,16 @@ fn setup(mut commands: Commands) {
                             },
                             ULayout {
                                 display: UDisplay::Radial,
+                                container_ext: ULayoutContainerExt {
+                                    radial: URadialLayout {
+                                        start_angle: -120.0,
+                                        sweep_angle: 240.0,
+                                        radius: UVal::Percent(0.8),
+                                        rotate_children: true,
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
                                 ..default()
                             },
                         ))
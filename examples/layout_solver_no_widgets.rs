@@ -539,6 +539,7 @@ fn spawn_grid_tile(
                     column_span: col_span.max(1),
                     row_start,
                     row_span: row_span.max(1),
+                    ..default()
                 },
                 ..default()
             },
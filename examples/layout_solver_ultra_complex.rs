@@ -459,6 +459,7 @@ fn spawn_right_column(parent: &mut ChildSpawnerCommands) {
                                 auto_flow: UGridAutoFlow::Column,
                                 auto_rows: UTrackSize::Px(56.0),
                                 auto_columns: UTrackSize::Fr(1.0),
+                                ..default()
                             },
                             ..default()
                         },
@@ -729,6 +730,7 @@ fn spawn_grid_tile(
                     column_span: col_span.max(1),
                     row_start,
                     row_span: row_span.max(1),
+                    ..default()
                 },
                 ..default()
             },
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use univis_ui::prelude::*;
+
+/// Space: يعكس ترتيب المخزون. A: يطوي/يفتح القسم.
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UnivisUiPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (sort_inventory, toggle_section))
+        .run();
+}
+
+#[derive(Component)]
+struct Inventory;
+
+#[derive(Component)]
+struct Section;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            UScreenRoot,
+            UNode {
+                width: UVal::Percent(1.0),
+                height: UVal::Percent(1.0),
+                padding: USides::all(24.0),
+                background_color: Color::srgb(0.08, 0.08, 0.1),
+                ..default()
+            },
+            ULayout {
+                flex_direction: UFlexDirection::Column,
+                gap: 16.0,
+                ..default()
+            },
+        ))
+        .with_children(|root| {
+            // 1. مخزون يعاد ترتيبه: كل خانة تنزلق إلى مكانها الجديد
+            root.spawn((
+                Inventory,
+                UNode {
+                    width: UVal::Px(420.0),
+                    padding: USides::all(10.0),
+                    background_color: Color::srgb(0.14, 0.14, 0.18),
+                    border_radius: UCornerRadius::all(8.0),
+                    ..default()
+                },
+                ULayout {
+                    gap: 10.0,
+                    container_ext: ULayoutContainerExt {
+                        flex: ULayoutFlexContainer { wrap: UFlexWrap::Wrap, ..default() },
+                        ..default()
+                    },
+                    ..default()
+                },
+            ))
+            .with_children(|inventory| {
+                for i in 0..10 {
+                    let hue = i as f32 * 36.0;
+                    inventory.spawn((
+                        UNode {
+                            width: UVal::Px(70.0),
+                            height: UVal::Px(70.0),
+                            background_color: Color::hsl(hue, 0.55, 0.5),
+                            border_radius: UCornerRadius::all(6.0),
+                            ..default()
+                        },
+                        ULayoutTransition::new(0.35, EaseFunction::CubicInOut),
+                    ));
+                }
+            });
+
+            // 2. قسم أكورديون: الحجم والعناصر التالية تتحرك معًا
+            root.spawn((
+                Section,
+                UNode {
+                    width: UVal::Px(420.0),
+                    height: UVal::Px(160.0),
+                    background_color: Color::srgb(0.2, 0.3, 0.45),
+                    border_radius: UCornerRadius::all(8.0),
+                    ..default()
+                },
+                ULayoutTransition::default(),
+            ));
+            root.spawn((
+                UNode {
+                    width: UVal::Px(420.0),
+                    height: UVal::Px(60.0),
+                    background_color: Color::srgb(0.3, 0.2, 0.3),
+                    border_radius: UCornerRadius::all(8.0),
+                    ..default()
+                },
+                ULayoutTransition::default(),
+            ));
+        });
+}
+
+fn sort_inventory(keys: Res<ButtonInput<KeyCode>>, mut inventory: Query<&mut ULayout, With<Inventory>>) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    for mut layout in inventory.iter_mut() {
+        layout.flex_direction = match layout.flex_direction {
+            UFlexDirection::Row => UFlexDirection::RowReverse,
+            _ => UFlexDirection::Row,
+        };
+    }
+}
+
+fn toggle_section(keys: Res<ButtonInput<KeyCode>>, mut sections: Query<&mut UNode, With<Section>>) {
+    if !keys.just_pressed(KeyCode::KeyA) {
+        return;
+    }
+    for mut node in sections.iter_mut() {
+        node.height = match node.height {
+            UVal::Px(h) if h > 40.0 => UVal::Px(40.0),
+            _ => UVal::Px(160.0),
+        };
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+use univis_ui_engine::prelude::*;
+
+// بدون App ولا نافذة: نبني الشجرة ونطبع المستطيلات
+fn main() {
+    let mut tree = LayoutTree::new();
+
+    let root = tree.add_node(UNode {
+        width: UVal::Percent(1.0),
+        height: UVal::Percent(1.0),
+        padding: USides::all(16.0),
+        ..default()
+    });
+    tree.set_layout(root, ULayout {
+        flex_direction: UFlexDirection::Column,
+        gap: 12.0,
+        ..default()
+    });
+
+    let toolbar = tree.add_node(UNode { width: UVal::Percent(1.0), ..default() });
+    tree.set_layout(toolbar, ULayout { gap: 8.0, ..default() });
+    tree.add_child(root, toolbar);
+    for width in [80.0, 60.0, 100.0] {
+        let button = tree.add_node(UNode {
+            width: UVal::Px(width),
+            height: UVal::Px(32.0),
+            ..default()
+        });
+        tree.add_child(toolbar, button);
+    }
+
+    // نص ملتف: 8px لكل حرف و20px لكل سطر
+    let body = tree.add_node(UNode { width: UVal::Percent(1.0), ..default() });
+    let text = "Layout runs without a World, so tools and tests can use it.";
+    tree.set_measure(body, move |width: Option<f32>| {
+        let full = text.len() as f32 * 8.0;
+        let line = width.unwrap_or(full).max(8.0);
+        Vec2::new(full.min(line), (full / line).ceil() * 20.0)
+    });
+    tree.add_child(root, body);
+
+    for size in [Vec2::new(640.0, 360.0), Vec2::new(240.0, 360.0)] {
+        tree.compute(root, size);
+        println!("available {size}");
+        print_tree(&tree, root, 1);
+    }
+}
+
+fn print_tree(tree: &LayoutTree, id: LayoutNodeId, indent: usize) {
+    let rect = tree.rect(id);
+    println!("{}{id:?}: pos {} size {}", "  ".repeat(indent), rect.min, rect.size());
+    for &child in tree.children(id) {
+        print_tree(tree, child, indent + 1);
+    }
+}
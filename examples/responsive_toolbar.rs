@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+use univis_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UnivisUiPlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // غيّر عرض النافذة: تحت 600px يتحول الشريط إلى عمود
+    commands
+        .spawn((
+            UScreenRoot,
+            UNode {
+                width: UVal::Percent(1.0),
+                height: UVal::Percent(1.0),
+                padding: USides::all(20.0),
+                background_color: Color::srgb(0.08, 0.08, 0.1),
+                ..default()
+            },
+            ULayout {
+                flex_direction: UFlexDirection::Column,
+                gap: 20.0,
+                ..default()
+            },
+        ))
+        .with_children(|root| {
+            // 1. Media query: حسب حجم الجذر
+            root.spawn((
+                UNode {
+                    width: UVal::Percent(1.0),
+                    padding: USides::all(10.0),
+                    background_color: Color::srgb(0.15, 0.15, 0.2),
+                    border_radius: UCornerRadius::all(8.0),
+                    ..default()
+                },
+                ULayout {
+                    flex_direction: UFlexDirection::Row,
+                    gap: 10.0,
+                    ..default()
+                },
+                UResponsive::new().with_rule(UResponsiveRule::layout(
+                    UQuery::root().max_width(600.0),
+                    ULayout {
+                        flex_direction: UFlexDirection::Column,
+                        gap: 6.0,
+                        ..default()
+                    },
+                )),
+            ))
+            .with_children(|toolbar| {
+                for label in ["File", "Edit", "View", "Help"] {
+                    spawn_button(toolbar, label);
+                }
+            });
+
+            // 2. Container query: حسب عرض اللوحة الحاوية (40% من الجذر)
+            root.spawn((
+                UQueryContainer,
+                UNode {
+                    width: UVal::Percent(0.4),
+                    padding: USides::all(10.0),
+                    background_color: Color::srgb(0.12, 0.2, 0.16),
+                    border_radius: UCornerRadius::all(8.0),
+                    ..default()
+                },
+            ))
+            .with_children(|panel| {
+                panel
+                    .spawn((
+                        UNode::default(),
+                        ULayout {
+                            flex_direction: UFlexDirection::Row,
+                            gap: 10.0,
+                            ..default()
+                        },
+                        UResponsive::new().with_rule(UResponsiveRule::layout(
+                            UQuery::container().max_width(360.0),
+                            ULayout {
+                                flex_direction: UFlexDirection::Column,
+                                gap: 6.0,
+                                ..default()
+                            },
+                        )),
+                    ))
+                    .with_children(|row| {
+                        for label in ["Play", "Pause", "Stop"] {
+                            spawn_button(row, label);
+                        }
+                    });
+            });
+        });
+}
+
+fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str) {
+    parent.spawn((
+        UNode {
+            width: UVal::Px(90.0),
+            height: UVal::Px(32.0),
+            background_color: Color::srgb(0.25, 0.45, 0.85),
+            border_radius: UCornerRadius::all(6.0),
+            ..default()
+        },
+        ULayout {
+            justify_content: UJustifyContent::Center,
+            align_items: UAlignItems::Center,
+            ..default()
+        },
+    ))
+    .with_children(|button| {
+        button.spawn(UTextLabel {
+            text: label.into(),
+            font_size: 14.0,
+            color: Color::WHITE,
+            ..default()
+        });
+    });
+}
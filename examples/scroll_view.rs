@@ -15,8 +15,11 @@ fn setup_scroll_list(mut commands: Commands) {
     commands
         .spawn((
             UWorldRoot::default(),
-            // تفعيل القص
-            UClip { enabled: true },
+            // تفعيل القص: Scroll يقص المحتوى ويسمح بتمريره
+            ULayout {
+                overflow_y: UOverflow::Scroll,
+                ..default()
+            },
             // تفعيل منطق التمرير
             UScrollContainer::new(),
             UNode {
@@ -24,7 +27,6 @@ fn setup_scroll_list(mut commands: Commands) {
                 height: UVal::Px(400.0),
                 background_color: Color::BLACK,
                 border_radius: UCornerRadius::all(15.0),
-                // مهم: Overflow Hidden منطقياً
                 ..default()
             },
             // التفاعل ضروري لاكتشاف الـ Hover
@@ -57,22 +59,58 @@ fn setup_scroll_list(mut commands: Commands) {
                     // يجب أن يكون Relative ليتحرك بالنسبة للأب
                 ))
                 .with_children(|list| {
-                    // إضافة 20 عنصر
-                    for i in 0..20 {
-                        list.spawn((UNode {
-                            width: UVal::Percent(1.0),
-                            height: UVal::Px(50.0),
-                            background_color: Color::srgb(0.2, 0.2, 0.25),
-                            border_radius: UCornerRadius::all(5.0),
-                            padding: USides::all(10.0),
-                            ..default()
-                        },))
-                            .with_children(|item| {
-                                item.spawn((
-                                    UTextLabel::new(format!("Item #{}", i + 1).as_str()),
-                                    
-                                ));
-                            });
+                    // 4 أقسام، لكل قسم عنوان لاصق (Sticky) و5 عناصر
+                    for section in 0..4 {
+                        list.spawn((
+                            UNode {
+                                width: UVal::Percent(1.0),
+                                height: UVal::Content,
+                                ..default()
+                            },
+                            ULayout {
+                                flex_direction: UFlexDirection::Column,
+                                gap: 10.0,
+                                ..default()
+                            },
+                        ))
+                        .with_children(|group| {
+                            group
+                                .spawn((
+                                    UNode {
+                                        width: UVal::Percent(1.0),
+                                        height: UVal::Px(30.0),
+                                        background_color: Color::srgb(0.3, 0.45, 0.8),
+                                        border_radius: UCornerRadius::all(5.0),
+                                        padding: USides::axes(10.0, 4.0),
+                                        ..default()
+                                    },
+                                    // يبقى ملتصقاً بأعلى الحاوية حتى يخرج قسمه
+                                    USelf {
+                                        position_type: UPositionType::Sticky,
+                                        top: UVal::Px(0.0),
+                                        ..default()
+                                    },
+                                ))
+                                .with_children(|header| {
+                                    header.spawn(UTextLabel::new(format!("Section {}", section + 1).as_str()));
+                                });
+
+                            for i in 0..5 {
+                                group.spawn((UNode {
+                                    width: UVal::Percent(1.0),
+                                    height: UVal::Px(50.0),
+                                    background_color: Color::srgb(0.2, 0.2, 0.25),
+                                    border_radius: UCornerRadius::all(5.0),
+                                    padding: USides::all(10.0),
+                                    ..default()
+                                },))
+                                    .with_children(|item| {
+                                        item.spawn((
+                                            UTextLabel::new(format!("Item #{}", section * 5 + i + 1).as_str()),
+                                        ));
+                                    });
+                            }
+                        });
                     }
                 });
         });
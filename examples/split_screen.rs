@@ -0,0 +1,86 @@
+use bevy::camera::Viewport;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use univis_ui::prelude::*;
+
+/// شاشة مقسومة: HUD لكل viewport، بحجمه وطبقاته والتقاطه الخاص.
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(UnivisUiPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, fit_viewports)
+        .run();
+}
+
+#[derive(Component)]
+struct Player(u32);
+
+fn setup(mut commands: Commands) {
+    for (index, color) in [Color::srgb(0.2, 0.45, 0.8), Color::srgb(0.8, 0.35, 0.25)].into_iter().enumerate() {
+        let layer = index + 1;
+        let camera = commands
+            .spawn((
+                Camera2d,
+                Camera { order: index as isize, ..default() },
+                // كل كاميرا في مكان مختلف من العالم وعلى طبقتها الخاصة
+                Transform::from_xyz(index as f32 * 5000.0, 0.0, 0.0),
+                RenderLayers::layer(layer),
+                Player(index as u32),
+            ))
+            .id();
+
+        commands
+            .spawn((
+                UScreenRoot,
+                UTargetCamera(camera),
+                UNode {
+                    width: UVal::Percent(1.0),
+                    height: UVal::Percent(1.0),
+                    padding: USides::all(16.0),
+                    background_color: Color::srgb(0.08, 0.08, 0.1),
+                    ..default()
+                },
+                ULayout {
+                    flex_direction: UFlexDirection::Column,
+                    justify_content: UJustifyContent::SpaceBetween,
+                    ..default()
+                },
+            ))
+            .with_children(|hud| {
+                hud.spawn(UTextLabel::new(&format!("Player {layer}")));
+                hud.spawn((
+                    UNode {
+                        width: UVal::Px(180.0),
+                        height: UVal::Px(48.0),
+                        background_color: color,
+                        border_radius: UCornerRadius::all(8.0),
+                        ..default()
+                    },
+                    UInteractionColors {
+                        normal: color,
+                        hovered: color.lighter(0.1),
+                        pressed: Color::WHITE,
+                    },
+                ));
+            });
+    }
+}
+
+/// يقسم النافذة بين الكاميرتين عند كل تغيير في حجمها.
+fn fit_viewports(
+    windows: Query<&Window, (With<PrimaryWindow>, Changed<Window>)>,
+    mut cameras: Query<(&mut Camera, &Player)>,
+) {
+    let Ok(window) = windows.single() else { return };
+    let size = window.physical_size();
+    let half = UVec2::new(size.x / 2, size.y);
+    for (mut camera, player) in cameras.iter_mut() {
+        camera.viewport = Some(Viewport {
+            physical_position: UVec2::new(player.0 * half.x, 0),
+            physical_size: half.max(UVec2::ONE),
+            ..default()
+        });
+    }
+}